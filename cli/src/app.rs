@@ -4,6 +4,31 @@ pub fn build_app() -> App<'static> {
     App::new("rusty-gql")
         .version(crate_version!())
         .setting(AppSettings::DeriveDisplayOrder)
-        .subcommand(App::new("new").arg(Arg::new("name").required(true).index(1)))
-        .subcommand(App::new("generate").alias("g"))
+        .subcommand(
+            App::new("new")
+                .arg(Arg::new("name").required(true).index(1))
+                .arg(Arg::new("features").long("features").takes_value(true)),
+        )
+        .subcommand(
+            App::new("generate")
+                .alias("g")
+                .arg(Arg::new("features").long("features").takes_value(true)),
+        )
+        .subcommand(
+            App::new("diff")
+                .arg(Arg::new("old").required(true).index(1))
+                .arg(Arg::new("new").required(true).index(2)),
+        )
+        .subcommand(App::new("check").arg(Arg::new("path").index(1)))
+        .subcommand(
+            App::new("client")
+                .arg(Arg::new("operations").required(true).index(1))
+                .arg(Arg::new("schema").long("schema").takes_value(true))
+                .arg(
+                    Arg::new("output")
+                        .long("output")
+                        .short('o')
+                        .takes_value(true),
+                ),
+        )
 }
@@ -0,0 +1,197 @@
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+};
+
+use graphql_parser::{
+    schema::{Definition, TypeDefinition},
+    Pos,
+};
+use rusty_gql::GqlError;
+
+pub struct SourceError {
+    pub path: Option<PathBuf>,
+    pub pos: Option<Pos>,
+    pub message: String,
+}
+
+impl std::fmt::Display for SourceError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match (&self.path, &self.pos) {
+            (Some(path), Some(pos)) => write!(
+                f,
+                "{}:{}:{}: error: {}",
+                path.display(),
+                pos.line,
+                pos.column,
+                self.message
+            ),
+            (Some(path), None) => write!(f, "{}: error: {}", path.display(), self.message),
+            _ => write!(f, "error: {}", self.message),
+        }
+    }
+}
+
+/// Reports syntax errors of each file, and semantic errors of the schema built from all files.
+pub fn check_schema_files(files: &[(PathBuf, String)]) -> Vec<SourceError> {
+    let mut errors = Vec::new();
+    let mut definitions: HashMap<String, (&PathBuf, Pos)> = HashMap::new();
+
+    for (path, content) in files {
+        let doc = match graphql_parser::parse_schema::<String>(content) {
+            Ok(doc) => doc,
+            Err(err) => {
+                errors.push(syntax_error(path, &err.to_string()));
+                continue;
+            }
+        };
+        for definition in &doc.definitions {
+            let (name, pos, kind) = match definition {
+                Definition::TypeDefinition(type_def) => {
+                    let (name, pos) = type_definition_name(type_def);
+                    (name.to_string(), pos, "Type ")
+                }
+                Definition::DirectiveDefinition(directive) => {
+                    (directive.name.clone(), directive.position, "Directive @")
+                }
+                _ => continue,
+            };
+            let key = format!("{}{}", kind, name);
+            match definitions.get(&key) {
+                Some((defined_path, defined_pos)) => errors.push(SourceError {
+                    path: Some(path.clone()),
+                    pos: Some(pos),
+                    message: format!(
+                        "{}{} is already defined at {}:{}:{}.",
+                        kind,
+                        name,
+                        defined_path.display(),
+                        defined_pos.line,
+                        defined_pos.column
+                    ),
+                }),
+                None => {
+                    definitions.insert(key, (path, pos));
+                }
+            }
+        }
+    }
+    if !errors.is_empty() {
+        return errors;
+    }
+
+    // Builds the schema from one document, so that a position can be mapped to the file.
+    let source = CombinedSource::new(files);
+    let schema_errors =
+        match rusty_gql::build_schema(&[source.content.as_str()], Default::default()) {
+            Ok(schema) => rusty_gql::check_schema(&schema),
+            Err(err) => vec![err],
+        };
+    errors.extend(schema_errors.into_iter().map(|err| source.error(err)));
+    errors.sort_by_key(|err| (err.path.clone(), err.pos.map(|pos| (pos.line, pos.column))));
+    errors
+}
+
+/// Reports syntax errors of each operation file.
+pub fn check_operation_files(files: &[(PathBuf, String)]) -> Vec<SourceError> {
+    files
+        .iter()
+        .filter_map(|(path, content)| {
+            graphql_parser::parse_query::<String>(content)
+                .err()
+                .map(|err| syntax_error(path, &err.to_string()))
+        })
+        .collect()
+}
+
+/// The files joined into one document, which maps a position of the document back to the file.
+pub struct CombinedSource<'a> {
+    pub content: String,
+    line_offsets: Vec<(usize, &'a Path)>,
+}
+
+impl<'a> CombinedSource<'a> {
+    pub fn new(files: &'a [(PathBuf, String)]) -> Self {
+        let mut content = String::new();
+        let mut line_offsets = Vec::new();
+        for (path, file_content) in files {
+            line_offsets.push((content.lines().count(), path.as_path()));
+            content.push_str(file_content);
+            content.push('\n');
+        }
+        CombinedSource {
+            content,
+            line_offsets,
+        }
+    }
+
+    pub fn error(&self, err: GqlError) -> SourceError {
+        let pos = err.locations.first().map(|location| Pos {
+            line: location.line,
+            column: location.column,
+        });
+        let (path, pos) = match pos.and_then(|pos| self.locate(pos)) {
+            Some((path, pos)) => (Some(path), Some(pos)),
+            None => (None, None),
+        };
+        SourceError {
+            path,
+            pos,
+            message: err.message,
+        }
+    }
+
+    fn locate(&self, pos: Pos) -> Option<(PathBuf, Pos)> {
+        let (offset, path) = self
+            .line_offsets
+            .iter()
+            .rev()
+            .find(|(offset, _)| *offset < pos.line)?;
+        Some((
+            path.to_path_buf(),
+            Pos {
+                line: pos.line - offset,
+                column: pos.column,
+            },
+        ))
+    }
+}
+
+// The message of graphql_parser is like "schema parse error: Parse error at 3:5\nUnexpected ...".
+fn syntax_error(path: &Path, message: &str) -> SourceError {
+    let mut lines = message.lines();
+    let pos = lines
+        .next()
+        .and_then(|line| line.rsplit("at ").next())
+        .and_then(|location| location.split_once(':'))
+        .and_then(|(line, column)| {
+            Some(Pos {
+                line: line.trim().parse().ok()?,
+                column: column.trim().parse().ok()?,
+            })
+        });
+    let detail: Vec<&str> = lines
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .collect();
+    SourceError {
+        path: Some(path.to_path_buf()),
+        pos,
+        message: if pos.is_some() && !detail.is_empty() {
+            detail.join(", ")
+        } else {
+            message.trim().to_string()
+        },
+    }
+}
+
+fn type_definition_name<'a>(type_def: &'a TypeDefinition<'a, String>) -> (&'a str, Pos) {
+    match type_def {
+        TypeDefinition::Scalar(v) => (&v.name, v.position),
+        TypeDefinition::Object(v) => (&v.name, v.position),
+        TypeDefinition::Interface(v) => (&v.name, v.position),
+        TypeDefinition::Union(v) => (&v.name, v.position),
+        TypeDefinition::Enum(v) => (&v.name, v.position),
+        TypeDefinition::InputObject(v) => (&v.name, v.position),
+    }
+}
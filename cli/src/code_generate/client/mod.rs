@@ -0,0 +1,601 @@
+mod selection;
+mod types;
+
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+
+use graphql_parser::{
+    query::{
+        Definition, Document, Field, FragmentDefinition, OperationDefinition, Selection,
+        SelectionSet, TypeCondition, VariableDefinition,
+    },
+    Pos,
+};
+use heck::ToSnakeCase;
+use proc_macro2::{Literal, TokenStream};
+use quote::quote;
+use rusty_gql::{GqlError, GqlValueType, InputObjectType, InputValueType, Schema, TypeDefinition};
+
+use self::types::{
+    enum_item, field_ident, is_list_type, rename_attr, rust_type, scalar_type, type_ident,
+};
+use super::util::get_interface_impl_object_map;
+
+/// Validates the operations against the schema and generates the typed client code of them.
+pub fn generate_client_code(schema: &Schema, query_doc: &str) -> Result<String, Vec<GqlError>> {
+    let errors = rusty_gql::validate_document(schema, query_doc);
+    if !errors.is_empty() {
+        return Err(errors);
+    }
+    // The generator borrows the document, so `__typename` is found in a separate parse.
+    let positions = {
+        let doc = parse_document(query_doc)?;
+        check_names(schema, &doc)?;
+        Generator::new(schema, &doc).typename_positions(&doc)
+    };
+
+    let mut doc = parse_document(query_doc)?;
+    for def in &mut doc.definitions {
+        match def {
+            Definition::Operation(operation) => {
+                add_typename(operation_selection_set_mut(operation), &positions)
+            }
+            Definition::Fragment(fragment) => add_typename(&mut fragment.selection_set, &positions),
+        }
+    }
+
+    let mut generator = Generator::new(schema, &doc);
+    let tokens = generator.generate(&doc);
+    Ok(format!(
+        "// This file is generated by rusty-gql from the operations. Do not edit it manually.\n\n{}",
+        tokens
+    ))
+}
+
+fn parse_document(query_doc: &str) -> Result<Document<'_, String>, Vec<GqlError>> {
+    graphql_parser::parse_query::<String>(query_doc)
+        .map_err(|err| vec![GqlError::new(err.to_string(), None)])
+}
+
+pub(super) struct Generator<'a> {
+    schema: &'a Schema,
+    fragments: BTreeMap<&'a str, &'a FragmentDefinition<'a, String>>,
+    interface_impls: HashMap<String, Vec<String>>,
+    // Enums and input objects used by the operations.
+    used_types: BTreeSet<String>,
+}
+
+// The types generated in the root or in the module of an operation.
+pub(super) struct TypeScope {
+    prefix: TokenStream,
+    names: HashSet<String>,
+    items: Vec<TokenStream>,
+}
+
+impl TypeScope {
+    fn new(prefix: TokenStream) -> Self {
+        TypeScope {
+            prefix,
+            names: HashSet::new(),
+            items: Vec::new(),
+        }
+    }
+
+    fn unique_name(&mut self, name: &str) -> String {
+        let mut unique_name = name.to_string();
+        let mut count = 2;
+        while !self.names.insert(unique_name.clone()) {
+            unique_name = format!("{}{}", name, count);
+            count += 1;
+        }
+        unique_name
+    }
+}
+
+impl<'a> Generator<'a> {
+    fn new(schema: &'a Schema, doc: &'a Document<'a, String>) -> Self {
+        let fragments = doc
+            .definitions
+            .iter()
+            .filter_map(|def| match def {
+                Definition::Fragment(fragment) => Some((fragment.name.as_str(), fragment)),
+                Definition::Operation(_) => None,
+            })
+            .collect();
+        let mut interface_impls = get_interface_impl_object_map(&schema.type_definitions);
+        for objects in interface_impls.values_mut() {
+            objects.sort();
+        }
+        Generator {
+            schema,
+            fragments,
+            interface_impls,
+            used_types: BTreeSet::new(),
+        }
+    }
+
+    fn typename_positions(&self, doc: &'a Document<'a, String>) -> HashSet<Pos> {
+        let mut positions = HashSet::new();
+        for def in &doc.definitions {
+            match def {
+                Definition::Operation(operation) => {
+                    let root_type_name = self.root_type_name(operation);
+                    self.typename_selection_sets(
+                        root_type_name,
+                        operation_selection_set(operation),
+                        &mut positions,
+                    );
+                }
+                Definition::Fragment(fragment) => {
+                    let TypeCondition::On(condition) = &fragment.type_condition;
+                    if self.needs_enum(condition, &[&fragment.selection_set]) {
+                        positions.insert(fragment.selection_set.span.0);
+                    }
+                    self.typename_selection_sets(
+                        condition,
+                        &fragment.selection_set,
+                        &mut positions,
+                    );
+                }
+            }
+        }
+        positions
+    }
+
+    fn generate(&mut self, doc: &'a Document<'a, String>) -> TokenStream {
+        let mut root_scope = TypeScope::new(quote! {});
+        for type_def in self.schema.type_definitions.values() {
+            if matches!(
+                type_def,
+                TypeDefinition::Enum(_) | TypeDefinition::InputObject(_)
+            ) {
+                root_scope
+                    .names
+                    .insert(type_ident(type_def.name()).to_string());
+            }
+        }
+        let fragments: Vec<_> = self.fragments.values().copied().collect();
+        for fragment in &fragments {
+            root_scope
+                .names
+                .insert(type_ident(&fragment.name).to_string());
+        }
+        for fragment in fragments {
+            let name = type_ident(&fragment.name).to_string();
+            let TypeCondition::On(condition) = &fragment.type_condition;
+            self.selection_type(
+                &mut root_scope,
+                &name,
+                &name,
+                condition,
+                &[&fragment.selection_set],
+            );
+        }
+
+        let mut modules = Vec::new();
+        for def in &doc.definitions {
+            if let Definition::Operation(operation) = def {
+                modules.push(self.operation_module(operation));
+            }
+        }
+        let schema_types = self.schema_type_items();
+        let root_items = root_scope.items;
+        // Enums derive both, input objects derive `Serialize` and fragments derive `Deserialize`.
+        let serde_use = match (!schema_types.is_empty(), !root_items.is_empty()) {
+            (true, _) => quote! { use serde::{Deserialize, Serialize}; },
+            (false, true) => quote! { use serde::Deserialize; },
+            (false, false) => quote! {},
+        };
+
+        quote! {
+            #serde_use
+
+            #(#schema_types)*
+            #(#root_items)*
+            #(#modules)*
+        }
+    }
+
+    fn operation_module(&mut self, operation: &'a OperationDefinition<'a, String>) -> TokenStream {
+        let (name, variable_definitions) = match operation {
+            OperationDefinition::Query(query) => (&query.name, &query.variable_definitions),
+            OperationDefinition::Mutation(mutation) => {
+                (&mutation.name, &mutation.variable_definitions)
+            }
+            OperationDefinition::Subscription(subscription) => {
+                (&subscription.name, &subscription.variable_definitions)
+            }
+            OperationDefinition::SelectionSet(_) => return quote! {},
+        };
+        let name = name.as_deref().unwrap_or_default();
+        let module_ident = field_ident(name);
+
+        let mut scope = TypeScope::new(quote! { super:: });
+        scope.names.insert("Variables".to_string());
+        scope.names.insert("ResponseData".to_string());
+        let variables = self.variables_item(variable_definitions, &scope.prefix);
+        let root_type_name = self.root_type_name(operation);
+        self.selection_type(
+            &mut scope,
+            "ResponseData",
+            "",
+            root_type_name,
+            &[operation_selection_set(operation)],
+        );
+        let query = self.query_text(operation);
+        let items = scope.items;
+
+        quote! {
+            pub mod #module_ident {
+                use serde::{Deserialize, Serialize};
+
+                pub const OPERATION_NAME: &str = #name;
+                pub const QUERY: &str = #query;
+
+                #variables
+                #(#items)*
+            }
+        }
+    }
+
+    fn variables_item(
+        &mut self,
+        variable_definitions: &[VariableDefinition<'a, String>],
+        prefix: &TokenStream,
+    ) -> TokenStream {
+        let fields: Vec<_> = variable_definitions
+            .iter()
+            .map(|variable| {
+                let ty = GqlValueType::from(variable.var_type.clone());
+                self.input_field_item(&variable.name, &ty, prefix, None)
+            })
+            .collect();
+        quote! {
+            #[derive(Debug, Clone, PartialEq, Serialize)]
+            pub struct Variables {
+                #(#fields),*
+            }
+        }
+    }
+
+    fn input_field_item(
+        &mut self,
+        name: &str,
+        ty: &GqlValueType,
+        prefix: &TokenStream,
+        parent_type_name: Option<&str>,
+    ) -> TokenStream {
+        let type_name = ty.name();
+        let named = match self.schema.type_definitions.get(type_name) {
+            Some(TypeDefinition::Enum(_) | TypeDefinition::InputObject(_)) => {
+                self.used_types.insert(type_name.to_string());
+                let ident = type_ident(type_name);
+                // A recursive input object is boxed.
+                if parent_type_name == Some(type_name) && !is_list_type(ty) {
+                    quote! { Box<#prefix #ident> }
+                } else {
+                    quote! { #prefix #ident }
+                }
+            }
+            _ => scalar_type(type_name),
+        };
+        let ty_tokens = rust_type(ty, &named);
+        let ident = field_ident(name);
+        let rename = rename_attr(&ident, name);
+        if ty.is_non_null() {
+            quote! {
+                #rename
+                pub #ident: #ty_tokens
+            }
+        } else {
+            quote! {
+                #rename
+                #[serde(skip_serializing_if = "Option::is_none")]
+                pub #ident: #ty_tokens
+            }
+        }
+    }
+
+    fn input_object_item(&mut self, input_object: &InputObjectType) -> TokenStream {
+        let ident = type_ident(&input_object.name);
+        let fields: Vec<_> = input_object
+            .fields
+            .iter()
+            .map(|field: &InputValueType| {
+                self.input_field_item(
+                    &field.name,
+                    &field.meta_type,
+                    &quote! {},
+                    Some(&input_object.name),
+                )
+            })
+            .collect();
+        quote! {
+            #[derive(Debug, Clone, PartialEq, Serialize)]
+            pub struct #ident {
+                #(#fields),*
+            }
+        }
+    }
+
+    // Input objects can use other enums and input objects, so this runs until no type is added.
+    fn schema_type_items(&mut self) -> Vec<TokenStream> {
+        let schema = self.schema;
+        let mut items = BTreeMap::new();
+        loop {
+            let pending: Vec<String> = self
+                .used_types
+                .iter()
+                .filter(|name| !items.contains_key(*name))
+                .cloned()
+                .collect();
+            if pending.is_empty() {
+                break;
+            }
+            for name in pending {
+                let item = match schema.type_definitions.get(&name) {
+                    Some(TypeDefinition::Enum(enum_type)) => enum_item(enum_type),
+                    Some(TypeDefinition::InputObject(input_object)) => {
+                        self.input_object_item(input_object)
+                    }
+                    _ => quote! {},
+                };
+                items.insert(name, item);
+            }
+        }
+        items.into_values().collect()
+    }
+
+    // The operation with the fragments it uses, as sent to the server.
+    fn query_text(&self, operation: &'a OperationDefinition<'a, String>) -> TokenStream {
+        let mut fragment_names = BTreeSet::new();
+        self.used_fragments(operation_selection_set(operation), &mut fragment_names);
+        let mut text = operation.to_string();
+        for name in fragment_names {
+            text.push('\n');
+            text.push_str(&self.fragments[name].to_string());
+        }
+        let text = text.trim_end();
+        // A raw string keeps the query readable in the generated file.
+        if text.contains("\"#") {
+            let literal = Literal::string(text);
+            quote! { #literal }
+        } else {
+            format!("r#\"{}\"#", text).parse().unwrap()
+        }
+    }
+
+    fn used_fragments(
+        &self,
+        selection_set: &'a SelectionSet<'a, String>,
+        fragment_names: &mut BTreeSet<&'a str>,
+    ) {
+        for selection in &selection_set.items {
+            match selection {
+                Selection::Field(field) => {
+                    self.used_fragments(&field.selection_set, fragment_names)
+                }
+                Selection::InlineFragment(inline) => {
+                    self.used_fragments(&inline.selection_set, fragment_names)
+                }
+                Selection::FragmentSpread(spread) => {
+                    if let Some(fragment) = self.fragments.get(spread.fragment_name.as_str()) {
+                        if fragment_names.insert(&fragment.name) {
+                            self.used_fragments(&fragment.selection_set, fragment_names);
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    fn root_type_name(&self, operation: &OperationDefinition<'a, String>) -> &'a str {
+        match operation {
+            OperationDefinition::Query(_) | OperationDefinition::SelectionSet(_) => {
+                &self.schema.query_type_name
+            }
+            OperationDefinition::Mutation(_) => &self.schema.mutation_type_name,
+            OperationDefinition::Subscription(_) => &self.schema.subscription_type_name,
+        }
+    }
+}
+
+// Operations become modules and fragments become types, so their names must be unique.
+fn check_names(schema: &Schema, doc: &Document<'_, String>) -> Result<(), Vec<GqlError>> {
+    let mut errors = Vec::new();
+    let mut operation_names = HashMap::new();
+    let mut fragment_names = HashMap::new();
+    for def in &doc.definitions {
+        match def {
+            Definition::Operation(operation) => {
+                let (name, pos) = match operation {
+                    OperationDefinition::Query(query) => (&query.name, query.position),
+                    OperationDefinition::Mutation(mutation) => (&mutation.name, mutation.position),
+                    OperationDefinition::Subscription(subscription) => {
+                        (&subscription.name, subscription.position)
+                    }
+                    OperationDefinition::SelectionSet(selection_set) => {
+                        (&None, selection_set.span.0)
+                    }
+                };
+                match name {
+                    Some(name) => {
+                        if let Some(defined) = operation_names.insert(name.to_snake_case(), name) {
+                            errors.push(GqlError::new(
+                                format!("Operation {} conflicts with {}.", name, defined),
+                                Some(pos),
+                            ));
+                        }
+                    }
+                    None => errors.push(GqlError::new(
+                        "Operations must be named to generate client code.",
+                        Some(pos),
+                    )),
+                }
+            }
+            Definition::Fragment(fragment) => {
+                let type_name = type_ident(&fragment.name).to_string();
+                if let Some(defined) = fragment_names.insert(type_name.clone(), &fragment.name) {
+                    errors.push(GqlError::new(
+                        format!("Fragment {} conflicts with {}.", fragment.name, defined),
+                        Some(fragment.position),
+                    ));
+                } else if schema.type_definitions.values().any(|type_def| {
+                    matches!(
+                        type_def,
+                        TypeDefinition::Enum(_) | TypeDefinition::InputObject(_)
+                    ) && type_ident(type_def.name()) == type_name
+                }) {
+                    errors.push(GqlError::new(
+                        format!(
+                            "Fragment {} conflicts with the type of the schema.",
+                            fragment.name
+                        ),
+                        Some(fragment.position),
+                    ));
+                }
+            }
+        }
+    }
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
+fn add_typename(selection_set: &mut SelectionSet<'_, String>, positions: &HashSet<Pos>) {
+    for selection in &mut selection_set.items {
+        match selection {
+            Selection::Field(field) => add_typename(&mut field.selection_set, positions),
+            Selection::InlineFragment(inline) => add_typename(&mut inline.selection_set, positions),
+            Selection::FragmentSpread(_) => {}
+        }
+    }
+
+    let pos = selection_set.span.0;
+    let has_typename = selection_set.items.iter().any(|selection| {
+        matches!(selection, Selection::Field(field) if field.alias.as_ref().unwrap_or(&field.name) == "__typename")
+    });
+    if positions.contains(&pos) && !has_typename {
+        selection_set.items.insert(
+            0,
+            Selection::Field(Field {
+                position: pos,
+                alias: None,
+                name: "__typename".to_string(),
+                arguments: Vec::new(),
+                directives: Vec::new(),
+                selection_set: SelectionSet {
+                    span: (pos, pos),
+                    items: Vec::new(),
+                },
+            }),
+        );
+    }
+}
+
+fn operation_selection_set<'a, 'b>(
+    operation: &'b OperationDefinition<'a, String>,
+) -> &'b SelectionSet<'a, String> {
+    match operation {
+        OperationDefinition::SelectionSet(selection_set) => selection_set,
+        OperationDefinition::Query(query) => &query.selection_set,
+        OperationDefinition::Mutation(mutation) => &mutation.selection_set,
+        OperationDefinition::Subscription(subscription) => &subscription.selection_set,
+    }
+}
+
+fn operation_selection_set_mut<'a, 'b>(
+    operation: &'b mut OperationDefinition<'a, String>,
+) -> &'b mut SelectionSet<'a, String> {
+    match operation {
+        OperationDefinition::SelectionSet(selection_set) => selection_set,
+        OperationDefinition::Query(query) => &mut query.selection_set,
+        OperationDefinition::Mutation(mutation) => &mut mutation.selection_set,
+        OperationDefinition::Subscription(subscription) => &mut subscription.selection_set,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{path::Path, process::Command};
+
+    use super::generate_client_code;
+
+    // The snapshot is included, so that the generated code is also compiled.
+    #[allow(dead_code, clippy::all)]
+    mod operations {
+        include!("snapshots/operations.rs");
+    }
+
+    // Compares the tokens, without the trailing commas which rustfmt adds to the snapshot.
+    fn normalize(code: &str) -> String {
+        let file = syn::parse_file(code).expect("The generated code is not valid Rust.");
+        quote::quote!(#file)
+            .to_string()
+            .replace(" , }", " }")
+            .replace(" , )", " )")
+    }
+
+    // Run with `UPDATE_SNAPSHOTS=1` to overwrite the snapshot with the generated code.
+    #[test]
+    fn test_client_code_snapshot() {
+        let snapshots =
+            Path::new(env!("CARGO_MANIFEST_DIR")).join("src/code_generate/client/snapshots");
+        let schema_doc = std::fs::read_to_string(snapshots.join("schema.graphql")).unwrap();
+        let schema = rusty_gql::build_schema(&[schema_doc.as_str()], Default::default()).unwrap();
+        let query_doc = std::fs::read_to_string(snapshots.join("operations.graphql")).unwrap();
+        let code = match generate_client_code(&schema, &query_doc) {
+            Ok(code) => code,
+            Err(errors) => panic!("{:?}", errors),
+        };
+
+        let snapshot_path = snapshots.join("operations.rs");
+        if std::env::var_os("UPDATE_SNAPSHOTS").is_some() {
+            std::fs::write(&snapshot_path, &code).unwrap();
+            Command::new("rustfmt")
+                .args(["--edition", "2021"])
+                .arg(&snapshot_path)
+                .status()
+                .unwrap();
+        }
+        let snapshot = std::fs::read_to_string(&snapshot_path).unwrap();
+        assert_eq!(normalize(&code), normalize(&snapshot));
+    }
+
+    #[test]
+    fn test_client_code_deserialize() {
+        use operations::{hero, search, Episode};
+
+        let variables = hero::Variables {
+            episode: None,
+            with_friends: true,
+        };
+        assert_eq!(
+            serde_json::to_string(&variables).unwrap(),
+            r#"{"withFriends":true}"#
+        );
+
+        let data: hero::ResponseData = serde_json::from_str(
+            r#"{"hero":{"__typename":"Droid","id":"2001","name":"R2-D2","appearsIn":["JEDI"],"primaryFunction":"Astromech"}}"#,
+        )
+        .unwrap();
+        match data.hero {
+            Some(hero::Hero::Droid(droid)) => {
+                assert_eq!(droid.appears_in, vec![Episode::Jedi]);
+                assert_eq!(droid.friends, None);
+                assert_eq!(droid.primary_function.as_deref(), Some("Astromech"));
+            }
+            _ => panic!("hero should be a droid"),
+        }
+
+        let data: search::ResponseData = serde_json::from_str(
+            r#"{"search":[{"__typename":"Starship","name":"X-wing","length":12.5},{"__typename":"Human","name":"Luke","homePlanet":null}]}"#,
+        )
+        .unwrap();
+        assert!(
+            matches!(&data.search[0], search::Search::Starship(ship) if ship.length == Some(12.5))
+        );
+        assert!(matches!(&data.search[1], search::Search::Human(human) if human.name == "Luke"));
+    }
+}
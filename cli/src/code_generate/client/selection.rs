@@ -0,0 +1,442 @@
+use std::collections::HashSet;
+
+use graphql_parser::{
+    query::{Directive, Selection, SelectionSet, TypeCondition},
+    Pos,
+};
+use heck::ToUpperCamelCase;
+use proc_macro2::{Ident, TokenStream};
+use quote::quote;
+use rusty_gql::TypeDefinition;
+
+use super::{
+    types::{field_ident, ident, rename_attr, rust_type, scalar_type, type_ident},
+    Generator, TypeScope,
+};
+
+// Fields with the same response key are merged, as the executor does.
+struct CollectedField<'a> {
+    key: &'a str,
+    name: &'a str,
+    is_conditional: bool,
+    selection_sets: Vec<&'a SelectionSet<'a, String>>,
+}
+
+fn has_condition_directive(directives: &[Directive<'_, String>]) -> bool {
+    directives
+        .iter()
+        .any(|directive| directive.name == "skip" || directive.name == "include")
+}
+
+impl<'a> Generator<'a> {
+    /// Generates the struct, or the enum tagged by `__typename`, of a selection set and returns its identifier.
+    pub(super) fn selection_type(
+        &mut self,
+        scope: &mut TypeScope,
+        name: &str,
+        path: &str,
+        type_name: &str,
+        selection_sets: &[&'a SelectionSet<'a, String>],
+    ) -> Ident {
+        if !self.needs_enum(type_name, selection_sets) {
+            let object = self.is_object(type_name).then_some(type_name);
+            let (fields, spreads) = self.collect_struct_fields(type_name, object, selection_sets);
+            return self.struct_item(scope, name, path, type_name, fields, spreads);
+        }
+
+        let mut variants = Vec::new();
+        for object in self.possible_types(type_name) {
+            let variant_name =
+                scope.unique_name(&format!("{}On{}", name, object.to_upper_camel_case()));
+            let mut fields = Vec::new();
+            for selection_set in selection_sets {
+                self.collect_fields(
+                    type_name,
+                    Some(&object),
+                    selection_set,
+                    false,
+                    false,
+                    &mut fields,
+                    &mut Vec::new(),
+                );
+            }
+            // The tag is consumed by the enum.
+            fields.retain(|field| field.key != "__typename");
+            let struct_ident = self.struct_item(
+                scope,
+                &variant_name,
+                &variant_name,
+                &object,
+                fields,
+                Vec::new(),
+            );
+            let variant = type_ident(&object);
+            let rename = rename_attr(&variant, &object);
+            variants.push(quote! { #rename #variant(#struct_ident) });
+        }
+
+        let enum_ident = ident(name);
+        scope.items.push(quote! {
+            #[derive(Debug, Clone, PartialEq, Deserialize)]
+            #[serde(tag = "__typename")]
+            pub enum #enum_ident {
+                #(#variants),*
+            }
+        });
+        enum_ident
+    }
+
+    fn struct_item(
+        &mut self,
+        scope: &mut TypeScope,
+        name: &str,
+        path: &str,
+        type_name: &str,
+        fields: Vec<CollectedField<'a>>,
+        spreads: Vec<&'a str>,
+    ) -> Ident {
+        let mut field_items = Vec::new();
+        for field in &fields {
+            field_items.push(self.field_item(scope, path, type_name, field));
+        }
+        let prefix = scope.prefix.clone();
+        for spread in spreads {
+            let field = field_ident(spread);
+            let fragment = type_ident(spread);
+            field_items.push(quote! {
+                #[serde(flatten)]
+                pub #field: #prefix #fragment
+            });
+        }
+
+        let struct_ident = ident(name);
+        scope.items.push(quote! {
+            #[derive(Debug, Clone, PartialEq, Deserialize)]
+            pub struct #struct_ident {
+                #(#field_items),*
+            }
+        });
+        struct_ident
+    }
+
+    fn field_item(
+        &mut self,
+        scope: &mut TypeScope,
+        path: &str,
+        type_name: &str,
+        field: &CollectedField<'a>,
+    ) -> TokenStream {
+        let ident = field_ident(field.key);
+        let rename = rename_attr(&ident, field.key);
+        let schema = self.schema;
+        let field_def = schema
+            .type_definitions
+            .get(type_name)
+            .and_then(|type_def| type_def.get_field_by_name(field.name));
+
+        let (ty, is_non_null) = if field.name == "__typename" {
+            (quote! { String }, true)
+        } else if let Some(field_def) = field_def {
+            let field_type_name = field_def.meta_type.name();
+            let named = match schema.type_definitions.get(field_type_name) {
+                Some(TypeDefinition::Enum(_)) => {
+                    self.used_types.insert(field_type_name.to_string());
+                    let prefix = &scope.prefix;
+                    let enum_ident = type_ident(field_type_name);
+                    quote! { #prefix #enum_ident }
+                }
+                Some(
+                    TypeDefinition::Object(_)
+                    | TypeDefinition::Interface(_)
+                    | TypeDefinition::Union(_),
+                ) => {
+                    let name =
+                        scope.unique_name(&format!("{}{}", path, field.key.to_upper_camel_case()));
+                    let ident = self.selection_type(
+                        scope,
+                        &name,
+                        &name,
+                        field_type_name,
+                        &field.selection_sets,
+                    );
+                    quote! { #ident }
+                }
+                _ => scalar_type(field_type_name),
+            };
+            (
+                rust_type(&field_def.meta_type, &named),
+                field_def.meta_type.is_non_null(),
+            )
+        } else {
+            (quote! { serde_json::Value }, false)
+        };
+
+        // A field with `@skip` or `@include` may be missing in the response.
+        if field.is_conditional {
+            let ty = if is_non_null {
+                quote! { Option<#ty> }
+            } else {
+                ty
+            };
+            quote! {
+                #rename
+                #[serde(default)]
+                pub #ident: #ty
+            }
+        } else {
+            quote! {
+                #rename
+                pub #ident: #ty
+            }
+        }
+    }
+
+    // Fragments on the same type are flattened, unless their fields overlap with others.
+    fn collect_struct_fields(
+        &self,
+        type_name: &str,
+        object: Option<&str>,
+        selection_sets: &[&'a SelectionSet<'a, String>],
+    ) -> (Vec<CollectedField<'a>>, Vec<&'a str>) {
+        let mut fields = Vec::new();
+        let mut spreads = Vec::new();
+        for selection_set in selection_sets {
+            self.collect_fields(
+                type_name,
+                object,
+                selection_set,
+                false,
+                true,
+                &mut fields,
+                &mut spreads,
+            );
+        }
+
+        let mut keys: HashSet<&str> = fields.iter().map(|field| field.key).collect();
+        let overlaps = spreads.iter().any(|spread| {
+            let mut fragment_fields = Vec::new();
+            self.collect_fields(
+                type_name,
+                object,
+                &self.fragments[spread].selection_set,
+                false,
+                false,
+                &mut fragment_fields,
+                &mut Vec::new(),
+            );
+            fragment_fields.iter().any(|field| !keys.insert(field.key))
+        });
+        if overlaps {
+            fields.clear();
+            spreads.clear();
+            for selection_set in selection_sets {
+                self.collect_fields(
+                    type_name,
+                    object,
+                    selection_set,
+                    false,
+                    false,
+                    &mut fields,
+                    &mut spreads,
+                );
+            }
+        }
+        (fields, spreads)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn collect_fields(
+        &self,
+        type_name: &str,
+        object: Option<&str>,
+        selection_set: &'a SelectionSet<'a, String>,
+        is_conditional: bool,
+        flatten: bool,
+        fields: &mut Vec<CollectedField<'a>>,
+        spreads: &mut Vec<&'a str>,
+    ) {
+        for selection in &selection_set.items {
+            match selection {
+                Selection::Field(field) => {
+                    let key = field.alias.as_ref().unwrap_or(&field.name).as_str();
+                    let is_conditional =
+                        is_conditional || has_condition_directive(&field.directives);
+                    match fields.iter_mut().find(|collected| collected.key == key) {
+                        Some(collected) => {
+                            collected.is_conditional &= is_conditional;
+                            collected.selection_sets.push(&field.selection_set);
+                        }
+                        None => fields.push(CollectedField {
+                            key,
+                            name: &field.name,
+                            is_conditional,
+                            selection_sets: vec![&field.selection_set],
+                        }),
+                    }
+                }
+                Selection::FragmentSpread(spread) => {
+                    let fragment = match self.fragments.get(spread.fragment_name.as_str()) {
+                        Some(fragment) => fragment,
+                        None => continue,
+                    };
+                    let TypeCondition::On(condition) = &fragment.type_condition;
+                    if !self.applies(condition, type_name, object) {
+                        continue;
+                    }
+                    let is_conditional =
+                        is_conditional || has_condition_directive(&spread.directives);
+                    if flatten
+                        && !is_conditional
+                        && condition == type_name
+                        && !self.needs_enum(condition, &[&fragment.selection_set])
+                    {
+                        if !spreads.contains(&spread.fragment_name.as_str()) {
+                            spreads.push(&spread.fragment_name);
+                        }
+                        continue;
+                    }
+                    self.collect_fields(
+                        type_name,
+                        object,
+                        &fragment.selection_set,
+                        is_conditional,
+                        flatten,
+                        fields,
+                        spreads,
+                    );
+                }
+                Selection::InlineFragment(inline) => {
+                    if let Some(TypeCondition::On(condition)) = &inline.type_condition {
+                        if !self.applies(condition, type_name, object) {
+                            continue;
+                        }
+                    }
+                    self.collect_fields(
+                        type_name,
+                        object,
+                        &inline.selection_set,
+                        is_conditional || has_condition_directive(&inline.directives),
+                        flatten,
+                        fields,
+                        spreads,
+                    );
+                }
+            }
+        }
+    }
+
+    // Whether the fields under `condition` are selected for `object` in a selection set on `type_name`.
+    fn applies(&self, condition: &str, type_name: &str, object: Option<&str>) -> bool {
+        if condition == type_name {
+            return true;
+        }
+        match object {
+            Some(object) => {
+                condition == object || self.possible_types(condition).iter().any(|t| t == object)
+            }
+            None => false,
+        }
+    }
+
+    /// A selection set on an interface or a union needs an enum, if some fields are selected by type conditions.
+    pub(super) fn needs_enum(
+        &self,
+        type_name: &str,
+        selection_sets: &[&'a SelectionSet<'a, String>],
+    ) -> bool {
+        self.is_abstract(type_name)
+            && selection_sets
+                .iter()
+                .any(|selection_set| self.has_type_condition(type_name, selection_set))
+    }
+
+    fn has_type_condition(
+        &self,
+        type_name: &str,
+        selection_set: &SelectionSet<'a, String>,
+    ) -> bool {
+        selection_set.items.iter().any(|selection| match selection {
+            Selection::Field(_) => false,
+            Selection::FragmentSpread(spread) => {
+                match self.fragments.get(spread.fragment_name.as_str()) {
+                    Some(fragment) => {
+                        let TypeCondition::On(condition) = &fragment.type_condition;
+                        condition != type_name
+                            || self.has_type_condition(type_name, &fragment.selection_set)
+                    }
+                    None => false,
+                }
+            }
+            Selection::InlineFragment(inline) => match &inline.type_condition {
+                Some(TypeCondition::On(condition)) if condition != type_name => true,
+                _ => self.has_type_condition(type_name, &inline.selection_set),
+            },
+        })
+    }
+
+    /// Positions of the selection sets which need `__typename` to be deserialized into an enum.
+    pub(super) fn typename_selection_sets(
+        &self,
+        type_name: &str,
+        selection_set: &'a SelectionSet<'a, String>,
+        positions: &mut HashSet<Pos>,
+    ) {
+        for selection in &selection_set.items {
+            match selection {
+                Selection::Field(field) => {
+                    if field.selection_set.items.is_empty() {
+                        continue;
+                    }
+                    let field_type_name = match self
+                        .schema
+                        .type_definitions
+                        .get(type_name)
+                        .and_then(|type_def| type_def.get_field_by_name(&field.name))
+                    {
+                        Some(field_def) => field_def.meta_type.name(),
+                        None => continue,
+                    };
+                    if self.needs_enum(field_type_name, &[&field.selection_set]) {
+                        positions.insert(field.selection_set.span.0);
+                    }
+                    self.typename_selection_sets(field_type_name, &field.selection_set, positions);
+                }
+                Selection::InlineFragment(inline) => {
+                    let type_name = match &inline.type_condition {
+                        Some(TypeCondition::On(condition)) => condition.as_str(),
+                        None => type_name,
+                    };
+                    self.typename_selection_sets(type_name, &inline.selection_set, positions);
+                }
+                Selection::FragmentSpread(_) => {}
+            }
+        }
+    }
+
+    fn is_object(&self, type_name: &str) -> bool {
+        matches!(
+            self.schema.type_definitions.get(type_name),
+            Some(TypeDefinition::Object(_))
+        )
+    }
+
+    fn is_abstract(&self, type_name: &str) -> bool {
+        matches!(
+            self.schema.type_definitions.get(type_name),
+            Some(TypeDefinition::Interface(_) | TypeDefinition::Union(_))
+        )
+    }
+
+    fn possible_types(&self, type_name: &str) -> Vec<String> {
+        match self.schema.type_definitions.get(type_name) {
+            Some(TypeDefinition::Union(union_type)) => union_type.types.clone(),
+            Some(TypeDefinition::Interface(_)) => self
+                .interface_impls
+                .get(type_name)
+                .cloned()
+                .unwrap_or_default(),
+            Some(TypeDefinition::Object(_)) => vec![type_name.to_string()],
+            _ => Vec::new(),
+        }
+    }
+}
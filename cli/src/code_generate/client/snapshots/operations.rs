@@ -0,0 +1,202 @@
+// This file is generated by rusty-gql from the operations. Do not edit it manually.
+
+use serde::{Deserialize, Serialize};
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ColorInput {
+    pub red: i32,
+    pub green: i32,
+    pub blue: i32,
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Episode {
+    #[serde(rename = "NEWHOPE")]
+    Newhope,
+    #[serde(rename = "EMPIRE")]
+    Empire,
+    #[serde(rename = "JEDI")]
+    Jedi,
+}
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ReviewInput {
+    pub stars: i32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub commentary: Option<String>,
+    #[serde(rename = "favoriteColor")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub favorite_color: Option<ColorInput>,
+}
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct CharacterFields {
+    pub id: String,
+    pub name: String,
+    #[serde(rename = "appearsIn")]
+    pub appears_in: Vec<Episode>,
+}
+pub mod hero {
+    use serde::{Deserialize, Serialize};
+    pub const OPERATION_NAME: &str = "Hero";
+    pub const QUERY: &str = r#"query Hero($episode: Episode, $withFriends: Boolean!) {
+  hero(episode: $episode) {
+    __typename
+    ...CharacterFields
+    friends @include(if: $withFriends) {
+      name
+    }
+    ... on Droid {
+      primaryFunction
+    }
+  }
+}
+
+fragment CharacterFields on Character {
+  id
+  name
+  appearsIn
+}"#;
+    #[derive(Debug, Clone, PartialEq, Serialize)]
+    pub struct Variables {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub episode: Option<super::Episode>,
+        #[serde(rename = "withFriends")]
+        pub with_friends: bool,
+    }
+    #[derive(Debug, Clone, PartialEq, Deserialize)]
+    pub struct HeroOnDroidFriends {
+        pub name: String,
+    }
+    #[derive(Debug, Clone, PartialEq, Deserialize)]
+    pub struct HeroOnDroid {
+        pub id: String,
+        pub name: String,
+        #[serde(rename = "appearsIn")]
+        pub appears_in: Vec<super::Episode>,
+        #[serde(default)]
+        pub friends: Option<Vec<Option<HeroOnDroidFriends>>>,
+        #[serde(rename = "primaryFunction")]
+        pub primary_function: Option<String>,
+    }
+    #[derive(Debug, Clone, PartialEq, Deserialize)]
+    pub struct HeroOnHumanFriends {
+        pub name: String,
+    }
+    #[derive(Debug, Clone, PartialEq, Deserialize)]
+    pub struct HeroOnHuman {
+        pub id: String,
+        pub name: String,
+        #[serde(rename = "appearsIn")]
+        pub appears_in: Vec<super::Episode>,
+        #[serde(default)]
+        pub friends: Option<Vec<Option<HeroOnHumanFriends>>>,
+    }
+    #[derive(Debug, Clone, PartialEq, Deserialize)]
+    #[serde(tag = "__typename")]
+    pub enum Hero {
+        Droid(HeroOnDroid),
+        Human(HeroOnHuman),
+    }
+    #[derive(Debug, Clone, PartialEq, Deserialize)]
+    pub struct ResponseData {
+        pub hero: Option<Hero>,
+    }
+}
+pub mod search {
+    use serde::{Deserialize, Serialize};
+    pub const OPERATION_NAME: &str = "Search";
+    pub const QUERY: &str = r#"query Search($text: String!) {
+  search(text: $text) {
+    __typename
+    ... on Human {
+      name
+      homePlanet
+    }
+    ... on Droid {
+      name
+    }
+    ... on Starship {
+      name
+      length
+    }
+  }
+}"#;
+    #[derive(Debug, Clone, PartialEq, Serialize)]
+    pub struct Variables {
+        pub text: String,
+    }
+    #[derive(Debug, Clone, PartialEq, Deserialize)]
+    pub struct SearchOnHuman {
+        pub name: String,
+        #[serde(rename = "homePlanet")]
+        pub home_planet: Option<String>,
+    }
+    #[derive(Debug, Clone, PartialEq, Deserialize)]
+    pub struct SearchOnDroid {
+        pub name: String,
+    }
+    #[derive(Debug, Clone, PartialEq, Deserialize)]
+    pub struct SearchOnStarship {
+        pub name: String,
+        pub length: Option<f64>,
+    }
+    #[derive(Debug, Clone, PartialEq, Deserialize)]
+    #[serde(tag = "__typename")]
+    pub enum Search {
+        Human(SearchOnHuman),
+        Droid(SearchOnDroid),
+        Starship(SearchOnStarship),
+    }
+    #[derive(Debug, Clone, PartialEq, Deserialize)]
+    pub struct ResponseData {
+        pub search: Vec<Search>,
+    }
+}
+pub mod reviews {
+    use serde::{Deserialize, Serialize};
+    pub const OPERATION_NAME: &str = "Reviews";
+    pub const QUERY: &str = r#"query Reviews($episode: Episode!, $first: Int) {
+  reviews(episode: $episode, first: $first) {
+    stars
+    createdAt
+  }
+}"#;
+    #[derive(Debug, Clone, PartialEq, Serialize)]
+    pub struct Variables {
+        pub episode: super::Episode,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub first: Option<i32>,
+    }
+    #[derive(Debug, Clone, PartialEq, Deserialize)]
+    pub struct Reviews {
+        pub stars: i32,
+        #[serde(rename = "createdAt")]
+        pub created_at: serde_json::Value,
+    }
+    #[derive(Debug, Clone, PartialEq, Deserialize)]
+    pub struct ResponseData {
+        pub reviews: Vec<Reviews>,
+    }
+}
+pub mod create_review {
+    use serde::{Deserialize, Serialize};
+    pub const OPERATION_NAME: &str = "CreateReview";
+    pub const QUERY: &str = r#"mutation CreateReview($episode: Episode!, $review: ReviewInput!) {
+  createReview(episode: $episode, review: $review) {
+    stars
+    commentary
+  }
+}"#;
+    #[derive(Debug, Clone, PartialEq, Serialize)]
+    pub struct Variables {
+        pub episode: super::Episode,
+        pub review: super::ReviewInput,
+    }
+    #[derive(Debug, Clone, PartialEq, Deserialize)]
+    pub struct CreateReview {
+        pub stars: i32,
+        pub commentary: Option<String>,
+    }
+    #[derive(Debug, Clone, PartialEq, Deserialize)]
+    pub struct ResponseData {
+        #[serde(rename = "createReview")]
+        pub create_review: Option<CreateReview>,
+    }
+}
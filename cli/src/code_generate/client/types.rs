@@ -0,0 +1,87 @@
+use heck::{ToSnakeCase, ToUpperCamelCase};
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::{format_ident, quote};
+use rusty_gql::{EnumType, GqlValueType};
+use syn::ext::IdentExt;
+
+pub fn field_ident(name: &str) -> Ident {
+    ident(&name.to_snake_case())
+}
+
+pub fn type_ident(name: &str) -> Ident {
+    ident(&name.to_upper_camel_case())
+}
+
+pub fn ident(name: &str) -> Ident {
+    if syn::parse_str::<Ident>(name).is_ok() {
+        return Ident::new(name, Span::call_site());
+    }
+    // These keywords cannot be raw identifiers.
+    if ["self", "Self", "super", "crate"].contains(&name) {
+        return format_ident!("{}_", name);
+    }
+    Ident::new_raw(name, Span::call_site())
+}
+
+// `#[serde(rename = "...")]` if the identifier differs from the GraphQL name.
+pub fn rename_attr(ident: &Ident, name: &str) -> TokenStream {
+    if ident.unraw() == name {
+        quote! {}
+    } else {
+        quote! { #[serde(rename = #name)] }
+    }
+}
+
+pub fn scalar_type(type_name: &str) -> TokenStream {
+    match type_name {
+        "Int" => quote! { i32 },
+        "Float" => quote! { f64 },
+        "String" | "ID" => quote! { String },
+        "Boolean" => quote! { bool },
+        _ => quote! { serde_json::Value },
+    }
+}
+
+/// Wraps the named type with `Option` and `Vec` as the GraphQL type.
+pub fn rust_type(ty: &GqlValueType, named: &TokenStream) -> TokenStream {
+    wrap_type(ty, named, true)
+}
+
+fn wrap_type(ty: &GqlValueType, named: &TokenStream, optional: bool) -> TokenStream {
+    let inner = match ty {
+        GqlValueType::NonNullType(inner) => return wrap_type(inner, named, false),
+        GqlValueType::ListType(inner) => {
+            let item = wrap_type(inner, named, true);
+            quote! { Vec<#item> }
+        }
+        GqlValueType::NamedType(_) => named.clone(),
+    };
+    if optional {
+        quote! { Option<#inner> }
+    } else {
+        inner
+    }
+}
+
+pub fn is_list_type(ty: &GqlValueType) -> bool {
+    match ty {
+        GqlValueType::NonNullType(inner) => is_list_type(inner),
+        GqlValueType::ListType(_) => true,
+        GqlValueType::NamedType(_) => false,
+    }
+}
+
+pub fn enum_item(enum_type: &EnumType) -> TokenStream {
+    let ident = type_ident(&enum_type.name);
+    let variants = enum_type.values.iter().map(|value| {
+        let variant = type_ident(&value.name);
+        let rename = rename_attr(&variant, &value.name);
+        quote! { #rename #variant }
+    });
+    quote! {
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+        pub enum #ident {
+            #(#variants),*
+        }
+    }
+}
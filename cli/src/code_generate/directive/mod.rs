@@ -88,5 +88,5 @@ pub async fn create_directive_files(
 }
 
 fn is_default_directive(name: &str) -> bool {
-    vec!["skip", "include", "deprecated"].contains(&name)
+    ["skip", "include", "deprecated"].contains(&name)
 }
@@ -1,3 +1,4 @@
+mod client;
 mod directive;
 mod mod_file;
 mod operation;
@@ -17,8 +18,10 @@ use self::{
     util::get_interface_impl_object_map,
 };
 
+pub use client::generate_client_code;
 pub use project::create_project_files;
 use tokio::io::AsyncWriteExt;
+pub use util::parse_scalar_features;
 
 #[async_trait::async_trait]
 pub(crate) trait CreateFile {
@@ -34,13 +37,9 @@ pub(crate) trait FileDefinition {
 
 pub(crate) async fn create_file<T: FileDefinition>(file_def: T) -> Result<(), Error> {
     let path = file_def.path();
-    if tokio::fs::File::open(&path).await.is_err() {
+    if tokio::fs::File::open(&path).await.is_err() || file_def.name() == *"mod.rs" {
         let mut file = tokio::fs::File::create(&path).await?;
-        file.write(file_def.content().as_bytes()).await?;
-        Ok(())
-    } else if file_def.name() == *"mod.rs" {
-        let mut file = tokio::fs::File::create(&path).await?;
-        file.write(file_def.content().as_bytes()).await?;
+        file.write_all(file_def.content().as_bytes()).await?;
         Ok(())
     } else {
         Ok(())
@@ -56,7 +55,11 @@ pub(crate) fn path_str(paths: Vec<&str>, is_file: bool) -> String {
     }
 }
 
-pub(crate) async fn create_gql_files(schema_documents: &[&str], path: &str) -> Result<(), Error> {
+pub(crate) async fn create_gql_files(
+    schema_documents: &[&str],
+    path: &str,
+    features: &[String],
+) -> Result<(), Error> {
     let schema = match build_schema(schema_documents, Default::default()) {
         Ok(v) => v,
         Err(err) => return Err(Error::new(std::io::ErrorKind::InvalidInput, err.message)),
@@ -71,7 +74,7 @@ pub(crate) async fn create_gql_files(schema_documents: &[&str], path: &str) -> R
     try_join_all(vec![query_task, mutation_task]).await?;
 
     let interface_obj_maps = get_interface_impl_object_map(&schema.type_definitions);
-    create_type_definition_files(&schema, path, &interface_obj_maps).await?;
+    create_type_definition_files(&schema, path, &interface_obj_maps, features).await?;
     create_directive_files(&schema.directives, path).await?;
     Ok(())
 }
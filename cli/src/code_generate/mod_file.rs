@@ -31,7 +31,7 @@ impl<'a> CreateFile for ModFile<'a> {
     async fn create_file(&self) -> Result<(), std::io::Error> {
         let path = self.path();
         let mut file = tokio::fs::File::create(&path).await?;
-        file.write(self.content().as_bytes()).await?;
+        file.write_all(self.content().as_bytes()).await?;
         Ok(())
     }
 }
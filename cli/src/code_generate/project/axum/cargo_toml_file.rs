@@ -2,6 +2,7 @@ use crate::code_generate::FileDefinition;
 
 pub struct AxumCargoTomlFile<'a> {
     pub app_name: &'a str,
+    pub features: &'a [String],
 }
 
 impl<'a> FileDefinition for AxumCargoTomlFile<'a> {
@@ -10,7 +11,7 @@ impl<'a> FileDefinition for AxumCargoTomlFile<'a> {
     }
 
     fn content(&self) -> String {
-        cargo_toml_content(self.app_name)
+        cargo_toml_content(self.app_name, self.features)
     }
 
     fn name(&self) -> String {
@@ -18,7 +19,7 @@ impl<'a> FileDefinition for AxumCargoTomlFile<'a> {
     }
 }
 
-fn cargo_toml_content(app_name: &str) -> String {
+fn cargo_toml_content(app_name: &str, features: &[String]) -> String {
     r#"[package]
 name = "APP_NAME"
 version = "0.1.2"
@@ -28,9 +29,38 @@ edition = "2021"
 async-trait = "0.1.52"
 axum = {version = "0.4.2", features = ["headers"]}
 hyper = "0.14.16"
-rusty-gql = "0.1.0"
+rusty-gql = RUSTY_GQL
 rusty-gql-axum = "0.1.0"
 tokio = { version = "1.0", features = ["full"] }
 "#
     .replace("APP_NAME", app_name)
+    .replace("RUSTY_GQL", &rusty_gql_dependency(features))
+}
+
+fn rusty_gql_dependency(features: &[String]) -> String {
+    if features.is_empty() {
+        return r#""0.1.0""#.to_string();
+    }
+    let features: Vec<String> = features
+        .iter()
+        .map(|feature| format!("\"{}\"", feature))
+        .collect();
+    format!(
+        "{{version = \"0.1.0\", features = [{}]}}",
+        features.join(", ")
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::cargo_toml_content;
+
+    #[test]
+    fn test_cargo_toml_content() {
+        assert!(cargo_toml_content("app", &[]).contains("\nrusty-gql = \"0.1.0\"\n"));
+
+        let features = vec!["chrono".to_string(), "uuid".to_string()];
+        assert!(cargo_toml_content("app", &features)
+            .contains("\nrusty-gql = {version = \"0.1.0\", features = [\"chrono\", \"uuid\"]}\n"));
+    }
 }
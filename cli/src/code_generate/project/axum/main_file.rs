@@ -20,7 +20,7 @@ impl<'a> FileDefinition for AxumMainFile<'a> {
 }
 
 fn main_file_content() -> String {
-    let contents = vec![
+    let contents = [
         axum_import_str(),
         axum_gql_handler(),
         axum_gql_playground(),
@@ -30,7 +30,7 @@ fn main_file_content() -> String {
 }
 
 fn axum_import_str() -> String {
-    let statements = vec![
+    let statements = [
         "mod graphql;",
         "use rusty_gql::*;",
         "use rusty_gql_axum::*;",
@@ -49,7 +49,7 @@ fn axum_gql_handler() -> String {
     f.arg("container", "axum::extract::Extension<ContainerType>");
     f.arg("req", "GqlRequest");
     f.ret("GqlResponse");
-    f.line("let result = execute(&container, req.0).await;");
+    f.line("let result = execute_batch(&container, req.0).await;");
     f.line("GqlResponse::from(result)");
 
     scope.to_string()
@@ -71,7 +71,7 @@ fn axum_main_function() -> String {
     f.set_async(true);
     f.line("let schema_docs = read_schemas(Path::new(\"./schema\")).unwrap();");
     f.line("let schema_docs: Vec<&str> = schema_docs.iter().map(|s| &**s).collect();");
-    f.line("let container = Container::new(schema_docs.as_slice(), Query, EmptyMutation, EmptySubscription, Default::default(),).unwrap();");
+    f.line("let container = Container::builder(schema_docs.as_slice(), Query, EmptyMutation, EmptySubscription).build().unwrap();");
     f.line("let app = Router::new().route(\"/graphiql\", get(gql_playground)).route(\"/\", get(gql_handler).post(gql_handler)).layer(AddExtensionLayer::new(container));");
     f.line("let addr = SocketAddr::from(([127, 0, 0, 1], 3000));");
     f.line("axum::Server::bind(&addr).serve(app.into_make_service()).await.unwrap();");
@@ -13,7 +13,6 @@ use crate::code_generate::{
 };
 
 pub struct InputObjectFile<'a> {
-    pub filename: &'a str,
     pub def: &'a InputObjectType,
     pub path: &'a str,
 }
@@ -27,12 +26,13 @@ impl<'a> InputObjectFile<'a> {
                 file.read_to_string(&mut current_file_src).await?;
                 let content = sync_file(&current_file_src, self.def);
                 let mut new_file = tokio::fs::File::create(&path).await?;
-                new_file.write(content.as_bytes()).await?;
+                new_file.write_all(content.as_bytes()).await?;
                 Ok(())
             }
             Err(_) => {
                 let mut file = tokio::fs::File::create(&path).await?;
-                file.write(new_file_content(self.def).as_bytes()).await?;
+                file.write_all(new_file_content(self.def).as_bytes())
+                    .await?;
                 Ok(())
             }
         }
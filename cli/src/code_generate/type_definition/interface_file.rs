@@ -13,7 +13,6 @@ use crate::code_generate::{
 };
 
 pub struct InterfaceFile<'a> {
-    pub filename: &'a str,
     pub def: &'a InterfaceType,
     pub path: &'a str,
     pub interface_obj_map: &'a HashMap<String, Vec<String>>,
@@ -28,12 +27,12 @@ impl<'a> InterfaceFile<'a> {
                 file.read_to_string(&mut current_file_src).await?;
                 let content = sync_file(&current_file_src, self);
                 let mut new_file = tokio::fs::File::create(&path).await?;
-                new_file.write(content.as_bytes()).await?;
+                new_file.write_all(content.as_bytes()).await?;
                 Ok(())
             }
             Err(_) => {
                 let mut file = tokio::fs::File::create(&path).await?;
-                file.write(new_file_content(self).as_bytes()).await?;
+                file.write_all(new_file_content(self).as_bytes()).await?;
                 Ok(())
             }
         }
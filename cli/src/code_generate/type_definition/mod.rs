@@ -18,7 +18,7 @@ use self::{
 use super::{
     mod_file::ModFile,
     path_str,
-    util::{is_gql_primitive_ty, is_introspection_type_names},
+    util::{is_gql_primitive_ty, is_introspection_type_names, is_rusty_gql_scalar},
     CreateFile,
 };
 
@@ -26,6 +26,7 @@ pub async fn create_type_definition_files(
     schema: &Schema,
     base_path: &str,
     interface_obj_map: &HashMap<String, Vec<String>>,
+    features: &[String],
 ) -> Result<Vec<()>, Error> {
     let mut futures = Vec::new();
     let mut resolver_names = Vec::new();
@@ -33,13 +34,13 @@ pub async fn create_type_definition_files(
     let mut scalar_names = Vec::new();
 
     for (_, type_def) in schema.type_definitions.iter() {
-        if is_gql_primitive_ty(type_def.name()) {
+        if is_gql_primitive_ty(type_def.name()) || is_rusty_gql_scalar(type_def.name()) {
             continue;
         }
         if is_introspection_type_names(type_def.name()) {
             continue;
         }
-        let operation_type_names = vec![
+        let operation_type_names = [
             &schema.query_type_name,
             &schema.mutation_type_name,
             &schema.subscription_type_name,
@@ -58,7 +59,7 @@ pub async fn create_type_definition_files(
             TypeDefinition::Scalar(v) => scalar_names.push(v.name.clone()),
         }
 
-        let task = create_type_definition_file(type_def, base_path, interface_obj_map);
+        let task = create_type_definition_file(type_def, base_path, interface_obj_map, features);
         futures.push(task);
     }
 
@@ -90,25 +91,19 @@ async fn create_type_definition_file(
     type_def: &TypeDefinition,
     base_path: &str,
     interface_obj_map: &HashMap<String, Vec<String>>,
+    features: &[String],
 ) -> Result<(), Error> {
     let filename = type_def.name().to_snake_case();
     match type_def {
         TypeDefinition::Object(def) => {
             let path = path_str(vec![base_path, "resolver", &filename], true);
-            ObjectFile {
-                def,
-                path: &path,
-                filename: &filename,
-            }
-            .create_file()
-            .await
+            ObjectFile { def, path: &path }.create_file().await
         }
         TypeDefinition::Interface(def) => {
             let path = path_str(vec![base_path, "resolver", &filename], true);
             InterfaceFile {
                 def,
                 path: &path,
-                filename: &filename,
                 interface_obj_map,
             }
             .create_file()
@@ -116,40 +111,22 @@ async fn create_type_definition_file(
         }
         TypeDefinition::Union(def) => {
             let path = path_str(vec![base_path, "resolver", &filename], true);
-            UnionFile {
-                def,
-                path: &path,
-                filename: &filename,
-            }
-            .create_file()
-            .await
+            UnionFile { def, path: &path }.create_file().await
         }
         TypeDefinition::Enum(def) => {
             let path = path_str(vec![base_path, "resolver", &filename], true);
-            EnumFile {
-                def,
-                path: &path,
-                filename: &filename,
-            }
-            .create_file()
-            .await
+            EnumFile { def, path: &path }.create_file().await
         }
         TypeDefinition::InputObject(def) => {
             let path = path_str(vec![base_path, "input", &filename], true);
-            InputObjectFile {
-                def,
-                path: &path,
-                filename: &filename,
-            }
-            .create_file()
-            .await
+            InputObjectFile { def, path: &path }.create_file().await
         }
         TypeDefinition::Scalar(def) => {
             let path = path_str(vec![base_path, "scalar", &filename], true);
             ScalarFile {
                 def,
                 path: &path,
-                filename: &filename,
+                features,
             }
             .create_file()
             .await
@@ -13,7 +13,6 @@ use crate::code_generate::{
 };
 
 pub struct ObjectFile<'a> {
-    pub filename: &'a str,
     pub def: &'a ObjectType,
     pub path: &'a str,
 }
@@ -27,12 +26,13 @@ impl<'a> ObjectFile<'a> {
                 file.read_to_string(&mut current_file_src).await?;
                 let content = sync_file(&current_file_src, self.def);
                 let mut new_file = tokio::fs::File::create(&path).await?;
-                new_file.write(content.as_bytes()).await?;
+                new_file.write_all(content.as_bytes()).await?;
                 Ok(())
             }
             Err(_) => {
                 let mut file = tokio::fs::File::create(&path).await?;
-                file.write(new_file_content(self.def).as_bytes()).await?;
+                file.write_all(new_file_content(self.def).as_bytes())
+                    .await?;
                 Ok(())
             }
         }
@@ -208,7 +208,7 @@ fn is_return_primitive_ty(field: &FieldType) -> bool {
 }
 
 fn is_copy_gql_ty(field: &FieldType) -> bool {
-    vec!["Int", "Float", "Boolean"].contains(&field.meta_type.name())
+    ["Int", "Float", "Boolean"].contains(&field.meta_type.name())
 }
 
 fn build_block_str(field: &FieldType, name: &str) -> String {
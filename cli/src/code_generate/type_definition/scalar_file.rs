@@ -2,12 +2,12 @@ use codegen::Scope;
 use rusty_gql::ScalarType;
 use tokio::io::AsyncWriteExt;
 
-use crate::code_generate::{use_gql_definitions, CreateFile};
+use crate::code_generate::{use_gql_definitions, util::feature_scalar_type, CreateFile};
 
 pub struct ScalarFile<'a> {
-    pub filename: &'a str,
     pub def: &'a ScalarType,
     pub path: &'a str,
+    pub features: &'a [String],
 }
 
 #[async_trait::async_trait]
@@ -18,14 +18,24 @@ impl<'a> CreateFile for ScalarFile<'a> {
             Ok(_) => Ok(()),
             Err(_) => {
                 let mut file = tokio::fs::File::create(&path).await?;
-                file.write(new_file_content(self.def).as_bytes()).await?;
+                file.write_all(new_file_content(self.def, self.features).as_bytes())
+                    .await?;
                 Ok(())
             }
         }
     }
 }
 
-fn new_file_content(scalar_def: &ScalarType) -> String {
+fn new_file_content(scalar_def: &ScalarType, features: &[String]) -> String {
+    if let Some(ty) = feature_scalar_type(&scalar_def.name, features) {
+        return format!(
+            "{}\n\npub type {} = {};\n",
+            use_gql_definitions(),
+            scalar_def.name,
+            ty
+        );
+    }
+
     let mut scope = Scope::new();
     let struct_name = &scalar_def.name;
     let scalar_scope = scope.new_struct(struct_name).vis("pub");
@@ -45,3 +55,25 @@ fn new_file_content(scalar_def: &ScalarType) -> String {
 
     format!("{}\n\n{}", use_gql_definitions(), scope.to_string())
 }
+
+#[cfg(test)]
+mod tests {
+    use rusty_gql::ScalarType;
+
+    use super::new_file_content;
+
+    #[test]
+    fn test_feature_scalar_file() {
+        let def = ScalarType {
+            name: "Date".to_string(),
+            description: None,
+            position: Default::default(),
+            directives: Default::default(),
+        };
+        let content = new_file_content(&def, &["chrono".to_string()]);
+        assert!(content.ends_with("pub type Date = rusty_gql::chrono::NaiveDate;\n"));
+
+        let content = new_file_content(&def, &[]);
+        assert!(content.contains("pub struct Date;"));
+    }
+}
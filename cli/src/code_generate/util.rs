@@ -54,11 +54,97 @@ fn gql_to_rust_type_str(gql_type: &str, optional: bool) -> String {
 }
 
 pub fn is_gql_primitive_ty(type_name: &str) -> bool {
-    vec!["String", "Int", "Float", "Boolean", "ID"].contains(&type_name)
+    ["String", "Int", "Float", "Boolean", "ID"].contains(&type_name)
+}
+
+pub fn is_rusty_gql_scalar(type_name: &str) -> bool {
+    type_name == "Upload"
+}
+
+/// Cargo features of rusty-gql that implement common scalars.
+pub const SCALAR_FEATURES: &[&str] = &[
+    "bigdecimal",
+    "chrono",
+    "json",
+    "rust_decimal",
+    "time",
+    "url",
+    "uuid",
+];
+
+// Feature, scalar name and the type re-exported from rusty-gql.
+const FEATURE_SCALARS: &[(&str, &str, &str)] = &[
+    (
+        "bigdecimal",
+        "BigDecimal",
+        "rusty_gql::bigdecimal::BigDecimal",
+    ),
+    (
+        "chrono",
+        "DateTime",
+        "rusty_gql::chrono::DateTime<rusty_gql::chrono::Utc>",
+    ),
+    ("chrono", "Date", "rusty_gql::chrono::NaiveDate"),
+    ("chrono", "Time", "rusty_gql::chrono::NaiveTime"),
+    (
+        "chrono",
+        "LocalDateTime",
+        "rusty_gql::chrono::NaiveDateTime",
+    ),
+    ("json", "JSON", "rusty_gql::serde_json::Value"),
+    (
+        "rust_decimal",
+        "Decimal",
+        "rusty_gql::rust_decimal::Decimal",
+    ),
+    ("time", "DateTime", "rusty_gql::time::OffsetDateTime"),
+    ("time", "Date", "rusty_gql::time::Date"),
+    ("time", "Time", "rusty_gql::time::Time"),
+    (
+        "time",
+        "LocalDateTime",
+        "rusty_gql::time::PrimitiveDateTime",
+    ),
+    ("url", "Url", "rusty_gql::url::Url"),
+    ("uuid", "UUID", "rusty_gql::uuid::Uuid"),
+];
+
+/// Parses a comma separated list of scalar features.
+pub fn parse_scalar_features(value: Option<&str>) -> Result<Vec<String>, String> {
+    let mut features = Vec::new();
+    for feature in value.unwrap_or_default().split(',').map(str::trim) {
+        if feature.is_empty() || features.iter().any(|f| f == feature) {
+            continue;
+        }
+        if !SCALAR_FEATURES.contains(&feature) {
+            return Err(format!(
+                "Unknown feature {}, expected one of {}.",
+                feature,
+                SCALAR_FEATURES.join(", ")
+            ));
+        }
+        features.push(feature.to_string());
+    }
+    if features.iter().any(|f| f == "chrono") && features.iter().any(|f| f == "time") {
+        return Err(
+            "chrono and time implement the same scalars, so only one of them can be used."
+                .to_string(),
+        );
+    }
+    features.sort();
+    Ok(features)
+}
+
+/// Type of the scalar implemented by rusty-gql behind one of `features`.
+pub fn feature_scalar_type(type_name: &str, features: &[String]) -> Option<&'static str> {
+    FEATURE_SCALARS
+        .iter()
+        .find(|(feature, name, _)| *name == type_name && features.iter().any(|f| f == feature))
+        .map(|(_, _, ty)| *ty)
 }
 
 pub fn is_introspection_type_names(type_name: &str) -> bool {
-    vec![
+    [
         "__Directive",
         "__DirectiveLocation",
         "__EnumValue",
@@ -89,3 +175,30 @@ pub fn is_default_item_use(item_use: &ItemUse) -> bool {
     }
     false
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{feature_scalar_type, parse_scalar_features};
+
+    #[test]
+    fn test_parse_scalar_features() {
+        assert_eq!(parse_scalar_features(None), Ok(vec![]));
+        assert_eq!(
+            parse_scalar_features(Some("uuid, chrono,uuid")),
+            Ok(vec!["chrono".to_string(), "uuid".to_string()])
+        );
+        assert!(parse_scalar_features(Some("serde")).is_err());
+        assert!(parse_scalar_features(Some("chrono,time")).is_err());
+    }
+
+    #[test]
+    fn test_feature_scalar_type() {
+        let features = vec!["time".to_string()];
+        assert_eq!(
+            feature_scalar_type("Date", &features),
+            Some("rusty_gql::time::Date")
+        );
+        assert_eq!(feature_scalar_type("UUID", &features), None);
+        assert_eq!(feature_scalar_type("Date", &[]), None);
+    }
+}
@@ -1,32 +1,45 @@
-use anyhow::Result;
+use anyhow::{anyhow, Result};
 use app::build_app;
 use async_recursion::async_recursion;
 use exit_codes::ExitCode;
 use std::process::Command;
-use std::{path::Path, process};
+use std::{
+    path::{Path, PathBuf},
+    process,
+};
 
-use crate::code_generate::{create_gql_files, create_project_files};
+use crate::check::{check_operation_files, check_schema_files, CombinedSource, SourceError};
+use crate::code_generate::{
+    create_gql_files, create_project_files, generate_client_code, parse_scalar_features,
+};
 
 mod app;
+mod check;
 mod code_generate;
 mod exit_codes;
 
 #[async_recursion]
-async fn visit_dirs(path: &Path) -> std::io::Result<Vec<String>> {
+async fn visit_schema_files(path: &Path) -> std::io::Result<Vec<(PathBuf, String)>> {
     let mut dir = tokio::fs::read_dir(path).await?;
     let mut schemas = Vec::new();
     while let Some(child) = dir.next_entry().await? {
         if child.metadata().await?.is_dir() {
-            visit_dirs(&child.path()).await?;
+            schemas.extend(visit_schema_files(&child.path()).await?);
         } else {
             let content = tokio::fs::read_to_string(child.path()).await?;
-            schemas.push(content)
+            schemas.push((child.path(), content))
         }
     }
+    schemas.sort_by(|a, b| a.0.cmp(&b.0));
 
     Ok(schemas)
 }
 
+async fn visit_dirs(path: &Path) -> std::io::Result<Vec<String>> {
+    let schemas = visit_schema_files(path).await?;
+    Ok(schemas.into_iter().map(|(_, content)| content).collect())
+}
+
 fn gql_files_path(app_name: Option<&str>) -> String {
     match app_name {
         Some(path) => format!("{}/src/graphql", path),
@@ -34,7 +47,10 @@ fn gql_files_path(app_name: Option<&str>) -> String {
     }
 }
 
-async fn create_graphql_files(app_name: Option<&str>) -> Result<(), std::io::Error> {
+async fn create_graphql_files(
+    app_name: Option<&str>,
+    features: &[String],
+) -> Result<(), std::io::Error> {
     let path = app_name
         .map(|name| format!("{}/schema", name))
         .unwrap_or_else(|| "schema".to_string());
@@ -43,33 +59,177 @@ async fn create_graphql_files(app_name: Option<&str>) -> Result<(), std::io::Err
     let schema_contents: Vec<&str> = schema_contents.iter().map(|s| &**s).collect();
 
     let gql_files_path = gql_files_path(app_name);
-    create_gql_files(&schema_contents, &gql_files_path).await
+    create_gql_files(&schema_contents, &gql_files_path, features).await
+}
+
+async fn load_schema(path: &str) -> Result<rusty_gql::Schema> {
+    let path = Path::new(path);
+    let schema_contents = if path.is_dir() {
+        visit_dirs(path).await?
+    } else {
+        vec![tokio::fs::read_to_string(path).await?]
+    };
+    let schema_contents: Vec<&str> = schema_contents.iter().map(|s| &**s).collect();
+
+    rusty_gql::build_schema(&schema_contents, Default::default())
+        .map_err(|err| anyhow!("{}: {}", path.display(), err.message))
+}
+
+fn diff_report(
+    old_schema: &rusty_gql::Schema,
+    new_schema: &rusty_gql::Schema,
+) -> (Vec<String>, ExitCode) {
+    let changes = rusty_gql::diff_schema(old_schema, new_schema);
+    let lines = changes
+        .iter()
+        .map(|change| format!("{} {}", change.level, change.message))
+        .collect();
+
+    if changes.iter().any(|change| change.is_breaking()) {
+        (lines, ExitCode::Failure)
+    } else {
+        (lines, ExitCode::Success)
+    }
+}
+
+async fn diff_schemas(old_path: &str, new_path: &str) -> Result<ExitCode> {
+    let old_schema = load_schema(old_path).await?;
+    let new_schema = load_schema(new_path).await?;
+    let (lines, code) = diff_report(&old_schema, &new_schema);
+
+    if lines.is_empty() {
+        println!("No changes detected.");
+    }
+    for line in &lines {
+        println!("{}", line);
+    }
+    Ok(code)
+}
+
+async fn read_files(path: &str) -> Result<Vec<(PathBuf, String)>> {
+    let path = Path::new(path);
+    if path.is_dir() {
+        Ok(visit_schema_files(path).await?)
+    } else {
+        Ok(vec![(
+            path.to_path_buf(),
+            tokio::fs::read_to_string(path).await?,
+        )])
+    }
+}
+
+fn report_errors(errors: &[SourceError], target: &str) {
+    for error in errors {
+        eprintln!("{}", error);
+    }
+    eprintln!("Found {} error(s) in the {}.", errors.len(), target);
+}
+
+async fn check_schema(path: &str) -> Result<ExitCode> {
+    let files = read_files(path).await?;
+
+    let errors = check_schema_files(&files);
+    if errors.is_empty() {
+        println!("No errors found in {} schema file(s).", files.len());
+        return Ok(ExitCode::Success);
+    }
+
+    report_errors(&errors, "schema");
+    Ok(ExitCode::Failure)
+}
+
+async fn generate_client(
+    operations_path: &str,
+    schema_path: &str,
+    output_path: &str,
+) -> Result<ExitCode> {
+    let schema = load_schema(schema_path).await?;
+    let files = read_files(operations_path).await?;
+
+    let errors = check_operation_files(&files);
+    if !errors.is_empty() {
+        report_errors(&errors, "operations");
+        return Ok(ExitCode::Failure);
+    }
+    let source = CombinedSource::new(&files);
+    let code = match generate_client_code(&schema, &source.content) {
+        Ok(code) => code,
+        Err(errors) => {
+            let errors: Vec<SourceError> =
+                errors.into_iter().map(|err| source.error(err)).collect();
+            report_errors(&errors, "operations");
+            return Ok(ExitCode::Failure);
+        }
+    };
+
+    if let Some(parent) = Path::new(output_path).parent() {
+        tokio::fs::create_dir_all(parent).await?;
+    }
+    tokio::fs::write(output_path, code).await?;
+    // The generated code is valid without formatting, so a missing rustfmt is not an error.
+    match Command::new("rustfmt")
+        .args(["--edition", "2021", output_path])
+        .status()
+    {
+        Ok(status) if status.success() => {}
+        Ok(status) => eprintln!("warning: rustfmt exited with {}.", status),
+        Err(err) => eprintln!("warning: failed to run rustfmt: {}", err),
+    }
+    println!("Successfully generated {}!", output_path);
+    Ok(ExitCode::Success)
 }
 
 fn run_fmt() {
     Command::new("cargo")
         .arg("fmt")
-        .spawn()
+        .status()
         .expect("Failed to run cargo fmt.");
 }
 
 async fn run() -> Result<ExitCode> {
     let matches = build_app().get_matches();
-    if matches.subcommand_matches("generate").is_some() {
-        create_graphql_files(None).await?;
+    if let Some(generate_matches) = matches.subcommand_matches("generate") {
+        let features = parse_scalar_features(generate_matches.value_of("features"))
+            .map_err(|err| anyhow!(err))?;
+        create_graphql_files(None, &features).await?;
         run_fmt();
         return Ok(ExitCode::Success);
     }
 
     if let Some(new_matches) = matches.subcommand_matches("new") {
         if let Some(app_name) = new_matches.value_of("name") {
-            create_project_files(app_name).await?;
-            create_graphql_files(Some(app_name)).await?;
+            let features = parse_scalar_features(new_matches.value_of("features"))
+                .map_err(|err| anyhow!(err))?;
+            create_project_files(app_name, &features).await?;
+            create_graphql_files(Some(app_name), &features).await?;
             println!("Successfully created the rusty-gql project!");
             return Ok(ExitCode::Success);
         }
     }
 
+    if let Some(diff_matches) = matches.subcommand_matches("diff") {
+        if let (Some(old_path), Some(new_path)) =
+            (diff_matches.value_of("old"), diff_matches.value_of("new"))
+        {
+            return diff_schemas(old_path, new_path).await;
+        }
+    }
+
+    if let Some(check_matches) = matches.subcommand_matches("check") {
+        let path = check_matches.value_of("path").unwrap_or("schema");
+        return check_schema(path).await;
+    }
+
+    if let Some(client_matches) = matches.subcommand_matches("client") {
+        if let Some(operations_path) = client_matches.value_of("operations") {
+            let schema_path = client_matches.value_of("schema").unwrap_or("schema");
+            let output_path = client_matches
+                .value_of("output")
+                .unwrap_or("src/graphql_client.rs");
+            return generate_client(operations_path, schema_path, output_path).await;
+        }
+    }
+
     Ok(ExitCode::Success)
 }
 
@@ -84,3 +244,89 @@ async fn main() {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{diff_report, diff_schemas, exit_codes::ExitCode};
+
+    const OLD_SCHEMA: &str = r#"
+        type Query { user(id: ID!): User }
+        type User { name: String! nickname: String @deprecated }
+    "#;
+
+    fn build_schema(schema_doc: &str) -> rusty_gql::Schema {
+        rusty_gql::build_schema(&[schema_doc], Default::default()).unwrap()
+    }
+
+    #[test]
+    fn test_diff_report() {
+        let new_schema = r#"
+            type Query { user(id: ID!, name: String): User }
+            type User { name: String! }
+        "#;
+        let (lines, code) = diff_report(&build_schema(OLD_SCHEMA), &build_schema(new_schema));
+        assert_eq!(
+            lines,
+            vec![
+                "BREAKING Field User.nickname was removed",
+                "DANGEROUS Optional argument name was added to Query.user",
+            ]
+        );
+        assert_eq!(code, ExitCode::Failure);
+    }
+
+    #[test]
+    fn test_diff_report_without_breaking_changes() {
+        let new_schema = r#"
+            type Query { user(id: ID!): User }
+            type User { name: String! nickname: String @deprecated age: Int }
+        "#;
+        let (lines, code) = diff_report(&build_schema(OLD_SCHEMA), &build_schema(new_schema));
+        assert_eq!(lines, vec!["SAFE Field User.age was added"]);
+        assert_eq!(code, ExitCode::Success);
+
+        let (lines, code) = diff_report(&build_schema(OLD_SCHEMA), &build_schema(OLD_SCHEMA));
+        assert!(lines.is_empty());
+        assert_eq!(code, ExitCode::Success);
+    }
+
+    #[tokio::test]
+    async fn test_diff_schema_directories() {
+        let dir = tempfile::tempdir().unwrap();
+        let old_dir = dir.path().join("old");
+        std::fs::create_dir_all(old_dir.join("types")).unwrap();
+        std::fs::write(
+            old_dir.join("query.graphql"),
+            "type Query { user(id: ID!): User }",
+        )
+        .unwrap();
+        std::fs::write(
+            old_dir.join("types/user.graphql"),
+            "type User { name: String! }",
+        )
+        .unwrap();
+        let new_file = dir.path().join("new.graphql");
+        std::fs::write(
+            &new_file,
+            "type Query { user(id: ID!): User } type User { name: String! }",
+        )
+        .unwrap();
+
+        // The files in the subdirectories of the old schema are loaded as one schema.
+        let old_path = old_dir.to_str().unwrap();
+        let code = diff_schemas(old_path, new_file.to_str().unwrap())
+            .await
+            .unwrap();
+        assert_eq!(code, ExitCode::Success);
+
+        std::fs::write(
+            &new_file,
+            "type Query { user(id: ID!): User } type User { id: ID! }",
+        )
+        .unwrap();
+        let code = diff_schemas(old_path, new_file.to_str().unwrap())
+            .await
+            .unwrap();
+        assert_eq!(code, ExitCode::Failure);
+    }
+}
@@ -0,0 +1,105 @@
+use std::{path::Path, sync::Mutex};
+
+use actix_web::{web, App, HttpResponse, HttpServer};
+use rusty_gql::*;
+use rusty_gql_actix_web::*;
+
+#[derive(Clone)]
+struct Todo {
+    id: usize,
+    title: String,
+    done: bool,
+}
+
+#[GqlType]
+impl Todo {
+    async fn id(&self) -> ID {
+        ID(self.id.to_string())
+    }
+    async fn title(&self) -> String {
+        self.title.clone()
+    }
+    async fn done(&self) -> bool {
+        self.done
+    }
+}
+
+type Todos = Mutex<Vec<Todo>>;
+
+struct Query;
+
+#[GqlType]
+impl Query {
+    async fn todos(&self, ctx: &Context<'_>, done: Option<bool>) -> Result<Vec<Todo>, Error> {
+        let todos = ctx.get_data::<Todos>()?.lock().unwrap();
+        Ok(todos
+            .iter()
+            .filter(|todo| done.is_none_or(|done| todo.done == done))
+            .cloned()
+            .collect())
+    }
+
+    async fn todo(&self, ctx: &Context<'_>, id: ID) -> Result<Option<Todo>, Error> {
+        let todos = ctx.get_data::<Todos>()?.lock().unwrap();
+        Ok(todos
+            .iter()
+            .find(|todo| todo.id.to_string() == id.0)
+            .cloned())
+    }
+}
+
+struct Mutation;
+
+#[GqlType]
+impl Mutation {
+    #[allow(non_snake_case)]
+    async fn addTodo(&self, ctx: &Context<'_>, title: String) -> Result<Todo, Error> {
+        let mut todos = ctx.get_data::<Todos>()?.lock().unwrap();
+        let todo = Todo {
+            id: todos.len() + 1,
+            title,
+            done: false,
+        };
+        todos.push(todo.clone());
+        Ok(todo)
+    }
+}
+
+type ContainerType = Container<Query, Mutation, EmptySubscription>;
+
+async fn gql_handler(container: web::Data<ContainerType>, req: GqlRequest) -> GqlResponse {
+    let result = execute_batch(&container, req.0).await;
+    GqlResponse::from(result)
+}
+
+async fn gql_playground() -> HttpResponse {
+    playground("/", None)
+}
+
+#[actix_web::main]
+async fn main() -> std::io::Result<()> {
+    let schema_docs = read_schemas(Path::new("./examples/actix_web/schemas"))?;
+    let schema_docs: Vec<&str> = schema_docs.iter().map(|s| &**s).collect();
+
+    let todos: Todos = Mutex::new(vec![Todo {
+        id: 1,
+        title: "Write a GraphQL server".to_string(),
+        done: true,
+    }]);
+    let container = Container::builder(schema_docs.as_slice(), Query, Mutation, EmptySubscription)
+        .data(todos)
+        .build()
+        .unwrap();
+    let container = web::Data::new(container);
+
+    HttpServer::new(move || {
+        App::new()
+            .app_data(container.clone())
+            .route("/graphiql", web::get().to(gql_playground))
+            .route("/", web::get().to(gql_handler))
+            .route("/", web::post().to(gql_handler))
+    })
+    .bind(("127.0.0.1", 3000))?
+    .run()
+    .await
+}
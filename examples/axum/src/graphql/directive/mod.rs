@@ -1,3 +1 @@
 mod possible_types;
-
-pub use possible_types::possibleTypes;
@@ -3,11 +3,8 @@ mod input;
 mod mutation;
 mod query;
 mod resolver;
-mod scalar;
 
-pub use directive::*;
 pub use input::*;
 pub use mutation::*;
 pub use query::*;
 pub use resolver::*;
-pub use scalar::*;
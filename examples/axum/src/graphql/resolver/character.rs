@@ -29,7 +29,7 @@ impl Character {
         ctx: &Context<'_>,
         first: Option<i32>,
         after: Option<ID>,
-    ) -> Result<FriendsConnection, Error> {
+    ) -> Result<Connection<Character>, Error> {
         match self {
             Character::Human(obj) => obj.friends(&ctx, first, after).await,
             Character::Droid(obj) => obj.friends(&ctx, first, after).await,
@@ -45,39 +45,20 @@ impl Human {
         ctx: &Context<'_>,
         first: Option<i32>,
         after: Option<ID>,
-    ) -> FriendsConnection {
-        if self.id.0 == "2".to_string() {
-            FriendsConnection {
-                totalCount: Some(0),
-                edges: vec![],
-                pageInfo: PageInfo {
-                    startCursor: None,
-                    endCursor: None,
-                    hasPreviousPage: false,
-                    hasNextPage: false,
-                },
-            }
+    ) -> Result<Connection<Character>, Error> {
+        let friends = if self.id.0 == "2".to_string() {
+            vec![]
         } else {
-            FriendsConnection {
-                totalCount: Some(2),
-                edges: vec![
-                    FriendsEdge {
-                        cursor: ID::from("1"),
-                        node: Some(Character::Human(luke())),
-                    },
-                    FriendsEdge {
-                        cursor: ID::from("3"),
-                        node: Some(Character::Human(han())),
-                    },
-                ],
-                pageInfo: PageInfo {
-                    startCursor: None,
-                    endCursor: None,
-                    hasPreviousPage: false,
-                    hasNextPage: false,
-                },
-            }
-        }
+            vec![Character::Human(luke()), Character::Human(han())]
+        };
+        let connection = connection_from_slice(
+            &friends,
+            first,
+            after.as_ref().map(|id| id.as_str()),
+            None,
+            None,
+        )?;
+        Ok(connection.with_name("Friends"))
     }
 
     pub async fn appearsIn(&self, ctx: &Context<'_>) -> Vec<Episode> {
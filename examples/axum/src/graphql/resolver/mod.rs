@@ -1,21 +1,15 @@
 mod character;
 mod droid;
 mod episode;
-mod friends_connection;
-mod friends_edge;
 mod human;
 mod length_unit;
-mod page_info;
 mod review;
 mod search_result;
 
 pub use character::Character;
 pub use droid::Droid;
 pub use episode::Episode;
-pub use friends_connection::FriendsConnection;
-pub use friends_edge::FriendsEdge;
 pub use human::Human;
 pub use length_unit::LengthUnit;
-pub use page_info::PageInfo;
 pub use review::Review;
 pub use search_result::SearchResult;
@@ -1,3 +0,0 @@
-mod date_time;
-
-pub use date_time::DateTime;
@@ -9,14 +9,14 @@ use axum::{
     extract::Extension,
     response::{self, IntoResponse},
     routing::get,
-    AddExtensionLayer, Router,
+    Router,
 };
 use graphql::{Mutation, Query};
 
 type ContainerType = Container<Query, Mutation, EmptySubscription>;
 
 async fn gql_handler(container: Extension<ContainerType>, req: GqlRequest) -> GqlResponse {
-    let result = execute(&container, req.0).await;
+    let result = execute_batch(&container, req.0).await;
     GqlResponse::from(result)
 }
 
@@ -29,18 +29,13 @@ async fn main() {
     let schema_docs = read_schemas(Path::new("./examples/axum/schemas")).unwrap();
     let schema_docs: Vec<&str> = schema_docs.iter().map(|s| &**s).collect();
 
-    let container = Container::new(
-        schema_docs.as_slice(),
-        Query,
-        Mutation,
-        EmptySubscription,
-        Default::default(),
-    )
-    .unwrap();
+    let container = Container::builder(schema_docs.as_slice(), Query, Mutation, EmptySubscription)
+        .build()
+        .unwrap();
     let app = Router::new()
         .route("/graphiql", get(gql_playground))
         .route("/", get(gql_handler).post(gql_handler))
-        .layer(AddExtensionLayer::new(container));
+        .layer(Extension(container));
     let addr = SocketAddr::from(([127, 0, 0, 1], 3000));
     axum::Server::bind(&addr)
         .serve(app.into_make_service())
@@ -1,14 +1,162 @@
+mod playground;
 mod request;
 mod response;
 
+pub use playground::playground;
 pub use request::GqlRequest;
 pub use response::GqlResponse;
 
 #[cfg(test)]
 mod tests {
-    #[test]
-    fn it_works() {
-        let result = 2 + 2;
-        assert_eq!(result, 4);
+    use actix_web::{
+        http::{header, StatusCode},
+        test, web, App,
+    };
+    use rusty_gql::*;
+
+    use crate::{GqlRequest, GqlResponse};
+
+    struct Query;
+
+    #[GqlType]
+    impl Query {
+        async fn value(&self) -> i32 {
+            10
+        }
+    }
+
+    struct Mutation;
+
+    #[GqlType]
+    impl Mutation {
+        async fn increment(&self, value: i32) -> i32 {
+            value + 1
+        }
+    }
+
+    type ContainerType = Container<Query, Mutation, EmptySubscription>;
+
+    async fn gql_handler(container: web::Data<ContainerType>, req: GqlRequest) -> GqlResponse {
+        let mut response = execute_batch(&container, req.0).await;
+        if let BatchResponse::Single(response) = &mut response {
+            response
+                .http_headers
+                .insert(header::CACHE_CONTROL, "no-cache".to_string());
+        }
+        GqlResponse::from(response)
+    }
+
+    fn container() -> ContainerType {
+        let schema_doc = r#"
+            type Query { value: Int! }
+            type Mutation { increment(value: Int!): Int! }
+        "#;
+        Container::builder(&[schema_doc], Query, Mutation, EmptySubscription)
+            .build()
+            .unwrap()
+    }
+
+    #[actix_web::test]
+    async fn test_get_request() {
+        let app = test::init_service(
+            App::new()
+                .app_data(web::Data::new(container()))
+                .route("/", web::get().to(gql_handler)),
+        )
+        .await;
+
+        let req = test::TestRequest::get()
+            .uri("/?query=%7B%20value%20%7D")
+            .to_request();
+        let res = test::call_service(&app, req).await;
+        assert_eq!(res.status(), StatusCode::OK);
+        assert_eq!(
+            res.headers().get(header::CACHE_CONTROL).unwrap(),
+            "no-cache"
+        );
+        let body = test::read_body(res).await;
+        assert_eq!(body, r#"{"data":{"value":10}}"#);
+
+        let req = test::TestRequest::get()
+            .uri("/?query=mutation%20%7B%20increment(value%3A%201)%20%7D")
+            .to_request();
+        let res = test::call_service(&app, req).await;
+        assert_eq!(res.status(), StatusCode::METHOD_NOT_ALLOWED);
+    }
+
+    #[actix_web::test]
+    async fn test_post_request() {
+        let app = test::init_service(
+            App::new()
+                .app_data(web::Data::new(container()))
+                .route("/", web::post().to(gql_handler)),
+        )
+        .await;
+
+        let req = test::TestRequest::post()
+            .uri("/")
+            .insert_header((header::CONTENT_TYPE, "application/json"))
+            .insert_header((header::ACCEPT, "application/graphql-response+json"))
+            .set_payload(
+                r#"[{"query": "mutation { increment(value: 1) }"}, {"query": "{ value }"}]"#,
+            )
+            .to_request();
+        let res = test::call_service(&app, req).await;
+        assert_eq!(res.status(), StatusCode::OK);
+        assert_eq!(
+            res.headers().get(header::CONTENT_TYPE).unwrap(),
+            "application/graphql-response+json"
+        );
+        let body = test::read_body(res).await;
+        assert_eq!(body, r#"[{"data":{"increment":2}},{"data":{"value":10}}]"#);
+
+        let req = test::TestRequest::post()
+            .uri("/")
+            .insert_header((header::CONTENT_TYPE, "text/plain"))
+            .set_payload("{ value }")
+            .to_request();
+        let res = test::call_service(&app, req).await;
+        assert_eq!(res.status(), StatusCode::UNSUPPORTED_MEDIA_TYPE);
+    }
+
+    #[actix_web::test]
+    async fn test_multipart_options() {
+        let body = concat!(
+            "--boundary\r\nContent-Disposition: form-data; name=\"operations\"\r\n\r\n",
+            r#"{"query": "{ value }", "variables": {"file": null}}"#,
+            "\r\n--boundary\r\nContent-Disposition: form-data; name=\"map\"\r\n\r\n",
+            r#"{"0": ["variables.file"]}"#,
+            "\r\n--boundary\r\nContent-Disposition: form-data; name=\"0\"; filename=\"a.txt\"\r\n",
+            "Content-Type: text/plain\r\n\r\ncontent a\r\n--boundary--\r\n",
+        );
+        let request = || {
+            test::TestRequest::post()
+                .uri("/")
+                .insert_header((
+                    header::CONTENT_TYPE,
+                    "multipart/form-data; boundary=boundary",
+                ))
+                .set_payload(body)
+                .to_request()
+        };
+
+        let app = test::init_service(
+            App::new()
+                .app_data(web::Data::new(container()))
+                .route("/", web::post().to(gql_handler)),
+        )
+        .await;
+        let res = test::call_service(&app, request()).await;
+        assert_eq!(res.status(), StatusCode::OK);
+
+        let app = test::init_service(
+            App::new()
+                .app_data(web::Data::new(container()))
+                .app_data(MultipartOptions::default().max_num_files(0))
+                .route("/", web::post().to(gql_handler)),
+        )
+        .await;
+        let res = test::call_service(&app, request()).await;
+        assert_eq!(res.status(), StatusCode::PAYLOAD_TOO_LARGE);
     }
 }
@@ -0,0 +1,8 @@
+use actix_web::HttpResponse;
+use rusty_gql::playground_html;
+
+pub fn playground(endpoint: &str, subscription_endpoint: Option<&str>) -> HttpResponse {
+    HttpResponse::Ok()
+        .content_type("text/html; charset=utf-8")
+        .body(playground_html(endpoint, subscription_endpoint))
+}
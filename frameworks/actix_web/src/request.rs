@@ -1,25 +1,66 @@
-use std::pin::Pin;
+use std::{future::Future, io, pin::Pin};
 
 use actix_web::{
-    http::{Error, Method},
-    Error, FromRequest, Result,
+    dev::Payload, error::InternalError, http::Method, Error, FromRequest, HttpRequest, HttpResponse,
 };
-use futures::Future;
+use futures_util::{SinkExt, StreamExt, TryStreamExt};
+use rusty_gql::{receive_batch_http_request, BatchRequest, HttpRequestError, MultipartOptions};
 
-pub struct GqlRequest(pub rusty_gql::Request);
+/// Reads `MultipartOptions` from the app data and uses the default options otherwise.
+pub struct GqlRequest(pub BatchRequest);
 
 impl FromRequest for GqlRequest {
     type Error = Error;
 
-    type Future;
+    type Future = Pin<Box<dyn Future<Output = Result<Self, Self::Error>>>>;
 
-    fn from_request(
-        req: &actix_web::HttpRequest,
-        payload: &mut actix_web::dev::Payload,
-    ) -> Self::Future {
-        if req.method() == Method::GET {
-            let body = serde_urlencoded::from_str(req.query_string());
-            Box::pin(async move { Ok(Self(rusty_gql::Request(body?))) })
+    fn from_request(req: &HttpRequest, payload: &mut Payload) -> Self::Future {
+        let method = req.method().clone();
+        let query_string = req.query_string().to_string();
+        let options = req
+            .app_data::<MultipartOptions>()
+            .copied()
+            .unwrap_or_default();
+        let mut headers = http::HeaderMap::new();
+        for (name, value) in req.headers() {
+            headers.append(name.clone(), value.clone());
         }
+
+        // actix-web payload is not Send, so forward it to the request parser through a channel.
+        let (mut tx, rx) = futures_channel::mpsc::channel(16);
+        let mut payload = payload.take();
+        if method != Method::GET {
+            actix_web::rt::spawn(async move {
+                while let Some(chunk) = payload.next().await {
+                    let chunk = chunk.map_err(|err| io::Error::other(err.to_string()));
+                    if tx.send(chunk).await.is_err() {
+                        return;
+                    }
+                }
+            });
+        }
+
+        Box::pin(async move {
+            let request = receive_batch_http_request(
+                &method,
+                &headers,
+                Some(&query_string),
+                rx.into_async_read(),
+                options,
+            )
+            .await
+            .map_err(rejection)?;
+            Ok(Self(request))
+        })
+    }
+}
+
+fn rejection(error: HttpRequestError) -> Error {
+    let message = error.to_string();
+    let http_response = error.into_http_response();
+    let mut response = HttpResponse::build(http_response.status());
+    for (name, value) in http_response.headers() {
+        response.append_header((name.clone(), value.clone()));
     }
+    InternalError::from_response(message, response.body(http_response.into_body())).into()
 }
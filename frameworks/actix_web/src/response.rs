@@ -1,24 +1,29 @@
-use actix_web::{
-    http::{Error, StatusCode},
-    HttpResponse, Responder,
-};
-use futures_util::future::{ready, Ready};
+use actix_web::{body::BoxBody, HttpResponse, Responder};
+use rusty_gql::BatchResponse;
 
-pub struct GqlResponse(pub rusty_gql::Response);
+pub struct GqlResponse(pub BatchResponse);
 
 impl From<rusty_gql::Response> for GqlResponse {
     fn from(response: rusty_gql::Response) -> Self {
+        GqlResponse(BatchResponse::Single(response))
+    }
+}
+
+impl From<BatchResponse> for GqlResponse {
+    fn from(response: BatchResponse) -> Self {
         GqlResponse(response)
     }
 }
 
 impl Responder for GqlResponse {
-    type Body = HttpResponse;
+    type Body = BoxBody;
 
     fn respond_to(self, _: &actix_web::HttpRequest) -> HttpResponse {
-        let body = serde_json::to_string(&self.0).unwrap();
-        HttpResponse::Ok()
-            .content_type("application/json")
-            .body(body)
+        let http_response = self.0.into_http_response();
+        let mut response = HttpResponse::build(http_response.status());
+        for (name, value) in http_response.headers() {
+            response.append_header((name.clone(), value.clone()));
+        }
+        response.body(http_response.into_body())
     }
 }
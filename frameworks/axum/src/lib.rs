@@ -1,5 +1,127 @@
 mod request;
 mod response;
 
-pub use request::GqlRequest;
-pub use response::GqlResponse;
+pub use request::{GqlRejection, GqlRequest};
+pub use response::{GqlIncrementalResponse, GqlResponse};
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use axum::{
+        body::Body,
+        extract::Extension,
+        http::{header, Request, StatusCode},
+        routing::post,
+        Router,
+    };
+    use rusty_gql::*;
+    use tower::ServiceExt;
+
+    use crate::{GqlIncrementalResponse, GqlRejection, GqlRequest, GqlResponse};
+
+    struct Query;
+
+    #[GqlType]
+    impl Query {
+        async fn value(&self) -> i32 {
+            10
+        }
+        async fn values(&self) -> Vec<i32> {
+            vec![1, 2]
+        }
+    }
+
+    type ContainerType = Container<Query, EmptyMutation, EmptySubscription>;
+
+    async fn gql_handler(
+        Extension(container): Extension<Arc<ContainerType>>,
+        req: GqlRequest,
+    ) -> GqlResponse {
+        GqlResponse::from(execute_batch(&container, req.0).await)
+    }
+
+    async fn gql_incremental_handler(
+        Extension(container): Extension<Arc<ContainerType>>,
+        req: GqlRequest,
+    ) -> Result<GqlIncrementalResponse, GqlRejection> {
+        let request = req.0.into_single()?;
+        Ok(GqlIncrementalResponse(execute_incremental(
+            &container, request,
+        )))
+    }
+
+    fn container() -> Arc<ContainerType> {
+        let schema_doc = "type Query { value: Int! values: [Int!]! }";
+        Arc::new(
+            Container::builder(&[schema_doc], Query, EmptyMutation, EmptySubscription)
+                .build()
+                .unwrap(),
+        )
+    }
+
+    fn multipart_request() -> Request<Body> {
+        let body = concat!(
+            "--boundary\r\nContent-Disposition: form-data; name=\"operations\"\r\n\r\n",
+            r#"{"query": "{ value }", "variables": {"file": null}}"#,
+            "\r\n--boundary\r\nContent-Disposition: form-data; name=\"map\"\r\n\r\n",
+            r#"{"0": ["variables.file"]}"#,
+            "\r\n--boundary\r\nContent-Disposition: form-data; name=\"0\"; filename=\"a.txt\"\r\n",
+            "Content-Type: text/plain\r\n\r\ncontent a\r\n--boundary--\r\n",
+        );
+        Request::post("/")
+            .header(
+                header::CONTENT_TYPE,
+                "multipart/form-data; boundary=boundary",
+            )
+            .body(Body::from(body))
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_multipart_options() {
+        let app = Router::new()
+            .route("/", post(gql_handler))
+            .layer(Extension(container()));
+        let res = app.oneshot(multipart_request()).await.unwrap();
+        assert_eq!(res.status(), StatusCode::OK);
+
+        let app = Router::new()
+            .route("/", post(gql_handler))
+            .layer(Extension(container()))
+            .layer(Extension(MultipartOptions::default().max_num_files(0)));
+        let res = app.oneshot(multipart_request()).await.unwrap();
+        assert_eq!(res.status(), StatusCode::PAYLOAD_TOO_LARGE);
+    }
+
+    #[tokio::test]
+    async fn test_incremental_response() {
+        let app = Router::new()
+            .route("/", post(gql_incremental_handler))
+            .layer(Extension(container()));
+        let req = Request::post("/")
+            .header(header::CONTENT_TYPE, "application/json")
+            .body(Body::from(
+                r#"{"query": "{ value values @stream(initialCount: 1) }"}"#,
+            ))
+            .unwrap();
+        let res = app.oneshot(req).await.unwrap();
+        assert_eq!(res.status(), StatusCode::OK);
+        assert_eq!(
+            res.headers()[header::CONTENT_TYPE],
+            "multipart/mixed; boundary=\"-\""
+        );
+
+        let body = hyper::body::to_bytes(res.into_body()).await.unwrap();
+        assert_eq!(
+            std::str::from_utf8(&body).unwrap(),
+            concat!(
+                "\r\n---\r\nContent-Type: application/json; charset=utf-8\r\n\r\n",
+                r#"{"data":{"value":10,"values":[1]},"hasNext":true}"#,
+                "\r\n---\r\nContent-Type: application/json; charset=utf-8\r\n\r\n",
+                r#"{"incremental":[{"items":[2],"path":["values",1]}],"hasNext":false}"#,
+                "\r\n-----\r\n",
+            )
+        );
+    }
+}
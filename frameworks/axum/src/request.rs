@@ -1,23 +1,20 @@
 use axum::extract::{BodyStream, FromRequest};
-use axum::http::{Method, StatusCode};
 use axum::response::{IntoResponse, Response};
 use axum::{body, BoxError};
 use bytes::Bytes;
 use futures_util::TryStreamExt;
-use rusty_gql::{receive_http_request, HttpRequestError};
+use rusty_gql::{receive_batch_http_request, BatchRequest, HttpRequestError, MultipartOptions};
 use tokio_util::compat::TokioAsyncReadCompatExt;
 
-pub struct GqlRequest(pub rusty_gql::Request);
+/// Reads `MultipartOptions` from the request extensions, e.g. added by the `Extension` layer,
+/// and uses the default options otherwise.
+pub struct GqlRequest(pub BatchRequest);
 
 pub struct GqlRejection(pub HttpRequestError);
 
 impl IntoResponse for GqlRejection {
     fn into_response(self) -> Response {
-        let body = body::boxed(body::Full::from(format!("{:?}", self.0)));
-        Response::builder()
-            .status(StatusCode::BAD_REQUEST)
-            .body(body)
-            .unwrap()
+        self.0.into_http_response().map(body::boxed).into_response()
     }
 }
 
@@ -38,26 +35,28 @@ where
     async fn from_request(
         req: &mut axum::extract::RequestParts<B>,
     ) -> Result<Self, Self::Rejection> {
-        if let (&Method::GET, uri) = (req.method(), req.uri()) {
-            let res = serde_urlencoded::from_str(uri.query().unwrap_or_default()).map_err(|err| {
-                HttpRequestError::Io(std::io::Error::new(
-                    std::io::ErrorKind::Other,
-                    format!("failed to parse graphql requst from query params: {}", err),
-                ))
-            });
-            Ok(Self(res?))
-        } else {
-            let body_stream = BodyStream::from_request(req)
-                .await
-                .map_err(|err| {
-                    HttpRequestError::Io(std::io::Error::new(
-                        std::io::ErrorKind::Other,
-                        err.to_string(),
-                    ))
-                })?
-                .map_err(|err| std::io::Error::new(std::io::ErrorKind::Other, err.to_string()));
-            let body_reader = tokio_util::io::StreamReader::new(body_stream).compat();
-            Ok(Self(receive_http_request(body_reader).await?))
-        }
+        let method = req.method().clone();
+        let query_string = req.uri().query().map(ToString::to_string);
+        let headers = req.headers().cloned().unwrap_or_default();
+        let options = req
+            .extensions()
+            .and_then(|extensions| extensions.get::<MultipartOptions>())
+            .copied()
+            .unwrap_or_default();
+        let body_stream = BodyStream::from_request(req)
+            .await
+            .map_err(|err| HttpRequestError::Io(std::io::Error::other(err.to_string())))?
+            .map_err(|err| std::io::Error::other(err.to_string()));
+        let body_reader = tokio_util::io::StreamReader::new(body_stream).compat();
+
+        let request = receive_batch_http_request(
+            &method,
+            &headers,
+            query_string.as_deref(),
+            body_reader,
+            options,
+        )
+        .await?;
+        Ok(Self(request))
     }
 }
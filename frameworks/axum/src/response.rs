@@ -1,15 +1,56 @@
-use axum::response::IntoResponse;
+use std::convert::Infallible;
 
-pub struct GqlResponse(pub rusty_gql::Response);
+use axum::{
+    body::{self, StreamBody},
+    http::{header, HeaderValue},
+    response::IntoResponse,
+};
+use bytes::Bytes;
+use futures_util::{future::ready, stream, stream::BoxStream, StreamExt};
+use rusty_gql::{BatchResponse, IncrementalResponse};
+
+pub struct GqlResponse(pub BatchResponse);
 
 impl From<rusty_gql::Response> for GqlResponse {
     fn from(response: rusty_gql::Response) -> Self {
+        GqlResponse(BatchResponse::Single(response))
+    }
+}
+
+impl From<BatchResponse> for GqlResponse {
+    fn from(response: BatchResponse) -> Self {
         GqlResponse(response)
     }
 }
 
 impl IntoResponse for GqlResponse {
     fn into_response(self) -> axum::response::Response {
-        axum::Json(&self.0).into_response()
+        self.0.into_http_response().map(body::boxed).into_response()
+    }
+}
+
+pub struct GqlIncrementalResponse(pub BoxStream<'static, IncrementalResponse>);
+
+impl IntoResponse for GqlIncrementalResponse {
+    fn into_response(self) -> axum::response::Response {
+        let parts = self
+            .0
+            .map(|response| {
+                let json = serde_json::to_string(&response).unwrap_or_default();
+                Ok::<_, Infallible>(Bytes::from(format!(
+                    "\r\n---\r\nContent-Type: application/json; charset=utf-8\r\n\r\n{}",
+                    json
+                )))
+            })
+            .chain(stream::once(ready(Ok(Bytes::from_static(
+                b"\r\n-----\r\n",
+            )))));
+
+        let mut response = StreamBody::new(parts).into_response();
+        response.headers_mut().insert(
+            header::CONTENT_TYPE,
+            HeaderValue::from_static("multipart/mixed; boundary=\"-\""),
+        );
+        response
     }
 }
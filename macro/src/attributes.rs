@@ -0,0 +1,299 @@
+use syn::{Attribute, Expr, ExprLit, Lit, LitInt, LitStr, Meta, NestedMeta};
+
+#[derive(Default)]
+pub struct FieldAttributes {
+    pub name: Option<String>,
+    pub skip: bool,
+    pub guard: Option<Expr>,
+    pub is_deprecated: bool,
+    pub deprecation_reason: Option<String>,
+    pub complexity: Option<usize>,
+}
+
+#[derive(Default)]
+pub struct ContainerAttributes {
+    pub rename_all: Option<RenameRule>,
+}
+
+#[derive(Default)]
+pub struct MemberAttributes {
+    pub name: Option<String>,
+    pub default: Option<Option<Expr>>,
+}
+
+#[derive(Clone, Copy)]
+pub enum RenameRule {
+    Lower,
+    Upper,
+    Pascal,
+    Camel,
+    Snake,
+    ScreamingSnake,
+}
+
+impl RenameRule {
+    fn parse(rule: &str) -> Option<Self> {
+        match rule {
+            "lowercase" => Some(RenameRule::Lower),
+            "UPPERCASE" => Some(RenameRule::Upper),
+            "PascalCase" => Some(RenameRule::Pascal),
+            "camelCase" => Some(RenameRule::Camel),
+            "snake_case" => Some(RenameRule::Snake),
+            "SCREAMING_SNAKE_CASE" => Some(RenameRule::ScreamingSnake),
+            _ => None,
+        }
+    }
+
+    /// Renames a snake_case field or a PascalCase variant.
+    pub fn apply(&self, name: &str) -> String {
+        let words = split_words(name);
+        match self {
+            RenameRule::Lower => words.concat().to_lowercase(),
+            RenameRule::Upper => words.concat().to_uppercase(),
+            RenameRule::Pascal => words.iter().map(|word| capitalize(word)).collect(),
+            RenameRule::Camel => words
+                .iter()
+                .enumerate()
+                .map(|(i, word)| {
+                    if i == 0 {
+                        word.to_lowercase()
+                    } else {
+                        capitalize(word)
+                    }
+                })
+                .collect(),
+            RenameRule::Snake => words.join("_").to_lowercase(),
+            RenameRule::ScreamingSnake => words.join("_").to_uppercase(),
+        }
+    }
+}
+
+fn split_words(name: &str) -> Vec<String> {
+    let mut words = Vec::new();
+    let mut word = String::new();
+    let mut prev_is_lower = false;
+    for c in name.chars() {
+        if c == '_' {
+            if !word.is_empty() {
+                words.push(std::mem::take(&mut word));
+            }
+            prev_is_lower = false;
+            continue;
+        }
+        if c.is_uppercase() && prev_is_lower && !word.is_empty() {
+            words.push(std::mem::take(&mut word));
+        }
+        prev_is_lower = c.is_lowercase() || c.is_ascii_digit();
+        word.push(c);
+    }
+    if !word.is_empty() {
+        words.push(word);
+    }
+    words
+}
+
+fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+    match chars.next() {
+        Some(first) => first
+            .to_uppercase()
+            .chain(chars.flat_map(char::to_lowercase))
+            .collect(),
+        None => String::new(),
+    }
+}
+
+#[derive(Default)]
+pub struct ArgAttributes {
+    pub name: Option<String>,
+    pub default: Option<Option<Expr>>,
+}
+
+/// Parses and removes `#[field(...)]` from the attributes of a resolver method.
+pub fn take_field_attributes(attrs: &mut Vec<Attribute>) -> Result<FieldAttributes, syn::Error> {
+    let mut field_attrs = FieldAttributes::default();
+    for nested in take_attributes(attrs, "field")? {
+        match &nested {
+            NestedMeta::Meta(Meta::Path(path)) if path.is_ident("skip") => {
+                set_flag_once(&nested, &mut field_attrs.skip)?;
+            }
+            NestedMeta::Meta(Meta::Path(path)) if path.is_ident("deprecation") => {
+                set_flag_once(&nested, &mut field_attrs.is_deprecated)?;
+            }
+            NestedMeta::Meta(Meta::NameValue(nv)) if nv.path.is_ident("name") => {
+                let name = lit_str(&nv.lit)?.value();
+                set_some_once(&nested, &mut field_attrs.name, name)?;
+            }
+            NestedMeta::Meta(Meta::NameValue(nv)) if nv.path.is_ident("guard") => {
+                let lit = lit_str(&nv.lit)?;
+                let guard = lit.parse::<Expr>().map_err(|err| {
+                    syn::Error::new_spanned(lit, format!("Invalid guard expression, {}.", err))
+                })?;
+                set_some_once(&nested, &mut field_attrs.guard, guard)?;
+            }
+            NestedMeta::Meta(Meta::NameValue(nv)) if nv.path.is_ident("deprecation") => {
+                set_flag_once(&nested, &mut field_attrs.is_deprecated)?;
+                field_attrs.deprecation_reason = Some(lit_str(&nv.lit)?.value());
+            }
+            NestedMeta::Meta(Meta::NameValue(nv)) if nv.path.is_ident("complexity") => {
+                let complexity = lit_int(&nv.lit)?.base10_parse::<usize>()?;
+                set_some_once(&nested, &mut field_attrs.complexity, complexity)?;
+            }
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    nested,
+                    "Unknown field attribute, expected one of `name`, `skip`, `guard`, `deprecation`, `complexity`.",
+                ));
+            }
+        }
+    }
+    Ok(field_attrs)
+}
+
+/// Parses and removes `#[arg(...)]` from the attributes of a resolver argument.
+pub fn take_arg_attributes(attrs: &mut Vec<Attribute>) -> Result<ArgAttributes, syn::Error> {
+    let mut arg_attrs = ArgAttributes::default();
+    for nested in take_attributes(attrs, "arg")? {
+        match &nested {
+            NestedMeta::Meta(Meta::NameValue(nv)) if nv.path.is_ident("name") => {
+                let name = lit_str(&nv.lit)?.value();
+                set_some_once(&nested, &mut arg_attrs.name, name)?;
+            }
+            NestedMeta::Meta(Meta::Path(path)) if path.is_ident("default") => {
+                set_some_once(&nested, &mut arg_attrs.default, None)?;
+            }
+            NestedMeta::Meta(Meta::NameValue(nv)) if nv.path.is_ident("default") => {
+                set_some_once(&nested, &mut arg_attrs.default, Some(lit_expr(&nv.lit)))?;
+            }
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    nested,
+                    "Unknown arg attribute, expected one of `name`, `default`.",
+                ));
+            }
+        }
+    }
+    Ok(arg_attrs)
+}
+
+/// Parses `#[gql(...)]` of `GqlInputObject` and `GqlEnum`.
+pub fn parse_container_attributes(attrs: &[Attribute]) -> Result<ContainerAttributes, syn::Error> {
+    let mut container_attrs = ContainerAttributes::default();
+    for nested in parse_attributes(attrs, "gql")? {
+        match &nested {
+            NestedMeta::Meta(Meta::NameValue(nv)) if nv.path.is_ident("rename_all") => {
+                let lit = lit_str(&nv.lit)?;
+                let rule = RenameRule::parse(&lit.value()).ok_or_else(|| {
+                    syn::Error::new_spanned(
+                        lit,
+                        "Unknown rename rule, expected one of `lowercase`, `UPPERCASE`, `PascalCase`, `camelCase`, `snake_case`, `SCREAMING_SNAKE_CASE`.",
+                    )
+                })?;
+                set_some_once(&nested, &mut container_attrs.rename_all, rule)?;
+            }
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    nested,
+                    "Unknown attribute, expected `rename_all`.",
+                ));
+            }
+        }
+    }
+    Ok(container_attrs)
+}
+
+/// Parses `#[gql(...)]` of a field of `GqlInputObject` or a variant of `GqlEnum`.
+pub fn parse_member_attributes(
+    attrs: &[Attribute],
+    allow_default: bool,
+) -> Result<MemberAttributes, syn::Error> {
+    let mut member_attrs = MemberAttributes::default();
+    for nested in parse_attributes(attrs, "gql")? {
+        match &nested {
+            NestedMeta::Meta(Meta::NameValue(nv)) if nv.path.is_ident("name") => {
+                let name = lit_str(&nv.lit)?.value();
+                set_some_once(&nested, &mut member_attrs.name, name)?;
+            }
+            NestedMeta::Meta(Meta::Path(path)) if allow_default && path.is_ident("default") => {
+                set_some_once(&nested, &mut member_attrs.default, None)?;
+            }
+            NestedMeta::Meta(Meta::NameValue(nv))
+                if allow_default && nv.path.is_ident("default") =>
+            {
+                set_some_once(&nested, &mut member_attrs.default, Some(lit_expr(&nv.lit)))?;
+            }
+            _ => {
+                let expected = if allow_default {
+                    "Unknown attribute, expected one of `name`, `default`."
+                } else {
+                    "Unknown attribute, expected `name`."
+                };
+                return Err(syn::Error::new_spanned(nested, expected));
+            }
+        }
+    }
+    Ok(member_attrs)
+}
+
+fn parse_attributes(attrs: &[Attribute], ident: &str) -> Result<Vec<NestedMeta>, syn::Error> {
+    let mut nested = Vec::new();
+    for attr in attrs.iter().filter(|attr| attr.path.is_ident(ident)) {
+        match attr.parse_meta()? {
+            Meta::List(list) => nested.extend(list.nested),
+            meta => {
+                return Err(syn::Error::new_spanned(
+                    meta,
+                    format!("Expected `#[{}(...)]`.", ident),
+                ));
+            }
+        }
+    }
+    Ok(nested)
+}
+
+fn take_attributes(attrs: &mut Vec<Attribute>, ident: &str) -> Result<Vec<NestedMeta>, syn::Error> {
+    let nested = parse_attributes(attrs, ident)?;
+    attrs.retain(|attr| !attr.path.is_ident(ident));
+    Ok(nested)
+}
+
+fn set_flag_once(nested: &NestedMeta, target: &mut bool) -> Result<(), syn::Error> {
+    if *target {
+        return Err(syn::Error::new_spanned(nested, "Duplicate attribute."));
+    }
+    *target = true;
+    Ok(())
+}
+
+fn set_some_once<T>(
+    nested: &NestedMeta,
+    target: &mut Option<T>,
+    value: T,
+) -> Result<(), syn::Error> {
+    if target.is_some() {
+        return Err(syn::Error::new_spanned(nested, "Duplicate attribute."));
+    }
+    *target = Some(value);
+    Ok(())
+}
+
+fn lit_str(lit: &Lit) -> Result<&LitStr, syn::Error> {
+    match lit {
+        Lit::Str(lit) => Ok(lit),
+        _ => Err(syn::Error::new_spanned(lit, "Expected a string literal.")),
+    }
+}
+
+fn lit_int(lit: &Lit) -> Result<&LitInt, syn::Error> {
+    match lit {
+        Lit::Int(lit) => Ok(lit),
+        _ => Err(syn::Error::new_spanned(lit, "Expected an integer literal.")),
+    }
+}
+
+fn lit_expr(lit: &Lit) -> Expr {
+    Expr::Lit(ExprLit {
+        attrs: Vec::new(),
+        lit: lit.clone(),
+    })
+}
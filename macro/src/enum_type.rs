@@ -2,6 +2,8 @@ use proc_macro::{self, TokenStream};
 use quote::quote;
 use syn::{ext::IdentExt, DeriveInput};
 
+use crate::attributes::{parse_container_attributes, parse_member_attributes};
+
 pub fn generate_enum(derive_input: &DeriveInput) -> Result<TokenStream, syn::Error> {
     let self_ty = &derive_input.ident;
     let crate_name = quote! { rusty_gql };
@@ -20,6 +22,7 @@ pub fn generate_enum(derive_input: &DeriveInput) -> Result<TokenStream, syn::Err
         }
     };
 
+    let container_attrs = parse_container_attributes(&derive_input.attrs)?;
     let mut resolve_fields = Vec::new();
     let mut resolve_selection_sets = Vec::new();
     let mut into_gql_values = Vec::new();
@@ -27,7 +30,12 @@ pub fn generate_enum(derive_input: &DeriveInput) -> Result<TokenStream, syn::Err
 
     for variant in &enum_data.variants {
         let enum_value_ident = &variant.ident;
-        let variant_str = enum_value_ident.unraw().to_string();
+        let variant_attrs = parse_member_attributes(&variant.attrs, false)?;
+        let variant_str = match (variant_attrs.name, container_attrs.rename_all) {
+            (Some(name), _) => name,
+            (None, Some(rule)) => rule.apply(&enum_value_ident.unraw().to_string()),
+            (None, None) => enum_value_ident.unraw().to_string(),
+        };
 
         resolve_fields.push(quote! {
             #self_ty::#enum_value_ident => Ok(Some(GqlValue::Enum(#variant_str.to_string())))
@@ -42,7 +50,7 @@ pub fn generate_enum(derive_input: &DeriveInput) -> Result<TokenStream, syn::Err
         });
 
         from_gql_values.push(quote! {
-            if enum_value == #variant_str.to_string() {
+            if enum_value == #variant_str {
                 return Ok(#self_ty::#enum_value_ident)
             }
         })
@@ -59,7 +67,7 @@ pub fn generate_enum(derive_input: &DeriveInput) -> Result<TokenStream, syn::Err
                             invalid_value => {
                                 return Err(format!(
                                     "Expected type: enum, but found {}",
-                                    invalid_value.to_string()
+                                    invalid_value
                                 ));
                             }
                         };
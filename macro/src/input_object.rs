@@ -2,6 +2,8 @@ use proc_macro::{self, TokenStream};
 use quote::quote;
 use syn::{ext::IdentExt, DeriveInput};
 
+use crate::attributes::{parse_container_attributes, parse_member_attributes};
+
 pub fn generate_input_object(derive_input: &DeriveInput) -> Result<TokenStream, syn::Error> {
     let self_ty = &derive_input.ident;
     let crate_name = quote! { rusty_gql };
@@ -20,17 +22,36 @@ pub fn generate_input_object(derive_input: &DeriveInput) -> Result<TokenStream,
         }
     };
 
+    let container_attrs = parse_container_attributes(&derive_input.attrs)?;
     let mut fields = Vec::new();
     let mut get_fields = Vec::new();
     let mut set_fields = Vec::new();
     for field in &struct_data.fields {
         let ident = field.ident.as_ref().unwrap();
         let ty = &field.ty;
-        let field_name = ident.unraw().to_string();
+        let field_attrs = parse_member_attributes(&field.attrs, true)?;
+        let field_name = match (field_attrs.name, container_attrs.rename_all) {
+            (Some(name), _) => name,
+            (None, Some(rule)) => rule.apply(&ident.unraw().to_string()),
+            (None, None) => ident.unraw().to_string(),
+        };
 
-        get_fields.push(quote! {
-            let #ident: #ty = #crate_name::GqlInputType::from_gql_value(obj.get(#field_name).cloned())?;
-        });
+        let default = match field_attrs.default {
+            Some(Some(default)) => Some(quote! { ::std::convert::Into::into(#default) }),
+            Some(None) => Some(quote! { ::std::default::Default::default() }),
+            None => None,
+        };
+        match default {
+            Some(default) => get_fields.push(quote! {
+                let #ident: #ty = match obj.get(#field_name).cloned() {
+                    Some(value) => #crate_name::GqlInputType::from_gql_value(Some(value))?,
+                    None => #default,
+                };
+            }),
+            None => get_fields.push(quote! {
+                let #ident: #ty = #crate_name::GqlInputType::from_gql_value(obj.get(#field_name).cloned())?;
+            }),
+        }
         fields.push(ident);
 
         set_fields.push(quote! {
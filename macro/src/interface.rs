@@ -20,9 +20,13 @@ pub fn generate_interface(derive_input: &DeriveInput) -> Result<TokenStream, syn
 
     let mut introspection_type_names = Vec::new();
     let mut collect_all_fields = Vec::new();
+    let mut possible_types = Vec::new();
 
     for variant in &union_data.variants {
         let enum_value_ident = &variant.ident;
+        if let Some(field) = variant.fields.iter().next() {
+            possible_types.push(field.ty.clone());
+        }
 
         introspection_type_names.push(quote! {
             #self_ty::#enum_value_ident(obj) => obj.introspection_type_name()
@@ -34,6 +38,13 @@ pub fn generate_interface(derive_input: &DeriveInput) -> Result<TokenStream, syn
     }
 
     let expanded = quote! {
+        impl #impl_generics #self_ty #where_clause {
+            #[doc(hidden)]
+            pub fn __register_possible_types(registry: &mut #crate_name::ResolverRegistry) {
+                #(<#possible_types as #crate_name::FieldResolver>::register(registry);)*
+            }
+        }
+
         impl #impl_generics #crate_name::CollectFields for #self_ty #where_clause {
             fn introspection_type_name(&self) -> String {
                 match self {
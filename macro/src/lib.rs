@@ -1,3 +1,4 @@
+mod attributes;
 mod enum_type;
 mod input_object;
 mod interface;
@@ -55,7 +56,7 @@ pub fn interface_derive(input: TokenStream) -> TokenStream {
     }
 }
 
-#[proc_macro_derive(GqlEnum)]
+#[proc_macro_derive(GqlEnum, attributes(gql))]
 pub fn enum_derive(input: TokenStream) -> TokenStream {
     let input = &parse_macro_input!(input as DeriveInput);
     match generate_enum(input) {
@@ -64,7 +65,7 @@ pub fn enum_derive(input: TokenStream) -> TokenStream {
     }
 }
 
-#[proc_macro_derive(GqlInputObject)]
+#[proc_macro_derive(GqlInputObject, attributes(gql))]
 pub fn input_object_derive(input: TokenStream) -> TokenStream {
     let input = &parse_macro_input!(input as DeriveInput);
     match generate_input_object(input) {
@@ -1,9 +1,18 @@
+use std::collections::{HashMap, HashSet};
+
 use proc_macro::{self, TokenStream};
 use quote::quote;
-use syn::{ext::IdentExt, Block, FnArg, ImplItem, ItemImpl, NestedMeta, ReturnType};
+use syn::{
+    ext::IdentExt, Block, FnArg, Ident, ImplItem, ImplItemMethod, ItemImpl, NestedMeta, Pat,
+    ReturnType,
+};
 
-use crate::utils::{
-    get_method_args_without_context, is_context_type, is_interface, is_internal, is_result_type,
+use crate::{
+    attributes::{take_arg_attributes, take_field_attributes, ArgAttributes},
+    utils::{
+        elide_lifetimes, get_method_args_without_context, get_result_ok_type, is_context_type,
+        is_interface, is_internal, is_result_type,
+    },
 };
 
 pub fn generate_type(
@@ -20,15 +29,35 @@ pub fn generate_type(
     let type_name = match self_ty.as_ref() {
         syn::Type::Path(path) => path.path.segments.last().unwrap().ident.unraw().to_string(),
         _ => {
-            return Err(syn::Error::new_spanned(&self_ty, "Invalid struct"));
+            return Err(syn::Error::new_spanned(self_ty, "Invalid struct"));
         }
     };
 
     let (impl_generics, _, where_clause) = &item_impl.generics.split_for_impl();
 
     let mut resolvers = Vec::new();
+    let mut field_metadata = Vec::new();
+    let mut return_types = Vec::new();
+    let mut field_names = HashSet::new();
     for item in &mut item_impl.items {
         if let ImplItem::Method(method) = item {
+            let field_attrs = take_field_attributes(&mut method.attrs)?;
+            let arg_attrs = take_method_arg_attributes(method)?;
+            if field_attrs.skip {
+                if field_attrs.name.is_some()
+                    || field_attrs.guard.is_some()
+                    || field_attrs.is_deprecated
+                    || field_attrs.complexity.is_some()
+                    || !arg_attrs.is_empty()
+                {
+                    return Err(syn::Error::new_spanned(
+                        &method.sig,
+                        "A skipped method cannot have other field or arg attributes.",
+                    ));
+                }
+                continue;
+            }
+
             if method.sig.asyncness.is_none() {
                 return Err(syn::Error::new_spanned(
                     &method,
@@ -47,6 +76,14 @@ pub fn generate_type(
             };
 
             let is_result = is_result_type(return_type);
+            let mut ok_type = if is_result {
+                get_result_ok_type(return_type)?
+            } else {
+                return_type.as_ref().clone()
+            };
+            elide_lifetimes(&mut ok_type, &item_impl.generics);
+            return_types.push(ok_type);
+
             let block = &method.block;
 
             if !is_result {
@@ -74,27 +111,70 @@ pub fn generate_type(
                 method.sig.inputs.insert(1, arg_ctx);
             }
             let method_name = &method.sig.ident;
-            let field_name = method_name.unraw().to_string();
+            let field_name = field_attrs
+                .name
+                .clone()
+                .unwrap_or_else(|| method_name.unraw().to_string());
+            if !field_names.insert(field_name.clone()) {
+                return Err(syn::Error::new_spanned(
+                    method_name,
+                    format!("Field {} is defined more than once.", field_name),
+                ));
+            }
 
             let method_args = get_method_args_without_context(method)?;
             let mut args = Vec::new();
+            let mut arg_names = Vec::new();
             let mut gql_arg_values = Vec::new();
 
             for (arg_ident, ty) in method_args {
                 args.push(quote! { #arg_ident });
                 let ident = arg_ident.ident;
-                let name = ident.unraw().to_string();
+                let attrs = arg_attrs.get(&ident);
+                let name = attrs
+                    .and_then(|attrs| attrs.name.clone())
+                    .unwrap_or_else(|| ident.unraw().to_string());
+                let get_arg_value = match attrs.and_then(|attrs| attrs.default.as_ref()) {
+                    Some(Some(default)) => quote! {
+                        ctx.get_arg_value::<::std::option::Option<#ty>>(#name)?
+                            .unwrap_or_else(|| ::std::convert::Into::into(#default))
+                    },
+                    Some(None) => quote! {
+                        ctx.get_arg_value::<::std::option::Option<#ty>>(#name)?
+                            .unwrap_or_default()
+                    },
+                    None => quote! { ctx.get_arg_value::<#ty>(#name)? },
+                };
                 gql_arg_values.push(quote! {
-                    let #ident = ctx.get_arg_value::<#ty>(#name)?;
+                    let #ident = #get_arg_value;
                 });
+                arg_names.push(name);
             }
 
+            let into_gql_error = quote! {
+                |err| {
+                    #[allow(unused_imports)]
+                    use #crate_name::__private::{ViaErrorExtensions, ViaFrom};
+                    #crate_name::__private::ErrorWrap(Some(err))
+                        .into_error()
+                        .into_gql_error(ctx.item.position)
+                }
+            };
+            let guard = field_attrs.guard.as_ref().map(|guard| {
+                quote! {
+                    #crate_name::Guard::check(&#guard, ctx)
+                        .await
+                        .map_err(|err| #crate_name::__private::guard_error(err, ctx.item.position))?;
+                }
+            });
+
             resolvers.push(quote! {
                 if ctx.item.name == #field_name {
                     let resolve_fn = async move {
+                        #guard
                         #(#gql_arg_values)*
                         let res = self.#method_name(ctx, #(#args),*).await;
-                        res.map_err(|err| #crate_name::Error::from(err).into_gql_error(ctx.item.position))
+                        res.map_err(#into_gql_error)
                     };
 
                     let obj = resolve_fn.await?;
@@ -102,9 +182,34 @@ pub fn generate_type(
                     return obj.resolve_selection_set(&ctx_selection_set).await.map(Some);
                 }
             });
+
+            let is_deprecated = field_attrs.is_deprecated;
+            let deprecation_reason = match &field_attrs.deprecation_reason {
+                Some(reason) => quote! { ::std::option::Option::Some(#reason.to_string()) },
+                None => quote! { ::std::option::Option::None },
+            };
+            let complexity = match field_attrs.complexity {
+                Some(complexity) => quote! { ::std::option::Option::Some(#complexity) },
+                None => quote! { ::std::option::Option::None },
+            };
+            field_metadata.push(quote! {
+                #crate_name::FieldMetadata {
+                    name: #field_name.to_string(),
+                    arguments: vec![#(#arg_names.to_string()),*],
+                    is_deprecated: #is_deprecated,
+                    deprecation_reason: #deprecation_reason,
+                    complexity: #complexity,
+                }
+            });
         }
     }
 
+    let register_possible_types = if is_interface(args) {
+        Some(quote! { Self::__register_possible_types(registry); })
+    } else {
+        None
+    };
+
     let collect_fields = if is_interface(args) {
         None
     } else {
@@ -125,6 +230,14 @@ pub fn generate_type(
             fn type_name() -> String {
                 #type_name.to_string()
             }
+            fn register(registry: &mut #crate_name::ResolverRegistry) {
+                if registry.contains_type(#type_name) {
+                    return;
+                }
+                registry.add_type(#type_name, vec![#(#field_metadata),*]);
+                #(<#return_types as #crate_name::FieldResolver>::register(registry);)*
+                #register_possible_types
+            }
         }
 
         #collect_fields
@@ -139,3 +252,29 @@ pub fn generate_type(
 
     Ok(expanded.into())
 }
+
+fn take_method_arg_attributes(
+    method: &mut ImplItemMethod,
+) -> Result<HashMap<Ident, ArgAttributes>, syn::Error> {
+    let mut arg_attrs = HashMap::new();
+    for arg in &mut method.sig.inputs {
+        if let FnArg::Typed(pat_type) = arg {
+            let is_context = is_context_type(&FnArg::Typed(pat_type.clone()));
+            let has_attrs = pat_type.attrs.iter().any(|attr| attr.path.is_ident("arg"));
+            let attrs = take_arg_attributes(&mut pat_type.attrs)?;
+            if !has_attrs {
+                continue;
+            }
+            if is_context {
+                return Err(syn::Error::new_spanned(
+                    pat_type,
+                    "Context argument cannot have arg attributes.",
+                ));
+            }
+            if let Pat::Ident(ident) = &*pat_type.pat {
+                arg_attrs.insert(ident.ident.clone(), attrs);
+            }
+        }
+    }
+    Ok(arg_attrs)
+}
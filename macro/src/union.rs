@@ -22,9 +22,13 @@ pub fn generate_union(derive_input: &DeriveInput) -> Result<TokenStream, syn::Er
 
     let mut introspection_type_names = Vec::new();
     let mut collect_all_fields = Vec::new();
+    let mut possible_types = Vec::new();
 
     for variant in &union_data.variants {
         let enum_value_ident = &variant.ident;
+        if let Some(field) = variant.fields.iter().next() {
+            possible_types.push(field.ty.clone());
+        }
 
         introspection_type_names.push(quote! {
             #self_ty::#enum_value_ident(obj) => obj.introspection_type_name()
@@ -44,6 +48,9 @@ pub fn generate_union(derive_input: &DeriveInput) -> Result<TokenStream, syn::Er
             fn type_name() -> String {
                 #type_name.to_string()
             }
+            fn register(registry: &mut #crate_name::ResolverRegistry) {
+                #(<#possible_types as #crate_name::FieldResolver>::register(registry);)*
+            }
         }
 
         impl #impl_generics #crate_name::CollectFields for #self_ty #where_clause {
@@ -1,4 +1,9 @@
-use syn::{FnArg, ImplItemMethod, Meta, NestedMeta, Pat, PatIdent, Type, TypeReference};
+use std::collections::HashSet;
+
+use syn::{
+    visit_mut::VisitMut, FnArg, GenericArgument, Generics, ImplItemMethod, Lifetime, Meta,
+    NestedMeta, Pat, PatIdent, PathArguments, Type, TypeReference,
+};
 
 pub fn is_internal(args: &[NestedMeta]) -> bool {
     for arg in args {
@@ -89,3 +94,41 @@ pub fn is_result_type(return_type: &Type) -> bool {
     }
     false
 }
+
+pub fn get_result_ok_type(return_type: &Type) -> Result<Type, syn::Error> {
+    if let Type::Path(ty_path) = return_type {
+        if let PathArguments::AngleBracketed(args) =
+            &ty_path.path.segments.last().unwrap().arguments
+        {
+            if let Some(GenericArgument::Type(ty)) = args.args.first() {
+                return Ok(ty.clone());
+            }
+        }
+    }
+    Err(syn::Error::new_spanned(
+        return_type,
+        "Result must have the type of the value.",
+    ))
+}
+
+struct ElideLifetimes {
+    impl_lifetimes: HashSet<String>,
+}
+
+impl VisitMut for ElideLifetimes {
+    fn visit_lifetime_mut(&mut self, lifetime: &mut Lifetime) {
+        if lifetime.ident != "static" && !self.impl_lifetimes.contains(&lifetime.ident.to_string())
+        {
+            *lifetime = Lifetime::new("'_", lifetime.apostrophe);
+        }
+    }
+}
+
+// Lifetimes of methods are not in scope of `FieldResolver::register`.
+pub fn elide_lifetimes(ty: &mut Type, impl_generics: &Generics) {
+    let impl_lifetimes = impl_generics
+        .lifetimes()
+        .map(|def| def.lifetime.ident.to_string())
+        .collect();
+    ElideLifetimes { impl_lifetimes }.visit_type_mut(ty);
+}
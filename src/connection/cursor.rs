@@ -0,0 +1,15 @@
+const OFFSET_PREFIX: &str = "offset:";
+
+pub fn encode_cursor(offset: usize) -> String {
+    base64::encode(format!("{}{}", OFFSET_PREFIX, offset))
+}
+
+pub fn decode_cursor(cursor: &str) -> Result<usize, String> {
+    let invalid_cursor = || format!("{} is invalid cursor", cursor);
+    let decoded = base64::decode(cursor).map_err(|_| invalid_cursor())?;
+    let decoded = String::from_utf8(decoded).map_err(|_| invalid_cursor())?;
+    decoded
+        .strip_prefix(OFFSET_PREFIX)
+        .and_then(|offset| offset.parse::<usize>().ok())
+        .ok_or_else(invalid_cursor)
+}
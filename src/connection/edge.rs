@@ -0,0 +1,95 @@
+use crate::{
+    resolve_selection_parallelly, CollectFields, Context, FieldResolver, GqlValue,
+    ResolverRegistry, ResolverResult, SelectionSetContext, SelectionSetResolver,
+};
+
+#[derive(Debug, Clone)]
+pub struct Edge<Node> {
+    pub cursor: String,
+    pub node: Node,
+}
+
+impl<Node> Edge<Node> {
+    pub fn new(cursor: impl Into<String>, node: Node) -> Self {
+        Edge {
+            cursor: cursor.into(),
+            node,
+        }
+    }
+}
+
+pub(crate) async fn resolve_edge_field<Node: SelectionSetResolver>(
+    edge: &Edge<Node>,
+    ctx: &Context<'_>,
+) -> ResolverResult<Option<GqlValue>> {
+    match ctx.item.name.as_str() {
+        "cursor" => Ok(Some(GqlValue::String(edge.cursor.clone()))),
+        "node" => {
+            let ctx_selection_set = ctx.with_selection_set(&ctx.item.selection_set);
+            edge.node
+                .resolve_selection_set(&ctx_selection_set)
+                .await
+                .map(Some)
+        }
+        _ => Ok(None),
+    }
+}
+
+#[async_trait::async_trait]
+impl<Node: SelectionSetResolver> FieldResolver for Edge<Node> {
+    async fn resolve_field(&self, ctx: &Context<'_>) -> ResolverResult<Option<GqlValue>> {
+        resolve_edge_field(self, ctx).await
+    }
+
+    fn type_name() -> String {
+        format!("{}Edge", Node::type_name())
+    }
+    fn register(registry: &mut ResolverRegistry) {
+        registry.add_unchecked_type(&Self::type_name());
+        Node::register(registry);
+    }
+}
+
+impl<Node: SelectionSetResolver> CollectFields for Edge<Node> {}
+
+#[async_trait::async_trait]
+impl<Node: SelectionSetResolver> SelectionSetResolver for Edge<Node> {
+    async fn resolve_selection_set(
+        &self,
+        ctx: &SelectionSetContext<'_>,
+    ) -> ResolverResult<GqlValue> {
+        resolve_selection_parallelly(ctx, self).await
+    }
+}
+
+pub(crate) struct NamedEdge<'a, Node> {
+    pub(crate) edge: &'a Edge<Node>,
+    pub(crate) type_name: &'a str,
+}
+
+#[async_trait::async_trait]
+impl<'a, Node: SelectionSetResolver> FieldResolver for NamedEdge<'a, Node> {
+    async fn resolve_field(&self, ctx: &Context<'_>) -> ResolverResult<Option<GqlValue>> {
+        resolve_edge_field(self.edge, ctx).await
+    }
+
+    fn type_name() -> String {
+        Edge::<Node>::type_name()
+    }
+}
+
+impl<'a, Node: SelectionSetResolver> CollectFields for NamedEdge<'a, Node> {
+    fn introspection_type_name(&self) -> String {
+        self.type_name.to_string()
+    }
+}
+
+#[async_trait::async_trait]
+impl<'a, Node: SelectionSetResolver> SelectionSetResolver for NamedEdge<'a, Node> {
+    async fn resolve_selection_set(
+        &self,
+        ctx: &SelectionSetContext<'_>,
+    ) -> ResolverResult<GqlValue> {
+        resolve_selection_parallelly(ctx, self).await
+    }
+}
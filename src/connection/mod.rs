@@ -0,0 +1,263 @@
+mod cursor;
+mod edge;
+mod page_info;
+
+use futures_util::Future;
+
+pub use cursor::{decode_cursor, encode_cursor};
+pub use edge::Edge;
+pub use page_info::PageInfo;
+
+use crate::{
+    resolve_selection_parallelly, CollectFields, Context, FieldResolver, GqlError, GqlErrorType,
+    GqlValue, ResolverRegistry, ResolverResult, SelectionSetContext, SelectionSetResolver,
+};
+
+use self::edge::NamedEdge;
+
+#[derive(Debug, Clone)]
+pub struct Connection<Node> {
+    pub edges: Vec<Edge<Node>>,
+    pub page_info: PageInfo,
+    pub total_count: Option<usize>,
+    name: Option<String>,
+}
+
+impl<Node> Connection<Node> {
+    pub fn new(edges: Vec<Edge<Node>>, page_info: PageInfo, total_count: Option<usize>) -> Self {
+        Connection {
+            edges,
+            page_info,
+            total_count,
+            name: None,
+        }
+    }
+
+    // e.g. `with_name("Friends")` resolves as FriendsConnection and FriendsEdge.
+    pub fn with_name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+}
+
+impl<Node: SelectionSetResolver> Connection<Node> {
+    fn base_name(&self) -> String {
+        self.name.clone().unwrap_or_else(Node::type_name)
+    }
+}
+
+#[async_trait::async_trait]
+impl<Node: SelectionSetResolver> FieldResolver for Connection<Node> {
+    async fn resolve_field(&self, ctx: &Context<'_>) -> ResolverResult<Option<GqlValue>> {
+        let ctx_selection_set = ctx.with_selection_set(&ctx.item.selection_set);
+        match ctx.item.name.as_str() {
+            "edges" => {
+                let edge_type_name = format!("{}Edge", self.base_name());
+                let edges: Vec<NamedEdge<'_, Node>> = self
+                    .edges
+                    .iter()
+                    .map(|edge| NamedEdge {
+                        edge,
+                        type_name: &edge_type_name,
+                    })
+                    .collect();
+                edges
+                    .resolve_selection_set(&ctx_selection_set)
+                    .await
+                    .map(Some)
+            }
+            "pageInfo" => self
+                .page_info
+                .resolve_selection_set(&ctx_selection_set)
+                .await
+                .map(Some),
+            "totalCount" => Ok(self.total_count.map(GqlValue::from)),
+            _ => Ok(None),
+        }
+    }
+
+    fn type_name() -> String {
+        format!("{}Connection", Node::type_name())
+    }
+    fn register(registry: &mut ResolverRegistry) {
+        // `totalCount` is optional in the schema, so the fields are not checked.
+        registry.add_unchecked_type(&Self::type_name());
+        Edge::<Node>::register(registry);
+        PageInfo::register(registry);
+    }
+}
+
+impl<Node: SelectionSetResolver> CollectFields for Connection<Node> {
+    fn introspection_type_name(&self) -> String {
+        format!("{}Connection", self.base_name())
+    }
+}
+
+#[async_trait::async_trait]
+impl<Node: SelectionSetResolver> SelectionSetResolver for Connection<Node> {
+    async fn resolve_selection_set(
+        &self,
+        ctx: &SelectionSetContext<'_>,
+    ) -> ResolverResult<GqlValue> {
+        resolve_selection_parallelly(ctx, self).await
+    }
+}
+
+pub fn connection_from_slice<Node: Clone>(
+    nodes: &[Node],
+    first: Option<i32>,
+    after: Option<&str>,
+    last: Option<i32>,
+    before: Option<&str>,
+) -> ResolverResult<Connection<Node>> {
+    let (start, end) = page_range(nodes.len(), first, after, last, before)?;
+    Ok(build_connection(
+        nodes[start..end].to_vec(),
+        start,
+        nodes.len(),
+    ))
+}
+
+pub async fn connection_from_fetch<Node, F, Fut>(
+    total_count: usize,
+    first: Option<i32>,
+    after: Option<&str>,
+    last: Option<i32>,
+    before: Option<&str>,
+    fetch: F,
+) -> ResolverResult<Connection<Node>>
+where
+    F: FnOnce(usize, usize) -> Fut,
+    Fut: Future<Output = ResolverResult<Vec<Node>>>,
+{
+    let (start, end) = page_range(total_count, first, after, last, before)?;
+    let nodes = fetch(start, end - start).await?;
+    Ok(build_connection(nodes, start, total_count))
+}
+
+fn build_connection<Node>(nodes: Vec<Node>, start: usize, total_count: usize) -> Connection<Node> {
+    let end = start + nodes.len();
+    let edges: Vec<Edge<Node>> = nodes
+        .into_iter()
+        .enumerate()
+        .map(|(index, node)| Edge::new(encode_cursor(start + index), node))
+        .collect();
+
+    let page_info = PageInfo {
+        has_previous_page: start > 0,
+        has_next_page: end < total_count,
+        start_cursor: edges.first().map(|edge| edge.cursor.clone()),
+        end_cursor: edges.last().map(|edge| edge.cursor.clone()),
+    };
+
+    Connection::new(edges, page_info, Some(total_count))
+}
+
+fn page_range(
+    total_count: usize,
+    first: Option<i32>,
+    after: Option<&str>,
+    last: Option<i32>,
+    before: Option<&str>,
+) -> ResolverResult<(usize, usize)> {
+    let mut start = 0;
+    let mut end = total_count;
+
+    if let Some(after) = after {
+        let offset = decode_cursor(after).map_err(bad_request)?;
+        start = offset.saturating_add(1).min(end);
+    }
+
+    if let Some(before) = before {
+        let offset = decode_cursor(before).map_err(bad_request)?;
+        end = offset.clamp(start, end);
+    }
+
+    if let Some(first) = first {
+        if first < 0 {
+            return Err(bad_request("first must be a non-negative integer"));
+        }
+        end = end.min(start.saturating_add(first as usize));
+    }
+
+    if let Some(last) = last {
+        if last < 0 {
+            return Err(bad_request("last must be a non-negative integer"));
+        }
+        start = start.max(end.saturating_sub(last as usize));
+    }
+
+    Ok((start, end))
+}
+
+fn bad_request(message: impl Into<String>) -> GqlError {
+    GqlError::new(message, None).with_error_type(GqlErrorType::BadRequest)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{connection_from_slice, decode_cursor, encode_cursor};
+    use crate::GqlErrorType;
+
+    #[test]
+    fn test_cursor() {
+        let cursor = encode_cursor(10);
+        assert_eq!(decode_cursor(&cursor), Ok(10));
+        assert!(decode_cursor("invalid").is_err());
+    }
+
+    #[test]
+    fn test_first_and_after() {
+        let nodes = vec![0, 1, 2, 3, 4];
+        let connection =
+            connection_from_slice(&nodes, Some(2), Some(&encode_cursor(0)), None, None).unwrap();
+        let values: Vec<i32> = connection.edges.iter().map(|edge| edge.node).collect();
+
+        assert_eq!(values, vec![1, 2]);
+        assert!(connection.page_info.has_previous_page);
+        assert!(connection.page_info.has_next_page);
+        assert_eq!(connection.page_info.start_cursor, Some(encode_cursor(1)));
+        assert_eq!(connection.page_info.end_cursor, Some(encode_cursor(2)));
+        assert_eq!(connection.total_count, Some(5));
+    }
+
+    #[test]
+    fn test_last_and_before() {
+        let nodes = vec![0, 1, 2, 3, 4];
+        let connection =
+            connection_from_slice(&nodes, None, None, Some(2), Some(&encode_cursor(4))).unwrap();
+        let values: Vec<i32> = connection.edges.iter().map(|edge| edge.node).collect();
+
+        assert_eq!(values, vec![2, 3]);
+        assert!(connection.page_info.has_previous_page);
+        assert!(connection.page_info.has_next_page);
+    }
+
+    #[test]
+    fn test_all_nodes() {
+        let nodes = vec![0, 1, 2];
+        let connection = connection_from_slice(&nodes, None, None, None, None).unwrap();
+
+        assert_eq!(connection.edges.len(), 3);
+        assert!(!connection.page_info.has_previous_page);
+        assert!(!connection.page_info.has_next_page);
+    }
+
+    #[test]
+    fn test_negative_first() {
+        let nodes = vec![0, 1, 2];
+        assert!(connection_from_slice(&nodes, Some(-1), None, None, None).is_err());
+    }
+
+    #[test]
+    fn test_malicious_cursor() {
+        let nodes = vec![0, 1, 2];
+        let cursor = encode_cursor(usize::MAX);
+        let connection =
+            connection_from_slice(&nodes, None, Some(&cursor), None, Some(&cursor)).unwrap();
+        assert!(connection.edges.is_empty());
+
+        let err = connection_from_slice(&nodes, None, Some("invalid"), None, None).unwrap_err();
+        assert_eq!(err.error_type(), Some(GqlErrorType::BadRequest));
+    }
+}
@@ -0,0 +1,29 @@
+use crate::*;
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PageInfo {
+    pub has_previous_page: bool,
+    pub has_next_page: bool,
+    pub start_cursor: Option<String>,
+    pub end_cursor: Option<String>,
+}
+
+#[allow(non_snake_case)]
+#[GqlType(internal)]
+impl PageInfo {
+    async fn hasPreviousPage(&self) -> bool {
+        self.has_previous_page
+    }
+
+    async fn hasNextPage(&self) -> bool {
+        self.has_next_page
+    }
+
+    async fn startCursor(&self) -> Option<String> {
+        self.start_cursor.clone()
+    }
+
+    async fn endCursor(&self) -> Option<String> {
+        self.end_cursor.clone()
+    }
+}
@@ -1,11 +1,33 @@
-use std::{collections::HashMap, ops::Deref, sync::Arc};
+use std::{
+    any::Any,
+    collections::{BTreeMap, HashMap},
+    ops::Deref,
+    sync::Arc,
+};
 
 use crate::{
     error::GqlError,
+    federation::{federation_sdl, Federation},
+    guard::GuardDirective,
+    node::{node_types, relay_node_sdl, RelayNode},
+    resolver::check_resolvers,
     types::schema::{build_schema, Schema},
-    CustomDirective, QueryRoot, SelectionSetResolver,
+    CustomDirective, CustomScalar, Data, EntityResolver, ErrorFormatter, ErrorMasking, Extension,
+    GqlValue, Guard, NodeFetcher, QueryRoot, Request, ResolverRegistry, SelectionSetResolver,
+    TypeDefinition,
 };
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum IntrospectionMode {
+    #[default]
+    Enabled,
+    /// Only `__typename` is allowed, `__schema` and `__type` return an error.
+    TypenameOnly,
+    Disabled,
+}
+
+pub type IntrospectionCallback = Box<dyn Fn(&Request) -> IntrospectionMode + Send + Sync>;
+
 pub struct ContainerInner<
     Query: SelectionSetResolver,
     Mutation: SelectionSetResolver,
@@ -15,15 +37,35 @@ pub struct ContainerInner<
     pub mutation_resolvers: Mutation,
     pub subscription_resolvers: Subscription,
     pub schema: Schema,
+    pub data: Data,
+    pub extensions: Vec<Box<dyn Extension>>,
+    pub depth_limit: Option<usize>,
+    pub complexity_limit: Option<usize>,
+    pub batch_limit: Option<usize>,
+    pub batch_concurrency: Option<usize>,
+    pub introspection_mode: IntrospectionMode,
+    pub introspection_callback: Option<IntrospectionCallback>,
+    pub error_formatter: Box<dyn ErrorFormatter>,
+    pub registry: ResolverRegistry,
 }
 
-#[derive(Clone)]
 pub struct Container<
     Query: SelectionSetResolver,
     Mutation: SelectionSetResolver,
     Subscription: SelectionSetResolver,
 >(Arc<ContainerInner<Query, Mutation, Subscription>>);
 
+impl<Query, Mutation, Subscription> Clone for Container<Query, Mutation, Subscription>
+where
+    Query: SelectionSetResolver,
+    Mutation: SelectionSetResolver,
+    Subscription: SelectionSetResolver,
+{
+    fn clone(&self) -> Self {
+        Container(self.0.clone())
+    }
+}
+
 impl<Query, Mutation, Subscription> Deref for Container<Query, Mutation, Subscription>
 where
     Query: SelectionSetResolver + 'static,
@@ -43,6 +85,13 @@ where
     Mutation: SelectionSetResolver + 'static,
     Subscription: SelectionSetResolver + 'static,
 {
+    pub(crate) fn introspection_mode(&self, request: &Request) -> IntrospectionMode {
+        match &self.introspection_callback {
+            Some(callback) => callback(request),
+            None => self.introspection_mode,
+        }
+    }
+
     pub fn new(
         schema_doc: &[&str],
         query: Query,
@@ -50,12 +99,325 @@ where
         subscription: Subscription,
         custom_directives: HashMap<&'static str, Box<dyn CustomDirective>>,
     ) -> Result<Self, GqlError> {
-        let schema = build_schema(schema_doc, custom_directives)?;
+        let mut builder = Self::builder(schema_doc, query, mutation, subscription);
+        builder.custom_directives = custom_directives;
+        builder.build().map_err(|mut errors| errors.remove(0))
+    }
+
+    pub fn builder<'a>(
+        schema_doc: &'a [&'a str],
+        query: Query,
+        mutation: Mutation,
+        subscription: Subscription,
+    ) -> ContainerBuilder<'a, Query, Mutation, Subscription> {
+        ContainerBuilder {
+            schema_doc,
+            query,
+            mutation,
+            subscription,
+            custom_directives: Default::default(),
+            custom_scalars: Default::default(),
+            data: Default::default(),
+            extensions: Default::default(),
+            depth_limit: None,
+            complexity_limit: None,
+            batch_limit: Some(10),
+            batch_concurrency: None,
+            introspection_mode: IntrospectionMode::Enabled,
+            introspection_callback: None,
+            error_formatter: Box::new(ErrorMasking::default()),
+            check_resolvers: false,
+            enable_federation: false,
+            entity_resolvers: Default::default(),
+            node_fetchers: Default::default(),
+        }
+    }
+}
+
+pub struct ContainerBuilder<'a, Query, Mutation, Subscription> {
+    schema_doc: &'a [&'a str],
+    query: Query,
+    mutation: Mutation,
+    subscription: Subscription,
+    custom_directives: HashMap<&'static str, Box<dyn CustomDirective>>,
+    custom_scalars: HashMap<String, Box<dyn CustomScalar>>,
+    data: Data,
+    extensions: Vec<Box<dyn Extension>>,
+    depth_limit: Option<usize>,
+    complexity_limit: Option<usize>,
+    batch_limit: Option<usize>,
+    batch_concurrency: Option<usize>,
+    introspection_mode: IntrospectionMode,
+    introspection_callback: Option<IntrospectionCallback>,
+    error_formatter: Box<dyn ErrorFormatter>,
+    check_resolvers: bool,
+    enable_federation: bool,
+    entity_resolvers: HashMap<String, Box<dyn EntityResolver>>,
+    node_fetchers: HashMap<String, Box<dyn NodeFetcher>>,
+}
+
+impl<'a, Query, Mutation, Subscription> ContainerBuilder<'a, Query, Mutation, Subscription>
+where
+    Query: SelectionSetResolver + 'static,
+    Mutation: SelectionSetResolver + 'static,
+    Subscription: SelectionSetResolver + 'static,
+{
+    pub fn directive(
+        mut self,
+        name: &'static str,
+        directive: impl CustomDirective + 'static,
+    ) -> Self {
+        self.custom_directives.insert(name, Box::new(directive));
+        self
+    }
+
+    /// Registers a directive which checks the guard built from its arguments before the field is resolved.
+    pub fn guard_directive<G: Guard + 'static>(
+        self,
+        name: &'static str,
+        guard: impl Fn(&BTreeMap<String, GqlValue>) -> G + Send + Sync + 'static,
+    ) -> Self {
+        self.directive(name, GuardDirective(guard))
+    }
+
+    pub fn scalar(mut self, name: &str, scalar: impl CustomScalar + 'static) -> Self {
+        self.custom_scalars
+            .insert(name.to_string(), Box::new(scalar));
+        self
+    }
+
+    pub fn data<T: Any + Send + Sync>(mut self, data: T) -> Self {
+        self.data.insert(data);
+        self
+    }
+
+    pub fn extension(mut self, extension: impl Extension + 'static) -> Self {
+        self.extensions.push(Box::new(extension));
+        self
+    }
+
+    pub fn limit_depth(mut self, depth: usize) -> Self {
+        self.depth_limit = Some(depth);
+        self
+    }
+
+    pub fn limit_complexity(mut self, complexity: usize) -> Self {
+        self.complexity_limit = Some(complexity);
+        self
+    }
+
+    /// The maximum number of requests in a batch, 10 by default.
+    pub fn limit_batch(mut self, size: usize) -> Self {
+        self.batch_limit = Some(size);
+        self
+    }
+
+    pub fn batch_concurrency(mut self, concurrency: usize) -> Self {
+        self.batch_concurrency = Some(concurrency);
+        self
+    }
+
+    pub fn introspection(mut self, mode: IntrospectionMode) -> Self {
+        self.introspection_mode = mode;
+        self
+    }
+
+    pub fn disable_introspection(self) -> Self {
+        self.introspection(IntrospectionMode::Disabled)
+    }
+
+    /// Decides the introspection mode per request, instead of the mode set by `introspection`.
+    pub fn introspection_callback(
+        mut self,
+        callback: impl Fn(&Request) -> IntrospectionMode + Send + Sync + 'static,
+    ) -> Self {
+        self.introspection_callback = Some(Box::new(callback));
+        self
+    }
+
+    /// Replaces the default formatter, which masks `GqlErrorType::Internal` errors.
+    pub fn error_formatter(mut self, formatter: impl ErrorFormatter + 'static) -> Self {
+        self.error_formatter = Box::new(formatter);
+        self
+    }
+
+    /// Fails to build if a schema field has no resolver or a resolver is not in the schema.
+    ///
+    /// Only the types reachable from the root and `#[GqlType]` resolvers are checked,
+    /// and the types returned by hand-written resolvers, such as a renamed `Connection`, are skipped.
+    pub fn check_resolvers(mut self) -> Self {
+        self.check_resolvers = true;
+        self
+    }
+
+    pub fn enable_federation(mut self) -> Self {
+        self.enable_federation = true;
+        self
+    }
+
+    pub fn entity_resolver(
+        mut self,
+        type_name: &str,
+        resolver: impl EntityResolver + 'static,
+    ) -> Self {
+        self.enable_federation = true;
+        self.entity_resolvers
+            .insert(type_name.to_string(), Box::new(resolver));
+        self
+    }
+
+    pub fn node_fetcher(mut self, type_name: &str, fetcher: impl NodeFetcher + 'static) -> Self {
+        self.node_fetchers
+            .insert(type_name.to_string(), Box::new(fetcher));
+        self
+    }
+
+    pub fn build(self) -> Result<Container<Query, Mutation, Subscription>, Vec<GqlError>> {
+        let mut errors = Vec::new();
+        let directive_names: Vec<&'static str> = self.custom_directives.keys().copied().collect();
+
+        let mut schema_doc = self.schema_doc.to_vec();
+        let (federation_doc, federation_root_fields) = if self.enable_federation {
+            federation_sdl(self.schema_doc).map_err(|err| vec![err])?
+        } else {
+            Default::default()
+        };
+        if self.enable_federation {
+            schema_doc.push(&federation_doc);
+        }
+        let (relay_node_doc, relay_node_root_fields) = if self.node_fetchers.is_empty() {
+            Default::default()
+        } else {
+            relay_node_sdl(self.schema_doc).map_err(|err| vec![err])?
+        };
+        if !relay_node_doc.is_empty() {
+            schema_doc.push(&relay_node_doc);
+        }
+
+        let mut schema = match build_schema(&schema_doc, self.custom_directives) {
+            Ok(schema) => schema,
+            Err(error) => return Err(vec![error]),
+        };
+
+        for name in directive_names {
+            if !schema.directives.contains_key(name) {
+                errors.push(GqlError::new(
+                    format!("Directive @{} is not defined in the schema.", name),
+                    None,
+                ));
+            }
+        }
+
+        for name in self.custom_scalars.keys() {
+            if !matches!(
+                schema.type_definitions.get(name),
+                Some(TypeDefinition::Scalar(_))
+            ) {
+                errors.push(GqlError::new(
+                    format!("Scalar {} is not defined in the schema.", name),
+                    None,
+                ));
+            }
+        }
+        if let Err(error) = schema.set_custom_scalars(self.custom_scalars) {
+            errors.push(error);
+        }
+
+        let mut registry = ResolverRegistry::default();
+        registry.register::<Query>();
+        registry.register::<Mutation>();
+        registry.register::<Subscription>();
+        if let Err(error) = schema.set_field_deprecations(&registry) {
+            errors.push(error);
+        }
+
+        let federation = if self.enable_federation {
+            let entity_types = match schema.type_definitions.get("_Entity") {
+                Some(TypeDefinition::Union(entity)) => entity.types.clone(),
+                _ => Vec::new(),
+            };
+            for type_name in self.entity_resolvers.keys() {
+                if !entity_types.contains(type_name) {
+                    errors.push(GqlError::new(
+                        format!("{} is not an entity type with @key.", type_name),
+                        None,
+                    ));
+                }
+            }
+            Some(Federation {
+                sdl: self.schema_doc.join("\n"),
+                entity_resolvers: self.entity_resolvers,
+                root_fields: federation_root_fields,
+            })
+        } else {
+            None
+        };
+
+        let relay_node = if self.node_fetchers.is_empty() {
+            None
+        } else {
+            let node_types = node_types(&schema.type_definitions);
+            for type_name in self.node_fetchers.keys() {
+                if !node_types.contains(type_name) {
+                    errors.push(GqlError::new(
+                        format!("{} does not implement Node.", type_name),
+                        None,
+                    ));
+                }
+            }
+            Some(RelayNode {
+                fetchers: self.node_fetchers,
+                root_fields: relay_node_root_fields,
+            })
+        };
+
+        if self.check_resolvers {
+            let root_types = [
+                (Query::type_name(), schema.query_type_name.as_str()),
+                (Mutation::type_name(), schema.mutation_type_name.as_str()),
+                (
+                    Subscription::type_name(),
+                    schema.subscription_type_name.as_str(),
+                ),
+            ];
+            let mut ignored_root_fields = Vec::new();
+            if let Some(federation) = &federation {
+                ignored_root_fields.extend(federation.root_fields.iter().copied());
+            }
+            if let Some(relay_node) = &relay_node {
+                ignored_root_fields.extend(relay_node.root_fields.iter().copied());
+            }
+            errors.extend(check_resolvers(
+                &schema,
+                &registry,
+                &root_types,
+                &ignored_root_fields,
+            ));
+        }
+
+        if !errors.is_empty() {
+            return Err(errors);
+        }
+
         Ok(Container(Arc::new(ContainerInner {
-            query_resolvers: QueryRoot { query },
-            mutation_resolvers: mutation,
-            subscription_resolvers: subscription,
+            query_resolvers: QueryRoot {
+                query: self.query,
+                federation,
+                relay_node,
+            },
+            mutation_resolvers: self.mutation,
+            subscription_resolvers: self.subscription,
             schema,
+            data: self.data,
+            extensions: self.extensions,
+            depth_limit: self.depth_limit,
+            complexity_limit: self.complexity_limit,
+            batch_limit: self.batch_limit,
+            batch_concurrency: self.batch_concurrency,
+            introspection_mode: self.introspection_mode,
+            introspection_callback: self.introspection_callback,
+            error_formatter: self.error_formatter,
+            registry,
         })))
     }
 }
@@ -1,6 +1,9 @@
+use std::any::Any;
+
 use crate::{
-    error::GqlError, input::GqlInputType, operation::Operation, types::schema::Schema, GqlValue,
-    ResolverResult,
+    custom_scalar::coerce_input_value, error::GqlError, incremental::DeferScope,
+    input::GqlInputType, operation::Operation, types::schema::Schema, Data, FieldType,
+    GqlErrorType, GqlValue, ResolverResult,
 };
 use graphql_parser::{
     query::{Field, SelectionSet},
@@ -12,6 +15,9 @@ pub struct ExecutionContext<'a, T> {
     pub schema: &'a Schema,
     pub operation: &'a Operation<'a>,
     pub item: T,
+    pub data: &'a Data,
+    pub field_definition: Option<&'a FieldType>,
+    pub(crate) defer_scope: Option<DeferScope>,
 }
 
 pub type Context<'a> = ExecutionContext<'a, &'a Field<'a, String>>;
@@ -24,19 +30,39 @@ impl<'a> Context<'a> {
             .iter()
             .find(|(name, _)| name == arg_name)
             .map(|(_, v)| v);
-        let gql_value = match value {
-            Some(v) => {
-                if let Value::Variable(var_name) = v {
-                    self.resolve_variable_value(var_name)?
-                } else {
-                    GqlValue::from(v.clone())
-                }
+        // An absent argument is passed as `None`, so that `MaybeUndefined` can tell it from `null`.
+        let absent = || {
+            T::from_gql_value(None)
+                .map_err(|err| GqlError::new(err, None).with_error_type(GqlErrorType::BadRequest))
+        };
+        let (gql_value, is_literal) = match value {
+            // A variable which is neither provided nor has a default value leaves the argument absent.
+            Some(Value::Variable(var_name)) => match self.operation.variable_value(var_name) {
+                Some(value) => value,
+                None => return absent(),
+            },
+            Some(v) => (GqlValue::from(v.clone()), true),
+            None => return absent(),
+        };
+        let arg_type = self.field_definition.and_then(|field_def| {
+            field_def
+                .arguments
+                .iter()
+                .find(|arg| arg.name == arg_name)
+                .map(|arg| &arg.meta_type)
+        });
+        let gql_value = match arg_type {
+            Some(ty) => {
+                coerce_input_value(self.schema, ty, gql_value, is_literal).map_err(|err| {
+                    GqlError::new(err, Some(self.item.position))
+                        .with_error_type(GqlErrorType::BadRequest)
+                })?
             }
-            None => GqlValue::Null,
+            None => gql_value,
         };
         match T::from_gql_value(Some(gql_value)) {
             Ok(v) => Ok(v),
-            Err(err) => Err(GqlError::new(err, None)),
+            Err(err) => Err(GqlError::new(err, None).with_error_type(GqlErrorType::BadRequest)),
         }
     }
 }
@@ -52,6 +78,19 @@ impl<'a, T> ExecutionContext<'a, T> {
             schema: self.schema,
             operation: self.operation,
             item: field,
+            data: self.data,
+            field_definition: None,
+            defer_scope: self
+                .defer_scope
+                .as_ref()
+                .map(|scope| scope.with_field(&field.name)),
+        }
+    }
+
+    pub fn with_field_definition(self, field_definition: Option<&'a FieldType>) -> Self {
+        ExecutionContext {
+            field_definition,
+            ..self
         }
     }
 
@@ -63,6 +102,9 @@ impl<'a, T> ExecutionContext<'a, T> {
             schema: self.schema,
             operation: self.operation,
             item: selection_set,
+            data: self.data,
+            field_definition: None,
+            defer_scope: self.defer_scope.clone(),
         }
     }
 
@@ -89,23 +131,33 @@ impl<'a, T> ExecutionContext<'a, T> {
 
         false
     }
+    pub fn get_data<D: Any + Send + Sync>(&self) -> ResolverResult<&'a D> {
+        self.data.get::<D>().ok_or_else(|| {
+            GqlError::new(
+                format!("Data {} does not exist.", std::any::type_name::<D>()),
+                None,
+            )
+        })
+    }
+
+    pub fn get_data_opt<D: Any + Send + Sync>(&self) -> Option<&'a D> {
+        self.data.get::<D>()
+    }
+
     pub fn add_error(&self, error: &GqlError) {
-        self.operation.errors.lock().unwrap().push(error.clone());
+        match self.defer_scope.as_ref().and_then(|scope| scope.errors()) {
+            Some(errors) => errors.lock().unwrap().push(error.clone()),
+            None => self.operation.errors.lock().unwrap().push(error.clone()),
+        }
     }
 
     pub fn resolve_variable_value(&self, name: &str) -> ResolverResult<GqlValue> {
-        let v = self
-            .operation
-            .variable_definitions
-            .iter()
-            .find(|var_def| var_def.name == name)
-            .and_then(|var_def| self.operation.variables.0.get(&var_def.name));
-        match v {
-            Some(value) => Ok(value.clone()),
-            None => Err(GqlError::new(
-                format!("Variable {} is not defined", name),
-                None,
-            )),
+        match self.operation.variable_value(name) {
+            Some((value, _)) => Ok(value),
+            None => Err(
+                GqlError::new(format!("Variable {} is not defined", name), None)
+                    .with_error_type(GqlErrorType::BadRequest),
+            ),
         }
     }
 }
@@ -113,10 +165,14 @@ impl<'a, T> ExecutionContext<'a, T> {
 pub(crate) fn build_context<'a>(
     schema: &'a Schema,
     operation: &'a Operation<'a>,
+    data: &'a Data,
 ) -> ExecutionContext<'a, &'a SelectionSet<'a, String>> {
     ExecutionContext {
         schema,
         operation,
         item: &operation.selection_set,
+        data,
+        field_definition: None,
+        defer_scope: None,
     }
 }
@@ -11,3 +11,17 @@ pub trait CustomDirective: Send + Sync {
         resolve_fut: ResolveFut<'_>,
     ) -> ResolverResult<Option<GqlValue>>;
 }
+
+#[async_trait::async_trait]
+impl CustomDirective for Box<dyn CustomDirective> {
+    async fn resolve_field(
+        &self,
+        ctx: &Context<'_>,
+        directive_args: &BTreeMap<String, GqlValue>,
+        resolve_fut: ResolveFut<'_>,
+    ) -> ResolverResult<Option<GqlValue>> {
+        self.as_ref()
+            .resolve_field(ctx, directive_args, resolve_fut)
+            .await
+    }
+}
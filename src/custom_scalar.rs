@@ -0,0 +1,103 @@
+use crate::{types::schema::Schema, GqlValue, GqlValueType, TypeDefinition};
+
+pub trait CustomScalar: Send + Sync {
+    /// Coerces a value provided through variables.
+    fn parse_value(&self, value: GqlValue) -> Result<GqlValue, String>;
+
+    /// Coerces a literal written in the query.
+    fn parse_literal(&self, value: GqlValue) -> Result<GqlValue, String> {
+        self.parse_value(value)
+    }
+
+    /// Converts a resolved value into the response representation.
+    fn serialize(&self, value: GqlValue) -> Result<GqlValue, String> {
+        Ok(value)
+    }
+}
+
+impl CustomScalar for Box<dyn CustomScalar> {
+    fn parse_value(&self, value: GqlValue) -> Result<GqlValue, String> {
+        self.as_ref().parse_value(value)
+    }
+
+    fn parse_literal(&self, value: GqlValue) -> Result<GqlValue, String> {
+        self.as_ref().parse_literal(value)
+    }
+
+    fn serialize(&self, value: GqlValue) -> Result<GqlValue, String> {
+        self.as_ref().serialize(value)
+    }
+}
+
+pub(crate) fn coerce_input_value(
+    schema: &Schema,
+    ty: &GqlValueType,
+    value: GqlValue,
+    is_literal: bool,
+) -> Result<GqlValue, String> {
+    match (ty, value) {
+        (_, GqlValue::Null) => Ok(GqlValue::Null),
+        (_, GqlValue::Variable(name)) => Ok(GqlValue::Variable(name)),
+        (GqlValueType::NonNullType(non_null_type), value) => {
+            coerce_input_value(schema, non_null_type, value, is_literal)
+        }
+        (GqlValueType::ListType(list_type), GqlValue::List(values)) => values
+            .into_iter()
+            .map(|v| coerce_input_value(schema, list_type, v, is_literal))
+            .collect::<Result<Vec<_>, _>>()
+            .map(GqlValue::List),
+        (GqlValueType::ListType(list_type), value) => {
+            coerce_input_value(schema, list_type, value, is_literal)
+        }
+        (GqlValueType::NamedType(type_name), value) => {
+            match schema.type_definitions.get(type_name) {
+                Some(TypeDefinition::Scalar(_)) => match schema.custom_scalars.get(type_name) {
+                    Some(scalar) if is_literal => scalar.parse_literal(value),
+                    Some(scalar) => scalar.parse_value(value),
+                    None => Ok(value),
+                },
+                Some(TypeDefinition::InputObject(input_object)) => match value {
+                    GqlValue::Object(mut obj) => {
+                        for field in &input_object.fields {
+                            if let Some(field_value) = obj.remove(&field.name) {
+                                let field_value = coerce_input_value(
+                                    schema,
+                                    &field.meta_type,
+                                    field_value,
+                                    is_literal,
+                                )?;
+                                obj.insert(field.name.clone(), field_value);
+                            }
+                        }
+                        Ok(GqlValue::Object(obj))
+                    }
+                    value => Ok(value),
+                },
+                _ => Ok(value),
+            }
+        }
+    }
+}
+
+pub(crate) fn serialize_output_value(
+    schema: &Schema,
+    ty: &GqlValueType,
+    value: GqlValue,
+) -> Result<GqlValue, String> {
+    match (ty, value) {
+        (_, GqlValue::Null) => Ok(GqlValue::Null),
+        (GqlValueType::NonNullType(non_null_type), value) => {
+            serialize_output_value(schema, non_null_type, value)
+        }
+        (GqlValueType::ListType(list_type), GqlValue::List(values)) => values
+            .into_iter()
+            .map(|v| serialize_output_value(schema, list_type, v))
+            .collect::<Result<Vec<_>, _>>()
+            .map(GqlValue::List),
+        (GqlValueType::ListType(_), value) => Ok(value),
+        (GqlValueType::NamedType(type_name), value) => match schema.custom_scalars.get(type_name) {
+            Some(scalar) => scalar.serialize(value),
+            None => Ok(value),
+        },
+    }
+}
@@ -0,0 +1,45 @@
+use std::{
+    any::{Any, TypeId},
+    collections::HashMap,
+};
+
+#[derive(Default)]
+pub struct Data(HashMap<TypeId, Box<dyn Any + Send + Sync>>);
+
+impl Data {
+    pub fn insert<T: Any + Send + Sync>(&mut self, data: T) {
+        self.0.insert(TypeId::of::<T>(), Box::new(data));
+    }
+
+    pub fn get<T: Any + Send + Sync>(&self) -> Option<&T> {
+        self.0
+            .get(&TypeId::of::<T>())
+            .and_then(|data| data.downcast_ref::<T>())
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl std::fmt::Debug for Data {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("Data").finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Data;
+
+    #[test]
+    fn test_insert_and_get() {
+        let mut data = Data::default();
+        data.insert(10_i32);
+        data.insert("value".to_string());
+
+        assert_eq!(data.get::<i32>(), Some(&10));
+        assert_eq!(data.get::<String>(), Some(&"value".to_string()));
+        assert!(data.get::<u64>().is_none());
+    }
+}
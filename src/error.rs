@@ -6,6 +6,8 @@ use std::{
 use graphql_parser::Pos;
 use serde::{Deserialize, Serialize};
 
+use crate::{types::value::serialize_into_gql_value, GqlValue};
+
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct Location {
     pub line: usize,
@@ -34,12 +36,21 @@ pub struct GqlTypedError {
     pub debug_uri: Option<String>,
 }
 
+impl GqlTypedError {
+    pub fn to_extensions(&self) -> BTreeMap<String, GqlValue> {
+        match serialize_into_gql_value(self) {
+            Ok(GqlValue::Object(extensions)) => extensions,
+            _ => Default::default(),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct GqlError {
     pub message: String,
     pub locations: Vec<Location>,
     pub path: Vec<String>,
-    pub extensions: Option<GqlTypedError>,
+    pub extensions: Option<BTreeMap<String, GqlValue>>,
 }
 
 impl GqlError {
@@ -65,9 +76,37 @@ impl GqlError {
     }
 
     pub fn set_extentions(&mut self, typed_error: GqlTypedError) -> Self {
-        self.extensions = Some(typed_error);
+        self.extensions
+            .get_or_insert_with(Default::default)
+            .extend(typed_error.to_extensions());
         self.clone()
     }
+
+    pub fn set_extension(&mut self, name: impl Into<String>, value: impl Into<GqlValue>) -> Self {
+        self.extensions
+            .get_or_insert_with(Default::default)
+            .insert(name.into(), value.into());
+        self.clone()
+    }
+
+    /// Sets the `errorType` of `GqlTypedError` in the extensions.
+    pub fn with_error_type(mut self, error_type: GqlErrorType) -> Self {
+        self.set_extension(
+            "errorType",
+            serialize_into_gql_value(error_type).unwrap_or_default(),
+        );
+        self
+    }
+
+    /// The `errorType` of `GqlTypedError` in the extensions.
+    pub fn error_type(&self) -> Option<GqlErrorType> {
+        match self.extensions.as_ref()?.get("errorType")? {
+            GqlValue::String(error_type) | GqlValue::Enum(error_type) => {
+                serde_json::from_value(serde_json::Value::String(error_type.clone())).ok()
+            }
+            _ => None,
+        }
+    }
 }
 
 impl Display for GqlError {
@@ -83,7 +122,7 @@ impl Display for GqlError {
 
 pub struct Error {
     pub message: String,
-    pub extensions: Option<GqlTypedError>,
+    pub extensions: Option<BTreeMap<String, GqlValue>>,
 }
 
 impl Error {
@@ -93,6 +132,14 @@ impl Error {
             extensions: None,
         }
     }
+
+    pub fn set_extension(mut self, name: impl Into<String>, value: impl Into<GqlValue>) -> Self {
+        self.extensions
+            .get_or_insert_with(Default::default)
+            .insert(name.into(), value.into());
+        self
+    }
+
     pub fn into_gql_error(self, pos: Pos) -> GqlError {
         GqlError {
             message: self.message,
@@ -128,3 +175,74 @@ impl PartialEq for Error {
         self.message.eq(&other.message) && self.extensions.eq(&other.extensions)
     }
 }
+
+/// Converts a user error into a message with extensions.
+///
+/// Errors implementing this trait keep their extensions when they are returned from resolvers.
+pub trait ErrorExtensions {
+    fn message(&self) -> String;
+
+    fn extensions(&self) -> BTreeMap<String, GqlValue> {
+        Default::default()
+    }
+
+    fn extend(&self) -> Error {
+        let extensions = self.extensions();
+        Error {
+            message: self.message(),
+            extensions: if extensions.is_empty() {
+                None
+            } else {
+                Some(extensions)
+            },
+        }
+    }
+}
+
+// Used by `#[GqlType]` to prefer `ErrorExtensions` over `Display` for the error of a resolver.
+#[doc(hidden)]
+pub mod __private {
+    use graphql_parser::Pos;
+
+    use super::{Error, ErrorExtensions, GqlError, GqlErrorType};
+
+    pub struct ErrorWrap<T>(pub Option<T>);
+
+    pub trait ViaErrorExtensions {
+        fn into_error(self) -> Error;
+    }
+
+    impl<T: ErrorExtensions> ViaErrorExtensions for ErrorWrap<T> {
+        fn into_error(self) -> Error {
+            match self.0 {
+                Some(err) => err.extend(),
+                None => Error::new(""),
+            }
+        }
+    }
+
+    pub trait ViaFrom {
+        fn into_error(self) -> Error;
+    }
+
+    impl<T> ViaFrom for &mut ErrorWrap<T>
+    where
+        Error: From<T>,
+    {
+        fn into_error(self) -> Error {
+            match self.0.take() {
+                Some(err) => Error::from(err),
+                None => Error::new(""),
+            }
+        }
+    }
+
+    // Guard rejections without a type are reported as `PermissionDenied`, so that they are not masked.
+    pub fn guard_error(err: Error, pos: Pos) -> GqlError {
+        let error = err.into_gql_error(pos);
+        match error.error_type() {
+            Some(_) => error,
+            None => error.with_error_type(GqlErrorType::PermissionDenied),
+        }
+    }
+}
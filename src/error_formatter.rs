@@ -0,0 +1,147 @@
+use crate::{GqlError, GqlErrorType};
+
+/// Formats the errors returned from resolvers and extensions before they are sent to the client.
+pub trait ErrorFormatter: Send + Sync {
+    fn format_error(&self, error: GqlError) -> GqlError;
+}
+
+impl<F> ErrorFormatter for F
+where
+    F: Fn(GqlError) -> GqlError + Send + Sync,
+{
+    fn format_error(&self, error: GqlError) -> GqlError {
+        self(error)
+    }
+}
+
+pub struct ErrorMasking {
+    message: String,
+    mask_untyped: bool,
+    allowed_types: Vec<GqlErrorType>,
+}
+
+impl Default for ErrorMasking {
+    fn default() -> Self {
+        ErrorMasking {
+            message: "Internal server error".to_string(),
+            mask_untyped: false,
+            allowed_types: vec![
+                GqlErrorType::BadRequest,
+                GqlErrorType::FailedPreCondition,
+                GqlErrorType::NotFound,
+                GqlErrorType::PermissionDenied,
+                GqlErrorType::Unauthenticated,
+                GqlErrorType::Unavailable,
+                GqlErrorType::Unknown,
+            ],
+        }
+    }
+}
+
+impl ErrorMasking {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Masks every error except the allowed error types, including errors without a type.
+    pub fn mask_all() -> Self {
+        ErrorMasking {
+            mask_untyped: true,
+            allowed_types: Vec::new(),
+            ..Default::default()
+        }
+    }
+
+    pub fn message(mut self, message: impl Into<String>) -> Self {
+        self.message = message.into();
+        self
+    }
+
+    /// Whether errors without a type are masked. Only `ErrorMasking::mask_all` masks them by default.
+    pub fn mask_untyped(mut self, mask_untyped: bool) -> Self {
+        self.mask_untyped = mask_untyped;
+        self
+    }
+
+    pub fn allow(mut self, error_type: GqlErrorType) -> Self {
+        if !self.allowed_types.contains(&error_type) {
+            self.allowed_types.push(error_type);
+        }
+        self
+    }
+
+    fn is_masked(&self, error: &GqlError) -> bool {
+        match error.error_type() {
+            Some(error_type) => !self.allowed_types.contains(&error_type),
+            None => self.mask_untyped,
+        }
+    }
+}
+
+impl ErrorFormatter for ErrorMasking {
+    fn format_error(&self, error: GqlError) -> GqlError {
+        if !self.is_masked(&error) {
+            return error;
+        }
+
+        let correlation_id = correlation_id();
+        log::error!("{} (correlation id: {})", error, correlation_id);
+
+        let error_type = error.error_type().unwrap_or(GqlErrorType::Internal);
+        let mut masked = GqlError {
+            message: self.message.clone(),
+            locations: error.locations,
+            path: error.path,
+            extensions: None,
+        }
+        .with_error_type(error_type);
+        masked.set_extension("correlationId", correlation_id);
+        masked
+    }
+}
+
+#[cfg(feature = "uuid")]
+fn correlation_id() -> String {
+    uuid::Uuid::new_v4().to_string()
+}
+
+// A random id formatted as a UUID v4, built from the random keys of `RandomState`.
+#[cfg(not(feature = "uuid"))]
+fn correlation_id() -> String {
+    use std::collections::hash_map::RandomState;
+    use std::hash::{BuildHasher, Hasher};
+    use std::sync::atomic::{AtomicU64, Ordering};
+
+    static COUNTER: AtomicU64 = AtomicU64::new(0);
+    let count = COUNTER.fetch_add(1, Ordering::Relaxed);
+    let random = || {
+        let mut hasher = RandomState::new().build_hasher();
+        hasher.write_u64(count);
+        hasher.finish()
+    };
+    let id = (u128::from(random()) << 64) | u128::from(random());
+    let id = (id & !(0xf << 76) & !(0x3 << 62)) | (0x4 << 76) | (0x2 << 62);
+    format!(
+        "{:08x}-{:04x}-{:04x}-{:04x}-{:012x}",
+        id >> 96,
+        (id >> 80) & 0xffff,
+        (id >> 64) & 0xffff,
+        (id >> 48) & 0xffff,
+        id & 0xffff_ffff_ffff
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::correlation_id;
+
+    #[test]
+    fn test_correlation_id() {
+        let id = correlation_id();
+        let groups: Vec<usize> = id.split('-').map(str::len).collect();
+        assert_eq!(groups, vec![8, 4, 4, 4, 12]);
+        assert_eq!(&id[14..15], "4");
+        assert!(matches!(&id[19..20], "8" | "9" | "a" | "b"));
+        assert_ne!(id, correlation_id());
+    }
+}
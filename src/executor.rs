@@ -1,7 +1,24 @@
+use futures_channel::mpsc;
+use futures_util::{
+    future::{ready, select, BoxFuture, Either},
+    stream,
+    stream::BoxStream,
+    FutureExt, StreamExt,
+};
+use graphql_parser::query::Document;
+use http::header;
+
 use crate::{
-    container::Container, context::build_context, error::GqlError, operation::build_operation,
-    request::Request, resolve_selection_parallelly, resolve_selection_serially, response::Response,
-    validation::apply_validation, OperationType, SelectionSetResolver,
+    container::Container,
+    context::{build_context, SelectionSetContext},
+    error::GqlError,
+    incremental::{resolve_early, DeferScope, IncrementalPayload, IncrementalResponse},
+    operation::{build_operation, Operation},
+    request::{negotiate_content_type, operation_type, BatchRequest, Request, JSON_CONTENT_TYPE},
+    resolve_selection_parallelly, resolve_selection_serially,
+    response::{BatchResponse, Response},
+    validation::{apply_validation, check_complexity_limit, check_depth_limit},
+    GqlValue, OperationType, ResolverResult, SelectionSetResolver,
 };
 
 pub async fn execute<
@@ -12,61 +29,391 @@ pub async fn execute<
     container: &Container<Query, Mutation, Subscription>,
     request: Request,
 ) -> Response {
+    let mut request = request;
+    for extension in &container.extensions {
+        request = match extension.prepare_request(request).await {
+            Ok(req) => req,
+            Err(error) => {
+                return Response::from_errors(format_errors(container, vec![error]));
+            }
+        };
+    }
+
+    let accept = request
+        .http_headers
+        .get(header::ACCEPT)
+        .and_then(|value| value.to_str().ok());
+    let content_type = negotiate_content_type(accept).unwrap_or(JSON_CONTENT_TYPE);
+
+    let (mut response, request_errors) = match execute_request(container, request).await {
+        Ok(response) => (response, 0),
+        Err(response) => {
+            let request_errors = response.errors.len();
+            (response, request_errors)
+        }
+    };
+    response
+        .http_headers
+        .insert(header::CONTENT_TYPE, content_type.to_string());
+    for extension in &container.extensions {
+        response = extension.response(response).await;
+    }
+    // Errors are formatted after the extensions, so that they also apply to the errors added by
+    // the extensions, while the parse and validation errors of the request are left as they are.
+    let errors = response
+        .errors
+        .split_off(request_errors.min(response.errors.len()));
+    response.errors.extend(format_errors(container, errors));
+    response
+}
+
+pub async fn execute_batch<
+    Query: SelectionSetResolver + 'static,
+    Mutation: SelectionSetResolver + 'static,
+    Subscription: SelectionSetResolver + 'static,
+>(
+    container: &Container<Query, Mutation, Subscription>,
+    batch_request: BatchRequest,
+) -> BatchResponse {
+    match batch_request {
+        BatchRequest::Single(request) => BatchResponse::Single(execute(container, request).await),
+        BatchRequest::Batch(requests) => {
+            if requests.is_empty() {
+                let error = GqlError::new("Batch request must not be empty.", None);
+                return BatchResponse::Single(Response::from_errors(vec![error]));
+            }
+            if let Some(batch_limit) = container.batch_limit {
+                if requests.len() > batch_limit {
+                    let error = GqlError::new(
+                        format!(
+                            "Batch size {} exceeds the maximum batch size {}.",
+                            requests.len(),
+                            batch_limit
+                        ),
+                        None,
+                    );
+                    return BatchResponse::Single(Response::from_errors(vec![error]));
+                }
+            }
+            execute_requests(container, requests).await
+        }
+    }
+}
+
+// Queries run concurrently, while any other request runs after the preceding requests and before
+// the following ones, so that mutations take effect in the order of the batch. A request whose
+// operation is unknown before `prepare_request`, such as a persisted query, also runs alone.
+async fn execute_requests<
+    Query: SelectionSetResolver + 'static,
+    Mutation: SelectionSetResolver + 'static,
+    Subscription: SelectionSetResolver + 'static,
+>(
+    container: &Container<Query, Mutation, Subscription>,
+    requests: Vec<Request>,
+) -> BatchResponse {
+    let concurrency = container.batch_concurrency.unwrap_or(requests.len()).max(1);
+    let mut responses = Vec::with_capacity(requests.len());
+    let mut queries = Vec::new();
+    for request in requests {
+        if matches!(
+            operation_type(&request.query, request.operation_name.as_deref()),
+            Some(OperationType::Query)
+        ) {
+            queries.push(request);
+            continue;
+        }
+        let preceding_queries = std::mem::take(&mut queries);
+        responses.extend(execute_concurrently(container, preceding_queries, concurrency).await);
+        responses.push(execute(container, request).await);
+    }
+    responses.extend(execute_concurrently(container, queries, concurrency).await);
+    BatchResponse::Batch(responses)
+}
+
+async fn execute_concurrently<
+    Query: SelectionSetResolver + 'static,
+    Mutation: SelectionSetResolver + 'static,
+    Subscription: SelectionSetResolver + 'static,
+>(
+    container: &Container<Query, Mutation, Subscription>,
+    requests: Vec<Request>,
+    concurrency: usize,
+) -> Vec<Response> {
+    stream::iter(requests)
+        .map(|request| execute(container, request))
+        .buffered(concurrency)
+        .collect()
+        .await
+}
+
+pub fn execute_incremental<
+    Query: SelectionSetResolver + 'static,
+    Mutation: SelectionSetResolver + 'static,
+    Subscription: SelectionSetResolver + 'static,
+>(
+    container: &Container<Query, Mutation, Subscription>,
+    request: Request,
+) -> BoxStream<'static, IncrementalResponse> {
+    let container = container.clone();
+    let (tx, rx) = mpsc::unbounded();
+    let producer = async move {
+        let mut request = request;
+        for extension in &container.extensions {
+            request = match extension.prepare_request(request).await {
+                Ok(req) => req,
+                Err(error) => {
+                    let response = Response::from_errors(format_errors(&container, vec![error]));
+                    tx.unbounded_send(IncrementalResponse::initial(response, false))
+                        .ok();
+                    return;
+                }
+            };
+        }
+        execute_incremental_request(&container, request, &tx).await;
+    };
+
+    stream::select(rx, producer.into_stream().filter_map(|_| ready(None))).boxed()
+}
+
+async fn execute_incremental_request<
+    Query: SelectionSetResolver + 'static,
+    Mutation: SelectionSetResolver + 'static,
+    Subscription: SelectionSetResolver + 'static,
+>(
+    container: &Container<Query, Mutation, Subscription>,
+    mut request: Request,
+    tx: &mpsc::UnboundedSender<IncrementalResponse>,
+) {
+    let send = |response: IncrementalResponse| {
+        tx.unbounded_send(response).ok();
+    };
+
     let query_doc = match graphql_parser::parse_query::<String>(&request.query) {
         Ok(doc) => doc,
         Err(_) => {
             let err = GqlError::new("failed to parse query", None);
-            return Response::from_errors(vec![err]);
+            send(IncrementalResponse::initial(
+                Response::from_errors(vec![err]),
+                false,
+            ));
+            return;
         }
     };
-    let operation = build_operation(
+    let mut operation = match build_operation(
         &query_doc,
         request.operation_name.clone(),
         request.variables.clone(),
-    );
+    ) {
+        Ok(operation) => operation,
+        Err(error) => {
+            send(IncrementalResponse::initial(
+                Response::from_errors(vec![error]),
+                false,
+            ));
+            return;
+        }
+    };
+    *operation.uploads.lock().unwrap() = std::mem::take(&mut request.uploads)
+        .into_iter()
+        .map(Some)
+        .collect();
+
+    operation.set_introspection_mode(container.introspection_mode(&request));
+    if let Err(errors) = validate_operation(container, &query_doc, &request, &operation) {
+        send(IncrementalResponse::initial(
+            Response::from_errors(errors),
+            false,
+        ));
+        return;
+    }
+
+    let (payload_sender, mut payloads) = mpsc::unbounded();
+    let mut ctx = build_context(&container.schema, &operation, &container.data);
+    // Mutations and subscriptions are delivered without incremental payloads.
+    if matches!(operation.operation_type, OperationType::Query) {
+        ctx = ctx.with_defer_scope(DeferScope::new(payload_sender));
+    }
+    let (early_sender, early_value) = ctx.early_value_channel();
+    let ctx = ctx.with_early_value(early_sender);
 
-    let operation = match operation {
-        Ok(op) => op,
-        Err(error) => return Response::from_errors(vec![error]),
+    // The initial payload is sent as soon as everything but the deferred fragments is resolved.
+    let (result, mut rest) =
+        resolve_early(resolve_root(container, &ctx).boxed(), early_value).await;
+    let mut response = operation_response(&ctx, result);
+    for extension in &container.extensions {
+        response = extension.response(response).await;
+    }
+    response.errors = format_errors(container, std::mem::take(&mut response.errors));
+
+    let format_payloads = |mut payloads: Vec<IncrementalPayload>| {
+        for payload in &mut payloads {
+            payload.errors = format_errors(container, std::mem::take(&mut payload.errors));
+        }
+        payloads
     };
+    let mut ready_payloads = ready_payloads(&mut rest, &mut payloads);
+    send(IncrementalResponse::initial(
+        response,
+        rest.is_some() || !ready_payloads.is_empty(),
+    ));
+    while rest.is_some() || !ready_payloads.is_empty() {
+        if let (Some(future), true) = (&mut rest, ready_payloads.is_empty()) {
+            match select(future, payloads.next()).await {
+                Either::Right((Some(payload), _)) => ready_payloads.push(payload),
+                _ => rest = None,
+            }
+            ready_payloads.extend(self::ready_payloads(&mut rest, &mut payloads));
+        }
+        send(IncrementalResponse::subsequent(
+            format_payloads(std::mem::take(&mut ready_payloads)),
+            rest.is_some(),
+        ));
+    }
+}
 
-    if let Err(errors) = apply_validation(
-        &container.schema,
+// Polls the deferred fragments once and takes the payloads they have sent so far.
+fn ready_payloads(
+    rest: &mut Option<BoxFuture<'_, ()>>,
+    payloads: &mut mpsc::UnboundedReceiver<IncrementalPayload>,
+) -> Vec<IncrementalPayload> {
+    if rest
+        .as_mut()
+        .is_some_and(|future| future.as_mut().now_or_never().is_some())
+    {
+        *rest = None;
+    }
+    let mut ready = Vec::new();
+    while let Ok(payload) = payloads.try_recv() {
+        ready.push(payload);
+    }
+    ready
+}
+
+async fn execute_request<
+    Query: SelectionSetResolver + 'static,
+    Mutation: SelectionSetResolver + 'static,
+    Subscription: SelectionSetResolver + 'static,
+>(
+    container: &Container<Query, Mutation, Subscription>,
+    mut request: Request,
+) -> Result<Response, Response> {
+    let query_doc = match graphql_parser::parse_query::<String>(&request.query) {
+        Ok(doc) => doc,
+        Err(_) => {
+            let err = GqlError::new("failed to parse query", None);
+            return Err(Response::from_errors(vec![err]));
+        }
+    };
+    let mut operation = match build_operation(
         &query_doc,
+        request.operation_name.clone(),
+        request.variables.clone(),
+    ) {
+        Ok(operation) => operation,
+        Err(error) => return Err(Response::from_errors(vec![error])),
+    };
+    operation.set_introspection_mode(container.introspection_mode(&request));
+    *operation.uploads.lock().unwrap() = std::mem::take(&mut request.uploads)
+        .into_iter()
+        .map(Some)
+        .collect();
+
+    validate_operation(container, &query_doc, &request, &operation)
+        .map_err(Response::from_errors)?;
+    Ok(resolve_operation(container, &operation).await)
+}
+
+fn validate_operation<
+    'a,
+    Query: SelectionSetResolver + 'static,
+    Mutation: SelectionSetResolver + 'static,
+    Subscription: SelectionSetResolver + 'static,
+>(
+    container: &'a Container<Query, Mutation, Subscription>,
+    query_doc: &'a Document<'a, String>,
+    request: &'a Request,
+    operation: &'a Operation<'a>,
+) -> Result<(), Vec<GqlError>> {
+    apply_validation(
+        &container.schema,
+        query_doc,
         Some(&request.variables),
-        &operation,
+        operation,
         request.operation_name.as_deref(),
-    ) {
-        return Response::from_errors(errors);
+    )?;
+
+    if let Some(depth_limit) = container.depth_limit {
+        check_depth_limit(operation, depth_limit).map_err(|error| vec![error])?;
+    }
+    if let Some(complexity_limit) = container.complexity_limit {
+        check_complexity_limit(
+            &container.schema,
+            &container.registry,
+            operation,
+            complexity_limit,
+        )
+        .map_err(|error| vec![error])?;
     }
+    Ok(())
+}
 
-    let ctx = build_context(&container.schema, &operation);
+async fn resolve_operation<
+    'a,
+    Query: SelectionSetResolver + 'static,
+    Mutation: SelectionSetResolver + 'static,
+    Subscription: SelectionSetResolver + 'static,
+>(
+    container: &'a Container<Query, Mutation, Subscription>,
+    operation: &'a Operation<'a>,
+) -> Response {
+    let ctx = build_context(&container.schema, operation, &container.data);
+    let result = resolve_root(container, &ctx).await;
+    operation_response(&ctx, result)
+}
 
-    let result = match operation.operation_type {
-        OperationType::Query => {
-            resolve_selection_parallelly(&ctx, &container.query_resolvers).await
-        }
+async fn resolve_root<
+    Query: SelectionSetResolver + 'static,
+    Mutation: SelectionSetResolver + 'static,
+    Subscription: SelectionSetResolver + 'static,
+>(
+    container: &Container<Query, Mutation, Subscription>,
+    ctx: &SelectionSetContext<'_>,
+) -> ResolverResult<GqlValue> {
+    match ctx.operation.operation_type {
+        OperationType::Query => resolve_selection_parallelly(ctx, &container.query_resolvers).await,
         OperationType::Mutation => {
-            resolve_selection_serially(&ctx, &container.mutation_resolvers).await
-        }
-        OperationType::Subscription => {
-            let error = GqlError::new("Subscription cannot execute from this path", None);
-            return Response::from_errors(vec![error]);
+            resolve_selection_serially(ctx, &container.mutation_resolvers).await
         }
-    };
+        OperationType::Subscription => Err(GqlError::new(
+            "Subscription cannot execute from this path",
+            None,
+        )),
+    }
+}
 
+fn operation_response(ctx: &SelectionSetContext<'_>, result: ResolverResult<GqlValue>) -> Response {
+    let errors = ctx.operation.errors.lock().unwrap().clone();
     match result {
-        Ok(value) => {
-            if !ctx.operation.errors.lock().unwrap().is_empty() {
-                Response::from_data_and_errors(value, ctx.operation.errors.lock().unwrap().clone())
-            } else {
-                Response::new(value)
-            }
-        }
+        Ok(value) if errors.is_empty() => Response::new(value),
+        Ok(value) => Response::from_data_and_errors(value, errors),
         Err(error) => {
-            let mut errors = vec![error];
-            errors.extend(ctx.operation.errors.lock().unwrap().clone());
-            Response::from_errors(errors)
+            let mut all_errors = vec![error];
+            all_errors.extend(errors);
+            Response::from_errors(all_errors)
         }
     }
 }
+
+fn format_errors<
+    Query: SelectionSetResolver + 'static,
+    Mutation: SelectionSetResolver + 'static,
+    Subscription: SelectionSetResolver + 'static,
+>(
+    container: &Container<Query, Mutation, Subscription>,
+    errors: Vec<GqlError>,
+) -> Vec<GqlError> {
+    errors
+        .into_iter()
+        .map(|error| container.error_formatter.format_error(error))
+        .collect()
+}
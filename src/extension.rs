@@ -0,0 +1,12 @@
+use crate::{Request, ResolverResult, Response};
+
+#[async_trait::async_trait]
+pub trait Extension: Send + Sync {
+    async fn prepare_request(&self, request: Request) -> ResolverResult<Request> {
+        Ok(request)
+    }
+
+    async fn response(&self, response: Response) -> Response {
+        response
+    }
+}
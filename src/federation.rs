@@ -0,0 +1,236 @@
+use std::collections::{BTreeMap, HashMap};
+
+use graphql_parser::{
+    schema::{Definition, TypeDefinition, TypeExtension},
+    Pos,
+};
+
+use crate::*;
+
+const FEDERATION_SDL: &str = r#"
+scalar _Any
+scalar FieldSet
+scalar link__Import
+
+enum link__Purpose {
+  SECURITY
+  EXECUTION
+}
+
+type _Service {
+  sdl: String!
+}
+
+directive @key(fields: FieldSet!, resolvable: Boolean = true) repeatable on OBJECT
+directive @requires(fields: FieldSet!) on FIELD_DEFINITION
+directive @provides(fields: FieldSet!) on FIELD_DEFINITION
+directive @external on OBJECT | FIELD_DEFINITION
+directive @shareable on OBJECT | FIELD_DEFINITION
+directive @link(url: String!, as: String, for: link__Purpose, import: [link__Import]) on SCHEMA
+"#;
+
+#[async_trait::async_trait]
+pub trait EntityResolver: Send + Sync {
+    async fn resolve_entity(
+        &self,
+        ctx: &SelectionSetContext<'_>,
+        representation: &BTreeMap<String, GqlValue>,
+    ) -> ResolverResult<GqlValue>;
+}
+
+pub(crate) struct Federation {
+    pub(crate) sdl: String,
+    pub(crate) entity_resolvers: HashMap<String, Box<dyn EntityResolver>>,
+    /// `_service` and `_entities` added to the query type, the schema may define them itself.
+    pub(crate) root_fields: Vec<&'static str>,
+}
+
+impl Federation {
+    pub(crate) async fn resolve_service(&self, ctx: &Context<'_>) -> ResolverResult<GqlValue> {
+        let service = _Service { sdl: &self.sdl };
+        let ctx_selection_set = ctx.with_selection_set(&ctx.item.selection_set);
+        service.resolve_selection_set(&ctx_selection_set).await
+    }
+
+    pub(crate) async fn resolve_entities(&self, ctx: &Context<'_>) -> ResolverResult<GqlValue> {
+        let representations =
+            ctx.get_arg_value::<Vec<BTreeMap<String, GqlValue>>>("representations")?;
+        let ctx_selection_set = ctx.with_selection_set(&ctx.item.selection_set);
+
+        // A representation that cannot be resolved is null, and does not fail the others.
+        let mut entities = Vec::new();
+        for representation in &representations {
+            match self
+                .resolve_entity(&ctx_selection_set, representation, ctx.item.position)
+                .await
+            {
+                Ok(entity) => entities.push(entity),
+                Err(error) => {
+                    ctx.add_error(&error);
+                    entities.push(GqlValue::Null);
+                }
+            }
+        }
+
+        Ok(GqlValue::List(entities))
+    }
+
+    async fn resolve_entity(
+        &self,
+        ctx: &SelectionSetContext<'_>,
+        representation: &BTreeMap<String, GqlValue>,
+        pos: Pos,
+    ) -> ResolverResult<GqlValue> {
+        let type_name = match representation.get("__typename") {
+            Some(GqlValue::String(type_name)) => type_name,
+            _ => {
+                return Err(
+                    GqlError::new("Entity representation must have __typename", Some(pos))
+                        .with_error_type(GqlErrorType::BadRequest),
+                )
+            }
+        };
+        let resolver = self.entity_resolvers.get(type_name).ok_or_else(|| {
+            GqlError::new(
+                format!("Entity resolver for {} is not registered", type_name),
+                Some(pos),
+            )
+            .with_error_type(GqlErrorType::BadRequest)
+        })?;
+        resolver.resolve_entity(ctx, representation).await
+    }
+}
+
+#[allow(non_camel_case_types)]
+struct _Service<'a> {
+    sdl: &'a str,
+}
+
+#[GqlType(internal)]
+impl<'a> _Service<'a> {
+    async fn sdl(&self) -> String {
+        self.sdl.to_string()
+    }
+}
+
+/// Returns the federation types, and the root fields added to the query type.
+pub(crate) fn federation_sdl(
+    schema_documents: &[&str],
+) -> Result<(String, Vec<&'static str>), GqlError> {
+    let mut query_type_name = "Query".to_string();
+    let mut entity_names = Vec::new();
+    let mut object_fields = HashMap::new();
+
+    for doc in schema_documents {
+        let parsed_schema = graphql_parser::parse_schema::<String>(doc)
+            .map_err(|err| GqlError::new(err.to_string(), None))?;
+        for node in parsed_schema.definitions {
+            match node {
+                Definition::SchemaDefinition(schema_def) => {
+                    if let Some(query) = schema_def.query {
+                        query_type_name = query;
+                    }
+                }
+                Definition::TypeDefinition(TypeDefinition::Object(obj)) => {
+                    if obj.directives.iter().any(|dir| dir.name == "key") {
+                        entity_names.push(obj.name.clone());
+                    }
+                    object_fields
+                        .entry(obj.name)
+                        .or_insert_with(Vec::new)
+                        .extend(obj.fields.into_iter().map(|f| f.name));
+                }
+                Definition::TypeExtension(TypeExtension::Object(obj_ext)) => {
+                    if obj_ext.directives.iter().any(|dir| dir.name == "key") {
+                        entity_names.push(obj_ext.name.clone());
+                    }
+                    object_fields
+                        .entry(obj_ext.name)
+                        .or_insert_with(Vec::new)
+                        .extend(obj_ext.fields.into_iter().map(|f| f.name));
+                }
+                _ => {}
+            }
+        }
+    }
+
+    entity_names.sort();
+    entity_names.dedup();
+    let query_fields = object_fields.remove(&query_type_name).unwrap_or_default();
+
+    let mut sdl = FEDERATION_SDL.to_string();
+    let mut root_fields = Vec::new();
+    let mut root_field_defs = Vec::new();
+    if !query_fields.iter().any(|name| name == "_service") {
+        root_fields.push("_service");
+        root_field_defs.push("  _service: _Service!");
+    }
+    if !entity_names.is_empty() {
+        sdl += &format!("union _Entity = {}\n", entity_names.join(" | "));
+        if !query_fields.iter().any(|name| name == "_entities") {
+            root_fields.push("_entities");
+            root_field_defs.push("  _entities(representations: [_Any!]!): [_Entity]!");
+        }
+    }
+    if !root_field_defs.is_empty() {
+        sdl += &format!(
+            "extend type {} {{\n{}\n}}\n",
+            query_type_name,
+            root_field_defs.join("\n")
+        );
+    }
+
+    Ok((sdl, root_fields))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::federation_sdl;
+
+    #[test]
+    fn test_federation_sdl() {
+        let schema_doc = r#"
+            schema { query: Root }
+            type Root { me: User }
+            type User @key(fields: "id") { id: ID! name: String }
+            type Review @key(fields: "id") { id: ID! body: String }
+        "#;
+        let (sdl, root_fields) = federation_sdl(&[schema_doc]).unwrap();
+        assert!(sdl.contains("union _Entity = Review | User"));
+        assert!(sdl.contains("extend type Root {"));
+        assert!(sdl.contains("_entities(representations: [_Any!]!): [_Entity]!"));
+        assert_eq!(root_fields, vec!["_service", "_entities"]);
+    }
+
+    #[test]
+    fn test_federation_sdl_without_entities() {
+        let schema_doc = r#"
+            type Query { value: Int }
+        "#;
+        let (sdl, root_fields) = federation_sdl(&[schema_doc]).unwrap();
+        assert!(!sdl.contains("_Entity"));
+        assert!(sdl.contains("_service: _Service!"));
+        assert_eq!(root_fields, vec!["_service"]);
+    }
+
+    #[test]
+    fn test_federation_sdl_with_defined_fields() {
+        let schema_doc = r#"
+            type Query { _service: _Service! }
+            type User @key(fields: "id") @key(fields: "name") { id: ID! name: String! }
+        "#;
+        let (sdl, root_fields) = federation_sdl(&[schema_doc]).unwrap();
+        assert!(sdl.contains("union _Entity = User"));
+        assert!(sdl.contains("extend type Query {\n  _entities("));
+        assert_eq!(root_fields, vec!["_entities"]);
+    }
+
+    #[test]
+    fn test_federation_sdl_with_schema_extension() {
+        let schema_doc = r#"
+            extend schema @link(url: "https://specs.apollo.dev/federation/v2.0", import: ["@key"])
+            type Query { value: Int }
+        "#;
+        assert!(federation_sdl(&[schema_doc]).is_err());
+    }
+}
@@ -0,0 +1,78 @@
+use std::collections::BTreeMap;
+
+use crate::{Context, CustomDirective, Error, GqlValue, ResolveFut, ResolverResult};
+
+/// Checks whether a field can be resolved, before its resolver runs.
+#[async_trait::async_trait]
+pub trait Guard: Send + Sync {
+    async fn check(&self, ctx: &Context<'_>) -> Result<(), Error>;
+
+    /// Both guards must pass.
+    fn and<G: Guard>(self, other: G) -> GuardAnd<Self, G>
+    where
+        Self: Sized,
+    {
+        GuardAnd(self, other)
+    }
+
+    /// Either guard must pass, the error of `other` is returned if both fail.
+    fn or<G: Guard>(self, other: G) -> GuardOr<Self, G>
+    where
+        Self: Sized,
+    {
+        GuardOr(self, other)
+    }
+}
+
+#[async_trait::async_trait]
+impl Guard for Box<dyn Guard> {
+    async fn check(&self, ctx: &Context<'_>) -> Result<(), Error> {
+        self.as_ref().check(ctx).await
+    }
+}
+
+pub struct GuardAnd<A, B>(A, B);
+
+#[async_trait::async_trait]
+impl<A: Guard, B: Guard> Guard for GuardAnd<A, B> {
+    async fn check(&self, ctx: &Context<'_>) -> Result<(), Error> {
+        self.0.check(ctx).await?;
+        self.1.check(ctx).await
+    }
+}
+
+pub struct GuardOr<A, B>(A, B);
+
+#[async_trait::async_trait]
+impl<A: Guard, B: Guard> Guard for GuardOr<A, B> {
+    async fn check(&self, ctx: &Context<'_>) -> Result<(), Error> {
+        if self.0.check(ctx).await.is_ok() {
+            return Ok(());
+        }
+        self.1.check(ctx).await
+    }
+}
+
+// A custom directive which builds a guard from the directive arguments, such as `@auth(requires: ADMIN)`.
+pub(crate) struct GuardDirective<F>(pub F);
+
+#[async_trait::async_trait]
+impl<F, G> CustomDirective for GuardDirective<F>
+where
+    F: Fn(&BTreeMap<String, GqlValue>) -> G + Send + Sync,
+    G: Guard,
+{
+    async fn resolve_field(
+        &self,
+        ctx: &Context<'_>,
+        directive_args: &BTreeMap<String, GqlValue>,
+        resolve_fut: ResolveFut<'_>,
+    ) -> ResolverResult<Option<GqlValue>> {
+        let guard = (self.0)(directive_args);
+        guard
+            .check(ctx)
+            .await
+            .map_err(|err| crate::__private::guard_error(err, ctx.item.position))?;
+        resolve_fut.await
+    }
+}
@@ -0,0 +1,411 @@
+use std::sync::{Arc, Mutex};
+
+use futures_channel::{mpsc, oneshot};
+use futures_util::{
+    future::{join_all, select, BoxFuture, Either},
+    FutureExt,
+};
+use graphql_parser::query::{Directive, Value};
+use serde::Serialize;
+
+use crate::{
+    context::{ExecutionContext, SelectionSetContext},
+    operation::Operation,
+    CollectFields, Fields, GqlError, GqlErrorType, GqlValue, ResolverResult, Response,
+    SelectionSetResolver,
+};
+
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct IncrementalResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub data: Option<GqlValue>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub errors: Vec<GqlError>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub incremental: Vec<IncrementalPayload>,
+    pub has_next: bool,
+}
+
+impl IncrementalResponse {
+    pub fn initial(response: Response, has_next: bool) -> Self {
+        IncrementalResponse {
+            data: Some(response.data),
+            errors: response.errors,
+            incremental: Vec::new(),
+            has_next,
+        }
+    }
+
+    pub fn subsequent(incremental: Vec<IncrementalPayload>, has_next: bool) -> Self {
+        IncrementalResponse {
+            data: None,
+            errors: Vec::new(),
+            incremental,
+            has_next,
+        }
+    }
+}
+
+/// A deferred fragment with `data`, or list items of `@stream` with `items`.
+#[derive(Serialize, Debug)]
+pub struct IncrementalPayload {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub data: Option<GqlValue>,
+    /// The streamed items as a list, or null if resolving them failed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub items: Option<GqlValue>,
+    pub path: Vec<GqlValue>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub errors: Vec<GqlError>,
+}
+
+// The receiver of an early value sends `()` back once the value is delivered, and only then
+// the deferred fragments and streamed items under the value are resolved and sent.
+type Delivered = oneshot::Sender<()>;
+type EarlySender = oneshot::Sender<(GqlValue, Delivered)>;
+pub(crate) type EarlyValue<'a, T> = BoxFuture<'a, Result<(T, Delivered), oneshot::Canceled>>;
+
+#[derive(Clone)]
+pub(crate) struct StreamOptions {
+    pub(crate) initial_count: usize,
+    label: Option<String>,
+}
+
+/// Where the payloads of deferred fragments go while a query is resolved incrementally.
+#[derive(Clone)]
+pub(crate) struct DeferScope {
+    path: Vec<GqlValue>,
+    // Receives the value of the current field once everything but its deferred fragments is
+    // resolved, so that the value can be delivered before the fragments.
+    early_value: Option<Arc<Mutex<Option<EarlySender>>>>,
+    // Errors of the deferred fragment being resolved, or `None` for the initial payload.
+    errors: Option<Arc<Mutex<Vec<GqlError>>>>,
+    // `@stream` of the current field, which applies to the list resolved for the field.
+    stream: Option<StreamOptions>,
+    payloads: mpsc::UnboundedSender<IncrementalPayload>,
+}
+
+impl DeferScope {
+    pub(crate) fn new(payloads: mpsc::UnboundedSender<IncrementalPayload>) -> Self {
+        DeferScope {
+            path: Vec::new(),
+            early_value: None,
+            errors: None,
+            stream: None,
+            payloads,
+        }
+    }
+
+    pub(crate) fn with_field(&self, name: &str) -> Self {
+        self.with_path_segment(GqlValue::String(name.to_string()))
+    }
+
+    fn with_path_segment(&self, segment: GqlValue) -> Self {
+        let mut path = self.path.clone();
+        path.push(segment);
+        DeferScope {
+            path,
+            early_value: None,
+            errors: self.errors.clone(),
+            stream: None,
+            payloads: self.payloads.clone(),
+        }
+    }
+
+    pub(crate) fn errors(&self) -> Option<&Arc<Mutex<Vec<GqlError>>>> {
+        self.errors.as_ref()
+    }
+
+    fn send_items(&self, index: usize, items: GqlValue, errors: Vec<GqlError>) {
+        let mut path = self.path.clone();
+        path.push(GqlValue::from(index));
+        let payload = IncrementalPayload {
+            data: None,
+            items: Some(items),
+            path,
+            label: self.stream.as_ref().and_then(|stream| stream.label.clone()),
+            errors,
+        };
+        self.payloads.unbounded_send(payload).ok();
+    }
+}
+
+impl<'a, T: Clone> ExecutionContext<'a, T> {
+    pub(crate) fn with_defer_scope(self, defer_scope: DeferScope) -> Self {
+        ExecutionContext {
+            defer_scope: Some(defer_scope),
+            ..self
+        }
+    }
+
+    pub(crate) fn with_list_index(&self, index: usize) -> Self {
+        ExecutionContext {
+            defer_scope: self
+                .defer_scope
+                .as_ref()
+                .map(|scope| scope.with_path_segment(GqlValue::from(index))),
+            ..self.clone()
+        }
+    }
+
+    /// Channel for the value of a field or a list item that has deferred fragments under it.
+    pub(crate) fn early_value_channel(
+        &self,
+    ) -> (Option<EarlySender>, Option<EarlyValue<'static, GqlValue>>) {
+        match self.defer_scope {
+            Some(_) => {
+                let (sender, receiver) = oneshot::channel();
+                (Some(sender), Some(receiver.boxed()))
+            }
+            None => (None, None),
+        }
+    }
+
+    pub(crate) fn with_early_value(self, sender: Option<EarlySender>) -> Self {
+        let defer_scope = self.defer_scope.map(|scope| DeferScope {
+            early_value: sender.map(|sender| Arc::new(Mutex::new(Some(sender)))),
+            ..scope
+        });
+        ExecutionContext {
+            defer_scope,
+            ..self
+        }
+    }
+
+    pub(crate) fn with_stream(self, stream: Option<StreamOptions>) -> Self {
+        let defer_scope = self.defer_scope.map(|scope| DeferScope { stream, ..scope });
+        ExecutionContext {
+            defer_scope,
+            ..self
+        }
+    }
+
+    pub(crate) fn stream_options(&self) -> Option<&StreamOptions> {
+        self.defer_scope.as_ref()?.stream.as_ref()
+    }
+
+    // Sends `value` ahead and waits until it is delivered. Returns `false` if the value cannot be
+    // sent ahead, e.g. when a custom directive of the field needs the whole value.
+    pub(crate) async fn send_early_value(&self, value: GqlValue) -> bool {
+        let sender = self
+            .defer_scope
+            .as_ref()
+            .and_then(|scope| scope.early_value.as_ref())
+            .and_then(|sender| sender.lock().unwrap().take());
+        let sender = match sender {
+            Some(sender) => sender,
+            None => return false,
+        };
+        let (delivered, is_delivered) = oneshot::channel();
+        sender.send((value, delivered)).is_ok() && is_delivered.await.is_ok()
+    }
+
+    /// Sends `value` ahead and resolves the deferred fragments under it before returning.
+    pub(crate) async fn resolve_deferred(
+        &self,
+        value: GqlValue,
+        deferred: Vec<BoxFuture<'_, ()>>,
+    ) -> GqlValue {
+        if deferred.is_empty() {
+            return value;
+        }
+        self.send_early_value(value.clone()).await;
+        join_all(deferred).await;
+        value
+    }
+}
+
+impl<'a> SelectionSetContext<'a> {
+    /// Resolves the items of a list after its initial items are sent ahead, and sends each of
+    /// them as a payload.
+    pub(crate) async fn stream_items<'v, T: SelectionSetResolver + 'v>(
+        &self,
+        mut items: Vec<GqlValue>,
+        rest: Vec<(usize, &'v T)>,
+    ) -> GqlValue {
+        let scope = match &self.defer_scope {
+            Some(scope) => scope.clone(),
+            None => return GqlValue::List(items),
+        };
+        for (index, value) in rest {
+            let errors = Arc::new(Mutex::new(Vec::new()));
+            let mut ctx_item = self.with_list_index(index);
+            if let Some(scope) = &mut ctx_item.defer_scope {
+                scope.errors = Some(errors.clone());
+            }
+            let (sender, early_value) = ctx_item.early_value_channel();
+            let ctx_item = ctx_item.with_early_value(sender);
+            let future = async move { value.resolve_selection_set(&ctx_item).await };
+            let (result, rest) = resolve_early(future.boxed(), early_value).await;
+
+            let mut errors = std::mem::take(&mut *errors.lock().unwrap());
+            let item = match result {
+                Ok(item) => {
+                    items.push(item.clone());
+                    GqlValue::List(vec![item])
+                }
+                Err(error) => {
+                    errors.insert(0, error);
+                    items.push(GqlValue::Null);
+                    GqlValue::Null
+                }
+            };
+            scope.send_items(index, item, errors);
+            if let Some(rest) = rest {
+                rest.await;
+            }
+        }
+        GqlValue::List(items)
+    }
+}
+
+/// Waits for `future` or for the value it sends ahead, and returns the rest of `future` if it
+/// is still resolving deferred fragments.
+pub(crate) async fn resolve_early<'a, T: Send + 'a>(
+    future: BoxFuture<'a, ResolverResult<T>>,
+    early_value: Option<EarlyValue<'a, T>>,
+) -> (ResolverResult<T>, Option<BoxFuture<'a, ()>>) {
+    let early_value = match early_value {
+        Some(early_value) => early_value,
+        None => return (future.await, None),
+    };
+    match select(future, early_value).await {
+        Either::Left((result, _)) => (result, None),
+        Either::Right((Ok((value, delivered)), future)) => {
+            let rest = async move {
+                // The caller polls the rest after it has delivered the value.
+                delivered.send(()).ok();
+                future.await.ok();
+            };
+            (Ok(value), Some(rest.boxed()))
+        }
+        Either::Right((Err(_), future)) => (future.await, None),
+    }
+}
+
+/// Resolves a deferred fragment on the object it is spread in, and sends it as a payload.
+pub(crate) fn defer_fragment<'a, 'ctx: 'a, T: CollectFields + ?Sized>(
+    ctx: SelectionSetContext<'ctx>,
+    root_type: &'a T,
+    directives: &[Directive<'ctx, String>],
+) -> BoxFuture<'a, ()> {
+    let label = active_directive(ctx.operation, directives, "defer")
+        .and_then(|directive| directive_label(ctx.operation, directive));
+    let errors = Arc::new(Mutex::new(Vec::new()));
+    let (sender, early_value) = ctx.early_value_channel();
+    let mut ctx = ctx.with_early_value(sender);
+    if let Some(scope) = &mut ctx.defer_scope {
+        scope.errors = Some(errors.clone());
+    }
+
+    Box::pin(async move {
+        let scope = match &ctx.defer_scope {
+            Some(scope) => scope.clone(),
+            None => return,
+        };
+        let future = async {
+            let mut fields = Fields::default();
+            root_type.collect_all_fields(&ctx, &mut fields)?;
+            fields.resolve(&ctx, true).await
+        };
+        let (result, rest) = resolve_early(future.boxed(), early_value).await;
+
+        let mut errors = std::mem::take(&mut *errors.lock().unwrap());
+        let data = match result {
+            Ok(data) => data,
+            Err(error) => {
+                errors.insert(0, error);
+                GqlValue::Null
+            }
+        };
+        let payload = IncrementalPayload {
+            data: Some(data),
+            items: None,
+            path: scope.path.clone(),
+            label,
+            errors,
+        };
+        scope.payloads.unbounded_send(payload).ok();
+        if let Some(rest) = rest {
+            rest.await;
+        }
+    })
+}
+
+pub(crate) fn stream_options<T>(
+    ctx: &ExecutionContext<'_, T>,
+    directives: &[Directive<'_, String>],
+) -> ResolverResult<Option<StreamOptions>> {
+    if ctx.defer_scope.is_none() {
+        return Ok(None);
+    }
+    let directive = match active_directive(ctx.operation, directives, "stream") {
+        Some(directive) => directive,
+        None => return Ok(None),
+    };
+    let initial_count = match directive_argument(ctx.operation, directive, "initialCount") {
+        Some(GqlValue::Number(count)) => count.as_u64().map(|count| count as usize),
+        Some(GqlValue::Null) | None => Some(0),
+        _ => None,
+    };
+    match initial_count {
+        Some(initial_count) => Ok(Some(StreamOptions {
+            initial_count,
+            label: directive_label(ctx.operation, directive),
+        })),
+        None => Err(GqlError::new(
+            "initialCount of @stream must be a non-negative integer",
+            Some(directive.position),
+        )
+        .with_error_type(GqlErrorType::BadRequest)),
+    }
+}
+
+pub(crate) fn is_deferred<T>(
+    ctx: &ExecutionContext<'_, T>,
+    directives: &[Directive<'_, String>],
+) -> bool {
+    ctx.defer_scope.is_some() && active_directive(ctx.operation, directives, "defer").is_some()
+}
+
+fn active_directive<'d, 'q>(
+    operation: &Operation<'_>,
+    directives: &'d [Directive<'q, String>],
+    name: &str,
+) -> Option<&'d Directive<'q, String>> {
+    directives.iter().find(|directive| {
+        directive.name == name
+            && !matches!(
+                directive_argument(operation, directive, "if"),
+                Some(GqlValue::Boolean(false))
+            )
+    })
+}
+
+fn directive_label(operation: &Operation<'_>, directive: &Directive<'_, String>) -> Option<String> {
+    match directive_argument(operation, directive, "label") {
+        Some(GqlValue::String(label)) => Some(label),
+        _ => None,
+    }
+}
+
+fn directive_argument(
+    operation: &Operation<'_>,
+    directive: &Directive<'_, String>,
+    name: &str,
+) -> Option<GqlValue> {
+    directive
+        .arguments
+        .iter()
+        .find(|(arg_name, _)| arg_name == name)
+        .map(|(_, value)| match value {
+            Value::Variable(var_name) => operation
+                .variable_value(var_name)
+                .map(|(value, _)| value)
+                .unwrap_or(GqlValue::Null),
+            value => GqlValue::from(value.clone()),
+        })
+}
@@ -9,7 +9,7 @@ impl GqlInputType for bool {
                 GqlValue::Boolean(v) => Ok(v),
                 invalid_value => Err(format!(
                     "Expected type: boolean, but found: {}",
-                    invalid_value.to_string()
+                    invalid_value
                 )),
             },
             None => Err("Expected type: boolean, but not found".to_string()),
@@ -8,10 +8,7 @@ impl GqlInputType for ID {
             Some(value) => match value {
                 GqlValue::String(v) => Ok(ID(v)),
                 GqlValue::Number(v) => Ok(ID(v.to_string())),
-                invalid_value => Err(format!(
-                    "{}: invalid gql value for id",
-                    invalid_value.to_string()
-                )),
+                invalid_value => Err(format!("{}: invalid gql value for id", invalid_value)),
             },
             None => Err("Expected type: id, but not found".to_string()),
         }
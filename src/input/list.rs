@@ -23,10 +23,7 @@ impl<T: GqlInputType, const N: usize> GqlInputType for [T; N] {
                     }
                     Ok(vec_to_array(result))
                 }
-                invalid_value => Err(format!(
-                    "Expected type: list, but found {}",
-                    invalid_value.to_string()
-                )),
+                invalid_value => Err(format!("Expected type: list, but found {}", invalid_value)),
             },
             None => Err("Expected type: list, but not found".to_string()),
         }
@@ -141,3 +138,24 @@ impl<T: GqlInputType> GqlInputType for VecDeque<T> {
         GqlValue::List(values)
     }
 }
+
+impl<T: GqlInputType> GqlInputType for Vec<T> {
+    fn from_gql_value(value: Option<GqlValue>) -> Result<Self, String> {
+        match value.unwrap_or_default() {
+            GqlValue::List(list) => {
+                let mut result = Vec::new();
+                for v in list {
+                    let value = T::from_gql_value(Some(v))?;
+                    result.push(value)
+                }
+                Ok(result)
+            }
+            value => Ok(vec![T::from_gql_value(Some(value))?]),
+        }
+    }
+
+    fn to_gql_value(&self) -> GqlValue {
+        let values = self.iter().map(|v| v.to_gql_value()).collect();
+        GqlValue::List(values)
+    }
+}
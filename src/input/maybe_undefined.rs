@@ -0,0 +1,62 @@
+use crate::GqlValue;
+
+use super::GqlInputType;
+
+/// An optional input value which tells an absent value from an explicit `null`.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum MaybeUndefined<T> {
+    #[default]
+    Undefined,
+    Null,
+    Value(T),
+}
+
+impl<T> MaybeUndefined<T> {
+    pub fn is_undefined(&self) -> bool {
+        matches!(self, MaybeUndefined::Undefined)
+    }
+
+    pub fn is_null(&self) -> bool {
+        matches!(self, MaybeUndefined::Null)
+    }
+
+    pub fn value(&self) -> Option<&T> {
+        match self {
+            MaybeUndefined::Value(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// `None` if undefined, `Some(None)` if null.
+    pub fn as_opt_ref(&self) -> Option<Option<&T>> {
+        match self {
+            MaybeUndefined::Undefined => None,
+            MaybeUndefined::Null => Some(None),
+            MaybeUndefined::Value(value) => Some(Some(value)),
+        }
+    }
+
+    pub fn into_option(self) -> Option<T> {
+        match self {
+            MaybeUndefined::Value(value) => Some(value),
+            _ => None,
+        }
+    }
+}
+
+impl<T: GqlInputType> GqlInputType for MaybeUndefined<T> {
+    fn from_gql_value(value: Option<GqlValue>) -> Result<Self, String> {
+        match value {
+            None => Ok(MaybeUndefined::Undefined),
+            Some(GqlValue::Null) => Ok(MaybeUndefined::Null),
+            Some(value) => Ok(MaybeUndefined::Value(T::from_gql_value(Some(value))?)),
+        }
+    }
+
+    fn to_gql_value(&self) -> GqlValue {
+        match self {
+            MaybeUndefined::Value(value) => value.to_gql_value(),
+            _ => GqlValue::Null,
+        }
+    }
+}
@@ -1,10 +1,16 @@
 mod boolean;
 mod id;
 mod list;
+mod maybe_undefined;
 mod number;
 mod object;
 mod optional;
 mod string;
+mod upload;
+mod value;
+
+pub use maybe_undefined::MaybeUndefined;
+pub use upload::{Upload, UploadValue};
 
 use std::sync::Arc;
 
@@ -14,7 +14,7 @@ impl GqlInputType for i8 {
                 }
                 invalid_value => Err(format!(
                     "Expected type: number, but found {}",
-                    invalid_value.to_string()
+                    invalid_value
                 )),
             },
             None => Err("Expected type: number, but not found".to_string()),
@@ -36,7 +36,7 @@ impl GqlInputType for i16 {
                 }
                 invalid_value => Err(format!(
                     "Expected type: number, but found {}",
-                    invalid_value.to_string()
+                    invalid_value
                 )),
             },
             None => Err("Expected type: number, but not found".to_string()),
@@ -58,7 +58,7 @@ impl GqlInputType for i32 {
                 }
                 invalid_value => Err(format!(
                     "Expected type: number, but found {}",
-                    invalid_value.to_string()
+                    invalid_value
                 )),
             },
             None => Err("Expected type: number, but not found".to_string()),
@@ -80,7 +80,7 @@ impl GqlInputType for i64 {
                 }
                 invalid_value => Err(format!(
                     "Expected type: number, but found {}",
-                    invalid_value.to_string()
+                    invalid_value
                 )),
             },
             None => Err("Expected type: number, but not found".to_string()),
@@ -102,7 +102,7 @@ impl GqlInputType for u8 {
                 }
                 invalid_value => Err(format!(
                     "Expected type: number, but found {}",
-                    invalid_value.to_string()
+                    invalid_value
                 )),
             },
             None => Err("Expected type: number, but not found".to_string()),
@@ -124,7 +124,7 @@ impl GqlInputType for u16 {
                 }
                 invalid_value => Err(format!(
                     "Expected type: number, but found {}",
-                    invalid_value.to_string()
+                    invalid_value
                 )),
             },
             None => Err("Expected type: number, but not found".to_string()),
@@ -146,7 +146,7 @@ impl GqlInputType for u32 {
                 }
                 invalid_value => Err(format!(
                     "Expected type: number, but found {}",
-                    invalid_value.to_string()
+                    invalid_value
                 )),
             },
             None => Err("Expected type: number, but not found".to_string()),
@@ -168,7 +168,7 @@ impl GqlInputType for u64 {
                 }
                 invalid_value => Err(format!(
                     "Expected type: number, but found {}",
-                    invalid_value.to_string()
+                    invalid_value
                 )),
             },
             None => Err("Expected type: number, but not found".to_string()),
@@ -190,7 +190,7 @@ impl GqlInputType for usize {
                 }
                 invalid_value => Err(format!(
                     "Expected type: number, but found {}",
-                    invalid_value.to_string()
+                    invalid_value
                 )),
             },
             None => Err("Expected type: number, but not found".to_string()),
@@ -212,7 +212,7 @@ impl GqlInputType for isize {
                 }
                 invalid_value => Err(format!(
                     "Expected type: number, but found {}",
-                    invalid_value.to_string()
+                    invalid_value
                 )),
             },
             None => Err("Expected type: number, but not found".to_string()),
@@ -234,7 +234,7 @@ impl GqlInputType for f32 {
                 }
                 invalid_value => Err(format!(
                     "Expected type: number, but found {}",
-                    invalid_value.to_string()
+                    invalid_value
                 )),
             },
             None => Err("Expected type: number, but not found".to_string()),
@@ -259,7 +259,7 @@ impl GqlInputType for f64 {
                 }
                 invalid_value => Err(format!(
                     "Expected type: number, but found {}",
-                    invalid_value.to_string()
+                    invalid_value
                 )),
             },
             None => Err("Expected type: number, but not found".to_string()),
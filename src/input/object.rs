@@ -17,7 +17,7 @@ impl<T: GqlInputType> GqlInputType for BTreeMap<String, T> {
                 }
                 invalid_value => Err(format!(
                     "Expected type: object, but found: {}",
-                    invalid_value.to_string()
+                    invalid_value
                 )),
             },
             None => Err("Expected type: object, but not found".to_string()),
@@ -46,7 +46,7 @@ impl<T: GqlInputType> GqlInputType for HashMap<String, T> {
                 }
                 invalid_value => Err(format!(
                     "Expected type: object, but found: {}",
-                    invalid_value.to_string()
+                    invalid_value
                 )),
             },
             None => Err("Expected type: object, but not found".to_string()),
@@ -7,10 +7,7 @@ impl GqlInputType for String {
         match value {
             Some(value) => match value {
                 GqlValue::String(v) => Ok(v),
-                invalid_value => Err(format!(
-                    "{}: invalid gql value for string",
-                    invalid_value.to_string()
-                )),
+                invalid_value => Err(format!("{}: invalid gql value for string", invalid_value)),
             },
             None => Err("Expected type: boolean, but not found".to_string()),
         }
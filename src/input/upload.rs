@@ -0,0 +1,72 @@
+use std::fs::File;
+
+use futures_util::{stream, Stream};
+use tokio::io::AsyncReadExt;
+
+use crate::{Context, GqlError, GqlErrorType, GqlValue, ResolverResult};
+
+use super::GqlInputType;
+
+#[derive(Debug)]
+pub struct UploadValue {
+    pub filename: String,
+    pub content_type: Option<String>,
+    pub content: File,
+}
+
+impl UploadValue {
+    pub fn into_async_read(self) -> tokio::fs::File {
+        tokio::fs::File::from_std(self.content)
+    }
+
+    pub fn into_stream(self) -> impl Stream<Item = std::io::Result<Vec<u8>>> {
+        stream::unfold(Some(self.into_async_read()), |file| async move {
+            let mut file = file?;
+            let mut buf = vec![0; 8192];
+            match file.read(&mut buf).await {
+                Ok(0) => None,
+                Ok(n) => {
+                    buf.truncate(n);
+                    Some((Ok(buf), Some(file)))
+                }
+                Err(err) => Some((Err(err), None)),
+            }
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Upload(pub usize);
+
+impl Upload {
+    pub fn value(&self, ctx: &Context<'_>) -> ResolverResult<UploadValue> {
+        ctx.operation
+            .uploads
+            .lock()
+            .unwrap()
+            .get_mut(self.0)
+            .and_then(Option::take)
+            .ok_or_else(|| {
+                GqlError::new(
+                    format!("Upload {} is not found or already taken", self.0),
+                    Some(ctx.item.position),
+                )
+                .with_error_type(GqlErrorType::BadRequest)
+            })
+    }
+}
+
+impl GqlInputType for Upload {
+    fn from_gql_value(value: Option<GqlValue>) -> Result<Self, String> {
+        // Only a file of a multipart request is an upload, not a number in JSON.
+        match value {
+            Some(GqlValue::Upload(index)) => Ok(Upload(index)),
+            Some(invalid_value) => Err(format!("{}: invalid gql value for upload", invalid_value)),
+            None => Err("Expected type: upload, but not found".to_string()),
+        }
+    }
+
+    fn to_gql_value(&self) -> GqlValue {
+        GqlValue::Upload(self.0)
+    }
+}
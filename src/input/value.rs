@@ -0,0 +1,13 @@
+use crate::GqlValue;
+
+use super::GqlInputType;
+
+impl GqlInputType for GqlValue {
+    fn from_gql_value(value: Option<GqlValue>) -> Result<Self, String> {
+        Ok(value.unwrap_or_default())
+    }
+
+    fn to_gql_value(&self) -> GqlValue {
+        self.clone()
+    }
+}
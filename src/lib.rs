@@ -1,15 +1,29 @@
+#![allow(clippy::result_large_err)]
+
+mod connection;
 mod container;
 mod context;
 mod custom_directive;
+mod custom_scalar;
+mod data;
 mod error;
+mod error_formatter;
 mod executor;
+mod extension;
+mod federation;
+mod guard;
+mod incremental;
 mod input;
+mod multipart;
+mod node;
 mod operation;
 mod playground_html;
 mod query_root;
 mod request;
 mod resolver;
 mod response;
+mod scalars;
+mod service;
 mod test_utils;
 mod types;
 mod validation;
@@ -20,29 +34,67 @@ use std::path::Path;
 #[doc(hidden)]
 pub use async_trait;
 
-pub use container::Container;
+#[cfg(feature = "bigdecimal")]
+pub use bigdecimal;
+#[cfg(feature = "chrono")]
+pub use chrono;
+#[cfg(feature = "rust_decimal")]
+pub use rust_decimal;
+#[cfg(feature = "json")]
+pub use serde_json;
+#[cfg(feature = "time")]
+pub use time;
+#[cfg(feature = "url")]
+pub use url;
+#[cfg(feature = "uuid")]
+pub use uuid;
+
+pub use connection::{
+    connection_from_fetch, connection_from_slice, decode_cursor, encode_cursor, Connection, Edge,
+    PageInfo,
+};
+pub use container::{Container, ContainerBuilder, IntrospectionCallback, IntrospectionMode};
 pub use context::{Context, ExecutionContext, SelectionSetContext};
 pub use custom_directive::CustomDirective;
-pub use error::{Error, GqlError, GqlErrorType, GqlTypedError};
-pub use executor::execute;
+pub use custom_scalar::CustomScalar;
+pub use data::Data;
+#[doc(hidden)]
+pub use error::__private;
+pub use error::{Error, ErrorExtensions, GqlError, GqlErrorType, GqlTypedError};
+pub use error_formatter::{ErrorFormatter, ErrorMasking};
+pub use executor::{execute, execute_batch, execute_incremental};
+pub use extension::Extension;
+pub use federation::EntityResolver;
 use futures_util::Future;
-pub use input::GqlInputType;
+pub use guard::{Guard, GuardAnd, GuardOr};
+pub use incremental::{IncrementalPayload, IncrementalResponse};
+pub use input::{GqlInputType, MaybeUndefined, Upload, UploadValue};
+pub use multipart::MultipartOptions;
+pub use node::NodeFetcher;
 pub use operation::OperationType;
 pub use playground_html::playground_html;
 pub use query_root::QueryRoot;
-pub use request::{receive_http_request, HttpRequestError, Request};
+pub use request::{
+    negotiate_content_type, receive_batch_http_request, receive_http_request, BatchRequest,
+    HttpRequestError, Request, GRAPHQL_RESPONSE_CONTENT_TYPE, JSON_CONTENT_TYPE,
+};
 pub use resolver::{
-    resolve_selection_parallelly, resolve_selection_serially, CollectFields, FieldResolver, Fields,
-    SelectionSetResolver,
+    resolve_selection_parallelly, resolve_selection_serially, CollectFields, FieldMetadata,
+    FieldResolver, Fields, ResolverRegistry, SelectionSetResolver,
 };
-pub use response::Response;
+pub use response::{BatchResponse, Response};
+#[cfg(feature = "json")]
+pub use scalars::Json;
+pub use service::GraphQLService;
 pub use test_utils::{build_test_request, check_gql_response, schema_content};
 pub use types::schema::build_schema;
+pub use types::{check_schema, diff_schema, SchemaChange, SchemaChangeLevel};
 pub use types::{
     DirectiveDefinition, EnumType, FieldType, GqlConstValue as Value, GqlDirective, GqlValue,
     GqlValueType, InputObjectType, InputValueType, InterfaceType, ObjectType, ScalarType, Schema,
     TypeDefinition, UnionType, ID,
 };
+pub use validation::validate_document;
 pub use variables::Variables;
 
 pub type ResolverResult<T> = ::std::result::Result<T, GqlError>;
@@ -109,7 +161,7 @@ pub fn read_schemas(dir: &Path) -> std::io::Result<Vec<String>> {
             let entry = entry?;
             let path = entry.path();
             if path.is_dir() {
-                read_schemas(&path)?;
+                schemas.extend(read_schemas(&path)?);
             } else {
                 let content = std::fs::read_to_string(path)?;
                 schemas.push(content);
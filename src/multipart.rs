@@ -0,0 +1,375 @@
+use std::{collections::HashMap, io::SeekFrom};
+
+use bytes::Bytes;
+use futures_util::{stream, AsyncRead, AsyncReadExt};
+use tokio::io::{AsyncSeekExt, AsyncWriteExt};
+
+use crate::{request::HttpRequestError, GqlValue, Request, UploadValue, Variables};
+
+/// Limits of a multipart request, 10 MiB per file and 10 files by default. `None` is unlimited.
+#[derive(Debug, Clone, Copy)]
+pub struct MultipartOptions {
+    pub max_file_size: Option<usize>,
+    pub max_num_files: Option<usize>,
+}
+
+impl Default for MultipartOptions {
+    fn default() -> Self {
+        MultipartOptions {
+            max_file_size: Some(10 * 1024 * 1024),
+            max_num_files: Some(10),
+        }
+    }
+}
+
+impl MultipartOptions {
+    pub fn max_file_size(mut self, size: usize) -> Self {
+        self.max_file_size = Some(size);
+        self
+    }
+
+    pub fn max_num_files(mut self, num: usize) -> Self {
+        self.max_num_files = Some(num);
+        self
+    }
+}
+
+pub(crate) async fn receive_multipart(
+    body: impl AsyncRead + Send + 'static,
+    boundary: impl Into<String>,
+    options: MultipartOptions,
+) -> Result<Request, HttpRequestError> {
+    let body_stream = stream::unfold(Some(Box::pin(body)), |body| async move {
+        let mut body = body?;
+        let mut buf = vec![0; 8192];
+        match body.read(&mut buf).await {
+            Ok(0) => None,
+            Ok(n) => {
+                buf.truncate(n);
+                Some((Ok(Bytes::from(buf)), Some(body)))
+            }
+            Err(err) => Some((Err(err), None)),
+        }
+    });
+
+    let mut constraints = multer::Constraints::new();
+    if let Some(max_file_size) = options.max_file_size {
+        constraints =
+            constraints.size_limit(multer::SizeLimit::new().per_field(max_file_size as u64));
+    }
+    let mut multipart = multer::Multipart::with_constraints(body_stream, boundary, constraints);
+
+    let mut request: Option<Request> = None;
+    let mut map: Option<HashMap<String, Vec<String>>> = None;
+
+    while let Some(mut field) = multipart.next_field().await.map_err(multipart_error)? {
+        match field.name() {
+            Some("operations") => {
+                let data = field.bytes().await.map_err(multipart_error)?;
+                request = Some(
+                    serde_json::from_slice::<Request>(&data)
+                        .map_err(|err| HttpRequestError::InvalidRequest(Box::new(err)))?,
+                );
+            }
+            Some("map") => {
+                let data = field.bytes().await.map_err(multipart_error)?;
+                map = Some(
+                    serde_json::from_slice::<HashMap<String, Vec<String>>>(&data)
+                        .map_err(|err| HttpRequestError::InvalidMultipart(Box::new(err)))?,
+                );
+            }
+            _ => {
+                let name = match field.name() {
+                    Some(name) => name.to_string(),
+                    None => continue,
+                };
+                let filename = match field.file_name() {
+                    Some(filename) => filename.to_string(),
+                    None => continue,
+                };
+                // The spec requires operations and map before the files, so each file can be
+                // checked against map before it is received.
+                let (request, map) = match (request.as_mut(), map.as_mut()) {
+                    (Some(request), Some(map)) => (request, map),
+                    _ => {
+                        return Err(invalid_multipart(
+                            "operations and map parts must precede the files",
+                        ))
+                    }
+                };
+                let paths = map
+                    .remove(&name)
+                    .ok_or_else(|| invalid_multipart(format!("file {} is not in map", name)))?;
+                if let Some(max_num_files) = options.max_num_files {
+                    if request.uploads.len() >= max_num_files {
+                        return Err(HttpRequestError::PayloadTooLarge);
+                    }
+                }
+                let content_type = field.content_type().map(|mime| mime.to_string());
+
+                let content = tokio::task::spawn_blocking(tempfile::tempfile)
+                    .await
+                    .map_err(|err| HttpRequestError::Io(err.into()))?
+                    .map_err(HttpRequestError::Io)?;
+                let mut content = tokio::fs::File::from_std(content);
+                while let Some(chunk) = field.chunk().await.map_err(multipart_error)? {
+                    content
+                        .write_all(&chunk)
+                        .await
+                        .map_err(HttpRequestError::Io)?;
+                }
+                content.flush().await.map_err(HttpRequestError::Io)?;
+                content
+                    .seek(SeekFrom::Start(0))
+                    .await
+                    .map_err(HttpRequestError::Io)?;
+
+                let index = request.uploads.len();
+                for path in paths {
+                    set_upload_variable(&mut request.variables, &path, index)?;
+                }
+                request.uploads.push(UploadValue {
+                    filename,
+                    content_type,
+                    content: content.into_std().await,
+                });
+            }
+        }
+    }
+
+    let request = request.ok_or_else(|| invalid_multipart("operations part is missing"))?;
+    let map = map.ok_or_else(|| invalid_multipart("map part is missing"))?;
+    if let Some(name) = map.keys().min() {
+        return Err(invalid_multipart(format!(
+            "file {} in map is missing",
+            name
+        )));
+    }
+
+    Ok(request)
+}
+
+fn set_upload_variable(
+    variables: &mut Variables,
+    path: &str,
+    index: usize,
+) -> Result<(), HttpRequestError> {
+    let invalid_path = || invalid_multipart(format!("{} is invalid path", path));
+    let mut segments = path.split('.');
+
+    if segments.next() != Some("variables") {
+        return Err(invalid_path());
+    }
+    let variable_name = segments.next().ok_or_else(invalid_path)?;
+    let mut current = variables
+        .0
+        .get_mut(variable_name)
+        .ok_or_else(invalid_path)?;
+
+    for segment in segments {
+        current = match current {
+            GqlValue::Object(obj) => obj.get_mut(segment),
+            GqlValue::List(list) => segment
+                .parse::<usize>()
+                .ok()
+                .and_then(move |i| list.get_mut(i)),
+            _ => None,
+        }
+        .ok_or_else(invalid_path)?;
+    }
+
+    *current = GqlValue::Upload(index);
+    Ok(())
+}
+
+fn multipart_error(err: multer::Error) -> HttpRequestError {
+    match err {
+        multer::Error::FieldSizeExceeded { .. } | multer::Error::StreamSizeExceeded { .. } => {
+            HttpRequestError::PayloadTooLarge
+        }
+        err => HttpRequestError::InvalidMultipart(Box::new(err)),
+    }
+}
+
+fn invalid_multipart(message: impl Into<String>) -> HttpRequestError {
+    HttpRequestError::InvalidMultipart(message.into().into())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Read;
+
+    use crate::{receive_http_request, GqlValue, HttpRequestError, MultipartOptions};
+
+    fn multipart_headers() -> http::HeaderMap {
+        let mut headers = http::HeaderMap::new();
+        headers.insert(
+            http::header::CONTENT_TYPE,
+            "multipart/form-data; boundary=boundary".parse().unwrap(),
+        );
+        headers
+    }
+
+    fn multipart_body(files: &[(&str, &str, &str)]) -> String {
+        let mut body = String::new();
+        body += "--boundary\r\nContent-Disposition: form-data; name=\"operations\"\r\n\r\n";
+        body += r#"{"query": "mutation ($file: Upload!, $files: [Upload!]!) { upload(file: $file, files: $files) }", "variables": {"file": null, "files": [null, null]}}"#;
+        body += "\r\n--boundary\r\nContent-Disposition: form-data; name=\"map\"\r\n\r\n";
+        body +=
+            r#"{"0": ["variables.file"], "1": ["variables.files.0"], "2": ["variables.files.1"]}"#;
+        for (name, filename, content) in files {
+            body += &format!(
+                "\r\n--boundary\r\nContent-Disposition: form-data; name=\"{}\"; filename=\"{}\"\r\nContent-Type: text/plain\r\n\r\n{}",
+                name, filename, content
+            );
+        }
+        body += "\r\n--boundary--\r\n";
+        body
+    }
+
+    #[tokio::test]
+    async fn test_multipart_request() {
+        let body = multipart_body(&[
+            ("0", "a.txt", "content a"),
+            ("1", "b.txt", "content b"),
+            ("2", "c.txt", "content c"),
+        ]);
+        let mut request = receive_http_request(
+            &http::Method::POST,
+            &multipart_headers(),
+            None,
+            futures_util::io::Cursor::new(body.into_bytes()),
+            MultipartOptions::default(),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(request.variables.0.get("file"), Some(&GqlValue::Upload(0)));
+        assert_eq!(
+            request.variables.0.get("files"),
+            Some(&GqlValue::List(vec![
+                GqlValue::Upload(1),
+                GqlValue::Upload(2)
+            ]))
+        );
+        assert_eq!(request.uploads.len(), 3);
+
+        let mut upload = request.uploads.remove(1);
+        assert_eq!(upload.filename, "b.txt");
+        assert_eq!(upload.content_type, Some("text/plain".to_string()));
+        let mut content = String::new();
+        upload.content.read_to_string(&mut content).unwrap();
+        assert_eq!(content, "content b");
+    }
+
+    #[tokio::test]
+    async fn test_max_num_files() {
+        let body = multipart_body(&[
+            ("0", "a.txt", "content a"),
+            ("1", "b.txt", "content b"),
+            ("2", "c.txt", "content c"),
+        ]);
+        let result = receive_http_request(
+            &http::Method::POST,
+            &multipart_headers(),
+            None,
+            futures_util::io::Cursor::new(body.into_bytes()),
+            MultipartOptions::default().max_num_files(2),
+        )
+        .await;
+
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_max_file_size() {
+        let body = multipart_body(&[("0", "a.txt", "large content")]);
+        let result = receive_http_request(
+            &http::Method::POST,
+            &multipart_headers(),
+            None,
+            futures_util::io::Cursor::new(body.into_bytes()),
+            MultipartOptions::default().max_file_size(5),
+        )
+        .await;
+
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_default_max_num_files() {
+        let mut body = String::new();
+        body += "--boundary\r\nContent-Disposition: form-data; name=\"operations\"\r\n\r\n";
+        body += &format!(
+            r#"{{"query": "mutation ($files: [Upload!]!) {{ upload(files: $files) }}", "variables": {{"files": [{}]}}}}"#,
+            ["null"; 11].join(", ")
+        );
+        body += "\r\n--boundary\r\nContent-Disposition: form-data; name=\"map\"\r\n\r\n";
+        let map = (0..11)
+            .map(|i| format!(r#""{}": ["variables.files.{}"]"#, i, i))
+            .collect::<Vec<_>>();
+        body += &format!("{{{}}}", map.join(", "));
+        for i in 0..11 {
+            body += &format!(
+                "\r\n--boundary\r\nContent-Disposition: form-data; name=\"{}\"; filename=\"{}.txt\"\r\n\r\ncontent",
+                i, i
+            );
+        }
+        body += "\r\n--boundary--\r\n";
+        let result = receive_http_request(
+            &http::Method::POST,
+            &multipart_headers(),
+            None,
+            futures_util::io::Cursor::new(body.into_bytes()),
+            MultipartOptions::default(),
+        )
+        .await;
+
+        assert!(matches!(result, Err(HttpRequestError::PayloadTooLarge)));
+    }
+
+    #[tokio::test]
+    async fn test_file_not_in_map() {
+        let body = multipart_body(&[
+            ("0", "a.txt", "content a"),
+            ("1", "b.txt", "content b"),
+            ("2", "c.txt", "content c"),
+            ("3", "d.txt", "content d"),
+        ]);
+        let result = receive_http_request(
+            &http::Method::POST,
+            &multipart_headers(),
+            None,
+            futures_util::io::Cursor::new(body.into_bytes()),
+            MultipartOptions::default(),
+        )
+        .await;
+
+        match result {
+            Err(HttpRequestError::InvalidMultipart(err)) => {
+                assert_eq!(err.to_string(), "file 3 is not in map")
+            }
+            _ => panic!("file 3 is not in map"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_file_in_map_is_missing() {
+        let body = multipart_body(&[("0", "a.txt", "content a"), ("2", "c.txt", "content c")]);
+        let result = receive_http_request(
+            &http::Method::POST,
+            &multipart_headers(),
+            None,
+            futures_util::io::Cursor::new(body.into_bytes()),
+            MultipartOptions::default(),
+        )
+        .await;
+
+        match result {
+            Err(HttpRequestError::InvalidMultipart(err)) => {
+                assert_eq!(err.to_string(), "file 1 in map is missing")
+            }
+            _ => panic!("file 1 in map is missing"),
+        }
+    }
+}
@@ -0,0 +1,172 @@
+use std::collections::HashMap;
+
+use graphql_parser::schema::{Definition, TypeDefinition, TypeExtension};
+
+use crate::{
+    Context, GqlError, GqlValue, ResolverResult, SelectionSetContext,
+    TypeDefinition as GqlTypeDefinition, ID,
+};
+
+#[async_trait::async_trait]
+pub trait NodeFetcher: Send + Sync {
+    async fn fetch_node(
+        &self,
+        ctx: &SelectionSetContext<'_>,
+        local_id: &str,
+    ) -> ResolverResult<GqlValue>;
+}
+
+pub(crate) struct RelayNode {
+    pub(crate) fetchers: HashMap<String, Box<dyn NodeFetcher>>,
+    /// `node` and `nodes` added to the query type, the schema may define them itself.
+    pub(crate) root_fields: Vec<&'static str>,
+}
+
+impl RelayNode {
+    pub(crate) async fn resolve_node(&self, ctx: &Context<'_>) -> ResolverResult<GqlValue> {
+        let id = ctx.get_arg_value::<ID>("id")?;
+        let ctx_selection_set = ctx.with_selection_set(&ctx.item.selection_set);
+        self.fetch(&ctx_selection_set, &id).await
+    }
+
+    pub(crate) async fn resolve_nodes(&self, ctx: &Context<'_>) -> ResolverResult<GqlValue> {
+        let ids = ctx.get_arg_value::<Vec<ID>>("ids")?;
+        let ctx_selection_set = ctx.with_selection_set(&ctx.item.selection_set);
+
+        let mut nodes = Vec::new();
+        for id in &ids {
+            match self.fetch(&ctx_selection_set, id).await {
+                Ok(node) => nodes.push(node),
+                Err(error) => {
+                    ctx.add_error(&error);
+                    nodes.push(GqlValue::Null);
+                }
+            }
+        }
+        Ok(GqlValue::List(nodes))
+    }
+
+    // A malformed id or an id of a type without a fetcher does not refer to any node.
+    async fn fetch(&self, ctx: &SelectionSetContext<'_>, id: &ID) -> ResolverResult<GqlValue> {
+        let (type_name, local_id) = match id.decode_global_id() {
+            Ok(global_id) => global_id,
+            Err(_) => return Ok(GqlValue::Null),
+        };
+        match self.fetchers.get(&type_name) {
+            Some(fetcher) => fetcher.fetch_node(ctx, &local_id).await,
+            None => Ok(GqlValue::Null),
+        }
+    }
+}
+
+/// Returns the definitions missing in the schema, and the root fields added to the query type.
+pub(crate) fn relay_node_sdl(
+    schema_documents: &[&str],
+) -> Result<(String, Vec<&'static str>), GqlError> {
+    let mut query_type_name = "Query".to_string();
+    let mut has_node_interface = false;
+    let mut object_fields = HashMap::new();
+
+    for doc in schema_documents {
+        let parsed_schema = graphql_parser::parse_schema::<String>(doc)
+            .map_err(|err| GqlError::new(err.to_string(), None))?;
+        for node in parsed_schema.definitions {
+            match node {
+                Definition::SchemaDefinition(schema_def) => {
+                    if let Some(query) = schema_def.query {
+                        query_type_name = query;
+                    }
+                }
+                Definition::TypeDefinition(TypeDefinition::Interface(interface))
+                    if interface.name == "Node" =>
+                {
+                    has_node_interface = true;
+                }
+                Definition::TypeDefinition(TypeDefinition::Object(obj)) => {
+                    object_fields
+                        .entry(obj.name)
+                        .or_insert_with(Vec::new)
+                        .extend(obj.fields.into_iter().map(|f| f.name));
+                }
+                Definition::TypeExtension(TypeExtension::Object(obj_ext)) => {
+                    object_fields
+                        .entry(obj_ext.name)
+                        .or_insert_with(Vec::new)
+                        .extend(obj_ext.fields.into_iter().map(|f| f.name));
+                }
+                _ => {}
+            }
+        }
+    }
+
+    let query_fields = object_fields.remove(&query_type_name).unwrap_or_default();
+
+    let mut sdl = String::new();
+    if !has_node_interface {
+        sdl += "interface Node {\n  id: ID!\n}\n";
+    }
+
+    let mut root_fields = Vec::new();
+    let mut root_field_defs = Vec::new();
+    if !query_fields.iter().any(|name| name == "node") {
+        root_fields.push("node");
+        root_field_defs.push("  node(id: ID!): Node");
+    }
+    if !query_fields.iter().any(|name| name == "nodes") {
+        root_fields.push("nodes");
+        root_field_defs.push("  nodes(ids: [ID!]!): [Node]!");
+    }
+    if !root_field_defs.is_empty() {
+        sdl += &format!(
+            "extend type {} {{\n{}\n}}\n",
+            query_type_name,
+            root_field_defs.join("\n")
+        );
+    }
+
+    Ok((sdl, root_fields))
+}
+
+pub(crate) fn node_types(type_definitions: &HashMap<String, GqlTypeDefinition>) -> Vec<String> {
+    type_definitions
+        .values()
+        .filter_map(|ty_def| match ty_def {
+            GqlTypeDefinition::Object(obj)
+                if obj.implements_interfaces.contains(&"Node".to_string()) =>
+            {
+                Some(obj.name.clone())
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::relay_node_sdl;
+
+    #[test]
+    fn test_relay_node_sdl() {
+        let schema_doc = r#"
+            type Query { me: User }
+            type User { id: ID! name: String }
+        "#;
+        let (sdl, root_fields) = relay_node_sdl(&[schema_doc]).unwrap();
+        assert!(sdl.contains("interface Node {"));
+        assert!(sdl.contains("node(id: ID!): Node"));
+        assert!(sdl.contains("nodes(ids: [ID!]!): [Node]!"));
+        assert_eq!(root_fields, vec!["node", "nodes"]);
+    }
+
+    #[test]
+    fn test_relay_node_sdl_with_defined_fields() {
+        let schema_doc = r#"
+            type Query { node(id: ID!): Node nodes(ids: [ID!]!): [Node]! }
+            interface Node { id: ID! }
+            type User implements Node { id: ID! name: String }
+        "#;
+        let (sdl, root_fields) = relay_node_sdl(&[schema_doc]).unwrap();
+        assert!(sdl.is_empty());
+        assert!(root_fields.is_empty());
+    }
+}
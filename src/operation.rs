@@ -9,7 +9,7 @@ use graphql_parser::{
     schema::Directive,
 };
 
-use crate::{error::GqlError, Variables};
+use crate::{error::GqlError, GqlValue, IntrospectionMode, UploadValue, Variables};
 
 #[derive(Debug)]
 pub struct OperationInner<'a> {
@@ -20,6 +20,8 @@ pub struct OperationInner<'a> {
     pub fragment_definitions: HashMap<String, FragmentDefinition<'a, String>>,
     pub errors: Mutex<Vec<GqlError>>,
     pub variables: Variables,
+    pub uploads: Mutex<Vec<Option<UploadValue>>>,
+    pub introspection_mode: IntrospectionMode,
 }
 
 #[derive(Debug)]
@@ -29,6 +31,28 @@ impl<'a> Operation<'a> {
     pub fn new(operation: OperationInner<'a>) -> Operation<'a> {
         Operation(Arc::new(operation))
     }
+
+    // The provided value of a declared variable, or else its default value, with whether it is a
+    // literal of the document.
+    pub(crate) fn variable_value(&self, name: &str) -> Option<(GqlValue, bool)> {
+        let var_def = self
+            .variable_definitions
+            .iter()
+            .find(|var_def| var_def.name == name)?;
+        match self.variables.0.get(name) {
+            Some(value) => Some((value.clone(), false)),
+            None => var_def
+                .default_value
+                .clone()
+                .map(|value| (GqlValue::from(value), true)),
+        }
+    }
+
+    pub(crate) fn set_introspection_mode(&mut self, mode: IntrospectionMode) {
+        if let Some(operation) = Arc::get_mut(&mut self.0) {
+            operation.introspection_mode = mode;
+        }
+    }
 }
 
 impl<'a> Deref for Operation<'a> {
@@ -54,12 +78,12 @@ pub enum OperationType {
     Subscription,
 }
 
-impl ToString for OperationType {
-    fn to_string(&self) -> String {
+impl std::fmt::Display for OperationType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            OperationType::Query => String::from("Query"),
-            OperationType::Mutation => String::from("Mutation"),
-            OperationType::Subscription => String::from("Subscription"),
+            OperationType::Query => write!(f, "Query"),
+            OperationType::Mutation => write!(f, "Mutation"),
+            OperationType::Subscription => write!(f, "Subscription"),
         }
     }
 }
@@ -166,6 +190,8 @@ pub fn build_operation<'a>(
                         selection_set: definition.selection_set,
                         errors: Default::default(),
                         variables,
+                        uploads: Default::default(),
+                        introspection_mode: Default::default(),
                     })))
                 }
                 None => Err(GqlError::new(
@@ -174,7 +200,7 @@ pub fn build_operation<'a>(
                 )),
             }
         }
-        None => match operation_definitions.get(&no_name_key.to_string()) {
+        None => match operation_definitions.get(no_name_key) {
             Some(definition) => {
                 let definition = definition.clone();
                 Ok(Operation(Arc::new(OperationInner {
@@ -185,6 +211,8 @@ pub fn build_operation<'a>(
                     selection_set: definition.selection_set,
                     errors: Default::default(),
                     variables,
+                    uploads: Default::default(),
+                    introspection_mode: Default::default(),
                 })))
             }
             None => match operation_definitions.values().next() {
@@ -198,6 +226,8 @@ pub fn build_operation<'a>(
                         selection_set: definition.selection_set,
                         errors: Default::default(),
                         variables,
+                        uploads: Default::default(),
+                        introspection_mode: Default::default(),
                     })))
                 }
                 None => Err(GqlError::new("operation does not exist", None)),
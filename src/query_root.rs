@@ -1,16 +1,30 @@
 use crate::{
-    error::GqlError,
+    error::{GqlError, GqlErrorType},
+    federation::Federation,
+    node::RelayNode,
     types::{__Type, build_schema_introspection},
-    CollectFields, Context, FieldResolver, GqlValue, ResolverResult, SelectionSetResolver,
+    CollectFields, Context, FieldResolver, GqlValue, IntrospectionMode, ResolverResult,
+    SelectionSetResolver,
 };
 
 pub struct QueryRoot<T> {
     pub query: T,
+    pub(crate) federation: Option<Federation>,
+    pub(crate) relay_node: Option<RelayNode>,
 }
 
 #[async_trait::async_trait]
 impl<T: SelectionSetResolver> FieldResolver for QueryRoot<T> {
     async fn resolve_field(&self, ctx: &Context<'_>) -> ResolverResult<Option<GqlValue>> {
+        if (ctx.item.name == "__schema" || ctx.item.name == "__type")
+            && ctx.operation.introspection_mode != IntrospectionMode::Enabled
+        {
+            return Err(
+                GqlError::new("Introspection is disabled", Some(ctx.item.position))
+                    .with_error_type(GqlErrorType::BadRequest),
+            );
+        }
+
         if ctx.item.name == "__schema" {
             let ctx_selection_set = ctx.with_selection_set(&ctx.item.selection_set);
             let schema_intro = build_schema_introspection(ctx.schema);
@@ -31,9 +45,32 @@ impl<T: SelectionSetResolver> FieldResolver for QueryRoot<T> {
                     .resolve_selection_set(&ctx_selection_set)
                     .await
                     .map(Some),
-                None => Err(GqlError::new(format!("{} is not defined", type_name), None)),
+                None => Err(GqlError::new(format!("{} is not defined", type_name), None)
+                    .with_error_type(GqlErrorType::BadRequest)),
             }
         } else {
+            if let Some(federation) = self
+                .federation
+                .as_ref()
+                .filter(|federation| federation.root_fields.contains(&ctx.item.name.as_str()))
+            {
+                match ctx.item.name.as_str() {
+                    "_service" => return federation.resolve_service(ctx).await.map(Some),
+                    "_entities" => return federation.resolve_entities(ctx).await.map(Some),
+                    _ => {}
+                }
+            }
+            if let Some(relay_node) = self
+                .relay_node
+                .as_ref()
+                .filter(|relay_node| relay_node.root_fields.contains(&ctx.item.name.as_str()))
+            {
+                match ctx.item.name.as_str() {
+                    "node" => return relay_node.resolve_node(ctx).await.map(Some),
+                    "nodes" => return relay_node.resolve_nodes(ctx).await.map(Some),
+                    _ => {}
+                }
+            }
             self.query.resolve_field(ctx).await
         }
     }
@@ -1,9 +1,15 @@
 use std::collections::HashMap;
 
 use futures_util::{pin_mut, AsyncRead, AsyncReadExt};
+use graphql_parser::query::{Definition, OperationDefinition};
+use http::{header, HeaderMap, Method, StatusCode};
 use serde::{Deserialize, Serialize};
 
-use crate::{variables::Variables, GqlValue};
+use crate::{
+    multipart::{receive_multipart, MultipartOptions},
+    variables::Variables,
+    GqlError, GqlValue, OperationType, Response, UploadValue,
+};
 
 #[derive(Serialize, Deserialize, Debug)]
 #[serde(rename_all = "camelCase")]
@@ -16,36 +22,392 @@ pub struct Request {
     pub variables: Variables,
     #[serde(default)]
     pub extensions: HashMap<String, GqlValue>,
+    #[serde(skip)]
+    pub uploads: Vec<UploadValue>,
+    #[serde(skip)]
+    pub http_headers: HeaderMap,
 }
 
+#[allow(clippy::large_enum_variant)]
+#[derive(Debug)]
+pub enum BatchRequest {
+    Single(Request),
+    Batch(Vec<Request>),
+}
+
+// Decides the variant by the first token, so that the error of the request itself is reported
+// instead of "data did not match any variant".
+impl<'de> Deserialize<'de> for BatchRequest {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct BatchRequestVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for BatchRequestVisitor {
+            type Value = BatchRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+                formatter.write_str("a request object or a list of request objects")
+            }
+
+            fn visit_map<A: serde::de::MapAccess<'de>>(
+                self,
+                map: A,
+            ) -> Result<Self::Value, A::Error> {
+                Request::deserialize(serde::de::value::MapAccessDeserializer::new(map))
+                    .map(BatchRequest::Single)
+            }
+
+            fn visit_seq<A: serde::de::SeqAccess<'de>>(
+                self,
+                seq: A,
+            ) -> Result<Self::Value, A::Error> {
+                Vec::<Request>::deserialize(serde::de::value::SeqAccessDeserializer::new(seq))
+                    .map(BatchRequest::Batch)
+            }
+        }
+
+        deserializer.deserialize_any(BatchRequestVisitor)
+    }
+}
+
+impl BatchRequest {
+    pub fn into_single(self) -> Result<Request, HttpRequestError> {
+        match self {
+            BatchRequest::Single(request) => Ok(request),
+            BatchRequest::Batch(_) => Err(HttpRequestError::InvalidRequest(
+                "batch request is not supported".into(),
+            )),
+        }
+    }
+}
+
+impl From<Request> for BatchRequest {
+    fn from(request: Request) -> Self {
+        BatchRequest::Single(request)
+    }
+}
+
+pub const JSON_CONTENT_TYPE: &str = "application/json";
+pub const GRAPHQL_RESPONSE_CONTENT_TYPE: &str = "application/graphql-response+json";
+
 pub async fn receive_http_request(
-    body: impl AsyncRead + Send,
+    method: &Method,
+    headers: &HeaderMap,
+    query_string: Option<&str>,
+    body: impl AsyncRead + Send + 'static,
+    options: MultipartOptions,
 ) -> Result<Request, HttpRequestError> {
-    receive_json_body(body).await
+    receive_batch_http_request(method, headers, query_string, body, options)
+        .await?
+        .into_single()
+}
+
+pub async fn receive_batch_http_request(
+    method: &Method,
+    headers: &HeaderMap,
+    query_string: Option<&str>,
+    body: impl AsyncRead + Send + 'static,
+    options: MultipartOptions,
+) -> Result<BatchRequest, HttpRequestError> {
+    let accept = headers
+        .get(header::ACCEPT)
+        .and_then(|value| value.to_str().ok());
+    if negotiate_content_type(accept).is_none() {
+        return Err(HttpRequestError::NotAcceptable);
+    }
+
+    let mut batch_request = if method == Method::GET {
+        BatchRequest::Single(receive_query_string(query_string.unwrap_or_default())?)
+    } else if method == Method::POST {
+        let content_type = headers
+            .get(header::CONTENT_TYPE)
+            .and_then(|value| value.to_str().ok())
+            .ok_or(HttpRequestError::UnsupportedMediaType)?;
+        if let Ok(boundary) = multer::parse_boundary(content_type) {
+            BatchRequest::Single(receive_multipart(body, boundary, options).await?)
+        } else if media_type_essence(content_type) == JSON_CONTENT_TYPE {
+            receive_batch_json_body(body).await?
+        } else {
+            return Err(HttpRequestError::UnsupportedMediaType);
+        }
+    } else {
+        return Err(HttpRequestError::MethodNotAllowed);
+    };
+
+    match &mut batch_request {
+        BatchRequest::Single(request) => request.http_headers = headers.clone(),
+        BatchRequest::Batch(requests) => {
+            for request in requests {
+                request.http_headers = headers.clone();
+            }
+        }
+    }
+    Ok(batch_request)
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct QueryStringRequest {
+    query: Option<String>,
+    operation_name: Option<String>,
+    variables: Option<String>,
+    extensions: Option<String>,
+}
+
+fn receive_query_string(query_string: &str) -> Result<Request, HttpRequestError> {
+    let params = serde_urlencoded::from_str::<QueryStringRequest>(query_string)
+        .map_err(|err| HttpRequestError::InvalidRequest(Box::new(err)))?;
+    let query = params
+        .query
+        .ok_or_else(|| HttpRequestError::InvalidRequest("query is missing".into()))?;
+    let variables = match params.variables {
+        Some(variables) => serde_json::from_str(&variables)
+            .map_err(|err| HttpRequestError::InvalidRequest(Box::new(err)))?,
+        None => Default::default(),
+    };
+    let extensions = match params.extensions {
+        Some(extensions) => serde_json::from_str(&extensions)
+            .map_err(|err| HttpRequestError::InvalidRequest(Box::new(err)))?,
+        None => Default::default(),
+    };
+
+    if is_mutation(&query, params.operation_name.as_deref()) {
+        return Err(HttpRequestError::MutationNotAllowed);
+    }
+
+    Ok(Request {
+        query,
+        operation_name: params.operation_name,
+        variables,
+        extensions,
+        uploads: Default::default(),
+        http_headers: Default::default(),
+    })
+}
+
+// Documents that cannot be parsed are left to the executor to report as GraphQL errors.
+fn is_mutation(query: &str, operation_name: Option<&str>) -> bool {
+    matches!(
+        operation_type(query, operation_name),
+        Some(OperationType::Mutation)
+    )
+}
+
+/// The type of the operation to execute, `None` if the document cannot be parsed or has no such operation.
+pub(crate) fn operation_type(query: &str, operation_name: Option<&str>) -> Option<OperationType> {
+    let query_doc = graphql_parser::parse_query::<&str>(query).ok()?;
+    let mut operations = query_doc
+        .definitions
+        .iter()
+        .filter_map(|definition| match definition {
+            Definition::Operation(operation) => Some(operation),
+            Definition::Fragment(_) => None,
+        });
+
+    let operation = match operation_name {
+        Some(name) => operations.find(|operation| match operation {
+            OperationDefinition::Query(query) => query.name == Some(name),
+            OperationDefinition::Mutation(mutation) => mutation.name == Some(name),
+            OperationDefinition::Subscription(subscription) => subscription.name == Some(name),
+            OperationDefinition::SelectionSet(_) => false,
+        }),
+        None => operations.next(),
+    };
+    match operation? {
+        OperationDefinition::Query(_) | OperationDefinition::SelectionSet(_) => {
+            Some(OperationType::Query)
+        }
+        OperationDefinition::Mutation(_) => Some(OperationType::Mutation),
+        OperationDefinition::Subscription(_) => Some(OperationType::Subscription),
+    }
 }
 
-pub async fn receive_json_body(body: impl AsyncRead) -> Result<Request, HttpRequestError> {
+async fn receive_batch_json_body(body: impl AsyncRead) -> Result<BatchRequest, HttpRequestError> {
     let mut data = Vec::new();
     pin_mut!(body);
 
     body.read_to_end(&mut data)
         .await
         .map_err(HttpRequestError::Io)?;
-    Ok(serde_json::from_slice::<Request>(&data)
-        .map_err(|err| HttpRequestError::InvalidRequest(Box::new(err)))?)
+    let invalid_request = |err| HttpRequestError::InvalidRequest(Box::new(err));
+    match data.iter().find(|byte| !byte.is_ascii_whitespace()) {
+        Some(b'[') => {
+            let requests =
+                serde_json::from_slice::<Vec<Request>>(&data).map_err(invalid_request)?;
+            if requests.is_empty() {
+                return Err(HttpRequestError::InvalidRequest(
+                    "batch request must not be empty".into(),
+                ));
+            }
+            Ok(BatchRequest::Batch(requests))
+        }
+        _ => serde_json::from_slice::<Request>(&data)
+            .map(BatchRequest::Single)
+            .map_err(invalid_request),
+    }
+}
+
+fn media_type_essence(media_type: &str) -> String {
+    media_type
+        .split(';')
+        .next()
+        .unwrap_or_default()
+        .trim()
+        .to_ascii_lowercase()
+}
+
+// Requests without Accept header are treated as `application/json` for legacy clients.
+// The media type with the highest q-value is chosen, and `q=0` means "not acceptable".
+pub fn negotiate_content_type(accept: Option<&str>) -> Option<&'static str> {
+    let accept = match accept {
+        Some(accept) => accept,
+        None => return Some(JSON_CONTENT_TYPE),
+    };
+    let media_ranges: Vec<(&'static str, u8, f32)> = accept
+        .split(',')
+        .filter_map(|media_range| {
+            let (content_type, specificity) = match media_type_essence(media_range).as_str() {
+                GRAPHQL_RESPONSE_CONTENT_TYPE => (GRAPHQL_RESPONSE_CONTENT_TYPE, 2),
+                JSON_CONTENT_TYPE => (JSON_CONTENT_TYPE, 2),
+                "application/*" => (JSON_CONTENT_TYPE, 1),
+                "*/*" => (JSON_CONTENT_TYPE, 0),
+                _ => return None,
+            };
+            Some((content_type, specificity, media_type_quality(media_range)))
+        })
+        .collect();
+    // A wildcard does not choose a media type excluded with `q=0`.
+    let is_excluded = |content_type: &str| {
+        media_ranges.iter().any(|&(ty, specificity, quality)| {
+            ty == content_type && specificity == 2 && quality <= 0.0
+        })
+    };
+
+    let mut negotiated: Option<(&'static str, u8, f32)> = None;
+    for &(content_type, specificity, quality) in &media_ranges {
+        if quality <= 0.0 || (specificity < 2 && is_excluded(content_type)) {
+            continue;
+        }
+        // On a tie, a more specific media type wins, and then the one listed first.
+        let is_better = match negotiated {
+            Some((_, s, q)) => quality > q || (quality == q && specificity > s),
+            None => true,
+        };
+        if is_better {
+            negotiated = Some((content_type, specificity, quality));
+        }
+    }
+    negotiated.map(|(content_type, _, _)| content_type)
+}
+
+fn media_type_quality(media_range: &str) -> f32 {
+    media_range
+        .split(';')
+        .skip(1)
+        .find_map(|param| {
+            let (name, value) = param.split_once('=')?;
+            if name.trim().eq_ignore_ascii_case("q") {
+                // An invalid q-value makes the media type unacceptable.
+                Some(value.trim().parse::<f32>().unwrap_or(0.0).clamp(0.0, 1.0))
+            } else {
+                None
+            }
+        })
+        .unwrap_or(1.0)
 }
 
 #[derive(Debug)]
 pub enum HttpRequestError {
     Io(std::io::Error),
     InvalidRequest(Box<dyn std::error::Error + Send + Sync>),
+    InvalidMultipart(Box<dyn std::error::Error + Send + Sync>),
+    PayloadTooLarge,
+    MethodNotAllowed,
+    MutationNotAllowed,
+    UnsupportedMediaType,
+    NotAcceptable,
+}
+
+impl HttpRequestError {
+    pub fn status_code(&self) -> StatusCode {
+        match self {
+            HttpRequestError::Io(_)
+            | HttpRequestError::InvalidRequest(_)
+            | HttpRequestError::InvalidMultipart(_) => StatusCode::BAD_REQUEST,
+            HttpRequestError::PayloadTooLarge => StatusCode::PAYLOAD_TOO_LARGE,
+            HttpRequestError::MethodNotAllowed | HttpRequestError::MutationNotAllowed => {
+                StatusCode::METHOD_NOT_ALLOWED
+            }
+            HttpRequestError::UnsupportedMediaType => StatusCode::UNSUPPORTED_MEDIA_TYPE,
+            HttpRequestError::NotAcceptable => StatusCode::NOT_ACCEPTABLE,
+        }
+    }
+
+    pub fn into_http_response(self) -> http::Response<String> {
+        let mut builder = http::Response::builder()
+            .status(self.status_code())
+            .header(header::CONTENT_TYPE, JSON_CONTENT_TYPE);
+        if matches!(self, HttpRequestError::MutationNotAllowed) {
+            builder = builder.header(header::ALLOW, "POST");
+        }
+        let response = Response::from_errors(vec![GqlError::new(self.to_string(), None)]);
+        builder
+            .body(serde_json::to_string(&response).unwrap_or_default())
+            .unwrap()
+    }
 }
 
+impl std::fmt::Display for HttpRequestError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            HttpRequestError::Io(err) => write!(f, "failed to read request: {}", err),
+            HttpRequestError::InvalidRequest(err) => write!(f, "invalid request: {}", err),
+            HttpRequestError::InvalidMultipart(err) => {
+                write!(f, "invalid multipart request: {}", err)
+            }
+            HttpRequestError::PayloadTooLarge => write!(f, "payload too large"),
+            HttpRequestError::MethodNotAllowed => write!(f, "method not allowed"),
+            HttpRequestError::MutationNotAllowed => {
+                write!(f, "mutations cannot be executed over GET")
+            }
+            HttpRequestError::UnsupportedMediaType => write!(f, "unsupported media type"),
+            HttpRequestError::NotAcceptable => write!(f, "not acceptable"),
+        }
+    }
+}
+
+impl std::error::Error for HttpRequestError {}
+
 #[cfg(test)]
 mod tests {
     use serde_json::Number;
 
-    use crate::{GqlValue, Request};
+    use futures_util::io::Cursor;
+    use http::{header, HeaderMap, Method, StatusCode};
+
+    use crate::{
+        negotiate_content_type, receive_http_request, BatchRequest, GqlValue, HttpRequestError,
+        Request, GRAPHQL_RESPONSE_CONTENT_TYPE, JSON_CONTENT_TYPE,
+    };
+
+    async fn receive(
+        method: Method,
+        headers: &[(header::HeaderName, &str)],
+        query_string: Option<&str>,
+        body: &str,
+    ) -> Result<Request, HttpRequestError> {
+        let mut header_map = HeaderMap::new();
+        for (name, value) in headers {
+            header_map.insert(name, value.parse().unwrap());
+        }
+        receive_http_request(
+            &method,
+            &header_map,
+            query_string,
+            Cursor::new(body.as_bytes().to_vec()),
+            Default::default(),
+        )
+        .await
+    }
 
     #[test]
     fn test_operation_name() {
@@ -65,7 +427,7 @@ mod tests {
 
         assert_eq!(
             req.variables.0.get("var1"),
-            Some(&GqlValue::Number(Number::from(100 as i32)))
+            Some(&GqlValue::Number(Number::from(100_i32)))
         );
         assert_eq!(
             req.variables.0.get("var2"),
@@ -74,9 +436,9 @@ mod tests {
         assert_eq!(
             req.variables.0.get("var3"),
             Some(&GqlValue::List(vec![
-                GqlValue::Number(Number::from(1 as i32)),
-                GqlValue::Number(Number::from(1 as i32)),
-                GqlValue::Number(Number::from(1 as i32))
+                GqlValue::Number(Number::from(1_i32)),
+                GqlValue::Number(Number::from(1_i32)),
+                GqlValue::Number(Number::from(1_i32))
             ]))
         );
     }
@@ -89,4 +451,176 @@ mod tests {
         assert!(req.operation_name.is_none());
         assert!(req.variables.0.is_empty());
     }
+
+    #[test]
+    fn test_batch_request() {
+        let query_doc = r#"{"query": "{ hero }"}"#;
+        let req = serde_json::from_str::<BatchRequest>(query_doc).unwrap();
+        assert!(matches!(req, BatchRequest::Single(_)));
+
+        let query_doc =
+            r#"[{"query": "{ hero }"}, {"query": "{ droids }", "operationName": "droids"}]"#;
+        match serde_json::from_str::<BatchRequest>(query_doc).unwrap() {
+            BatchRequest::Batch(requests) => {
+                assert_eq!(requests.len(), 2);
+                assert_eq!(requests[1].query, "{ droids }");
+                assert_eq!(requests[1].operation_name, Some("droids".to_string()));
+            }
+            BatchRequest::Single(_) => panic!("expected batch request"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_get_request() {
+        let req = receive(
+            Method::GET,
+            &[],
+            Some("query=query%20Hero(%24id%3A%20ID)%20%7B%20hero(id%3A%20%24id)%20%7D&operationName=Hero&variables=%7B%22id%22%3A%221%22%7D"),
+            "",
+        )
+        .await
+        .unwrap();
+        assert_eq!(req.query, "query Hero($id: ID) { hero(id: $id) }");
+        assert_eq!(req.operation_name, Some("Hero".to_string()));
+        assert_eq!(
+            req.variables.0.get("id"),
+            Some(&GqlValue::String("1".to_string()))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_request_errors() {
+        let err = receive(Method::GET, &[], Some("operationName=Hero"), "")
+            .await
+            .unwrap_err();
+        assert_eq!(err.status_code(), StatusCode::BAD_REQUEST);
+
+        let err = receive(Method::GET, &[], Some("query=%7B&variables=invalid"), "")
+            .await
+            .unwrap_err();
+        assert_eq!(err.status_code(), StatusCode::BAD_REQUEST);
+
+        let err = receive(
+            Method::GET,
+            &[],
+            Some("query=mutation%20%7B%20createHero%20%7D"),
+            "",
+        )
+        .await
+        .unwrap_err();
+        assert!(matches!(err, HttpRequestError::MutationNotAllowed));
+        assert_eq!(err.status_code(), StatusCode::METHOD_NOT_ALLOWED);
+
+        let err = receive(
+            Method::GET,
+            &[],
+            Some("query=query%20A%20%7B%20hero%20%7D%20mutation%20B%20%7B%20createHero%20%7D&operationName=B"),
+            "",
+        )
+        .await
+        .unwrap_err();
+        assert!(matches!(err, HttpRequestError::MutationNotAllowed));
+    }
+
+    #[tokio::test]
+    async fn test_post_request() {
+        let body = r#"{"query": "{ hero }"}"#;
+        let req = receive(
+            Method::POST,
+            &[(header::CONTENT_TYPE, "application/json; charset=utf-8")],
+            None,
+            body,
+        )
+        .await
+        .unwrap();
+        assert_eq!(req.query, "{ hero }");
+
+        let err = receive(Method::POST, &[], None, body).await.unwrap_err();
+        assert_eq!(err.status_code(), StatusCode::UNSUPPORTED_MEDIA_TYPE);
+
+        let err = receive(
+            Method::POST,
+            &[(header::CONTENT_TYPE, "text/plain")],
+            None,
+            body,
+        )
+        .await
+        .unwrap_err();
+        assert_eq!(err.status_code(), StatusCode::UNSUPPORTED_MEDIA_TYPE);
+
+        let err = receive(
+            Method::POST,
+            &[
+                (header::CONTENT_TYPE, "application/json"),
+                (header::ACCEPT, "text/html"),
+            ],
+            None,
+            body,
+        )
+        .await
+        .unwrap_err();
+        assert_eq!(err.status_code(), StatusCode::NOT_ACCEPTABLE);
+
+        let err = receive(Method::PUT, &[], None, body).await.unwrap_err();
+        assert_eq!(err.status_code(), StatusCode::METHOD_NOT_ALLOWED);
+    }
+
+    #[test]
+    fn test_negotiate_content_type() {
+        assert_eq!(negotiate_content_type(None), Some(JSON_CONTENT_TYPE));
+        assert_eq!(
+            negotiate_content_type(Some(
+                "application/graphql-response+json, application/json;q=0.9"
+            )),
+            Some(GRAPHQL_RESPONSE_CONTENT_TYPE)
+        );
+        assert_eq!(
+            negotiate_content_type(Some("application/json")),
+            Some(JSON_CONTENT_TYPE)
+        );
+        assert_eq!(negotiate_content_type(Some("*/*")), Some(JSON_CONTENT_TYPE));
+        assert_eq!(negotiate_content_type(Some("text/html")), None);
+    }
+
+    #[test]
+    fn test_negotiate_content_type_with_quality() {
+        assert_eq!(
+            negotiate_content_type(Some(
+                "application/json;q=0.9, application/graphql-response+json"
+            )),
+            Some(GRAPHQL_RESPONSE_CONTENT_TYPE)
+        );
+        assert_eq!(
+            negotiate_content_type(Some(
+                "application/graphql-response+json;q=0.5, application/json; q=0.8"
+            )),
+            Some(JSON_CONTENT_TYPE)
+        );
+        assert_eq!(
+            negotiate_content_type(Some("*/*, application/graphql-response+json")),
+            Some(GRAPHQL_RESPONSE_CONTENT_TYPE)
+        );
+        assert_eq!(
+            negotiate_content_type(Some("application/graphql-response+json;q=0, */*;q=0.1")),
+            Some(JSON_CONTENT_TYPE)
+        );
+        assert_eq!(
+            negotiate_content_type(Some("application/json;q=0, text/html")),
+            None
+        );
+        assert_eq!(
+            negotiate_content_type(Some("application/json;q=0, */*")),
+            None
+        );
+        assert_eq!(
+            negotiate_content_type(Some(
+                "application/graphql-response+json;q=0.5, application/json;q=0, */*"
+            )),
+            Some(GRAPHQL_RESPONSE_CONTENT_TYPE)
+        );
+        assert_eq!(
+            negotiate_content_type(Some("application/json;q=invalid")),
+            None
+        );
+    }
 }
@@ -1,10 +1,58 @@
 use std::collections::{HashSet, LinkedList, VecDeque};
 
+use futures_util::{
+    future::{join_all, BoxFuture},
+    FutureExt,
+};
+
 use crate::{
-    CollectFields, Context, FieldResolver, GqlValue, ResolverResult, SelectionSetContext,
-    SelectionSetResolver,
+    incremental::resolve_early, CollectFields, Context, FieldResolver, GqlValue, ResolverRegistry,
+    ResolverResult, SelectionSetContext, SelectionSetResolver,
 };
 
+async fn resolve_list<'a, T: SelectionSetResolver + 'a>(
+    ctx: &SelectionSetContext<'_>,
+    values: impl Iterator<Item = &'a T> + Send,
+) -> ResolverResult<GqlValue> {
+    let initial_count = ctx
+        .stream_options()
+        .map(|stream| stream.initial_count)
+        .unwrap_or(usize::MAX);
+    let mut values = values.enumerate();
+    let mut result = Vec::new();
+    let mut deferred = Vec::new();
+    for (index, value) in values.by_ref().take(initial_count) {
+        let (v, rest) = resolve_item(ctx, index, value).await;
+        result.push(v?);
+        deferred.extend(rest);
+    }
+
+    // With `@stream`, the initial items are delivered before the rest of the items is resolved.
+    let rest: Vec<_> = values.collect();
+    if !rest.is_empty() && ctx.send_early_value(GqlValue::List(result.clone())).await {
+        join_all(deferred).await;
+        return Ok(ctx.stream_items(result, rest).await);
+    }
+    for (index, value) in rest {
+        let (v, rest) = resolve_item(ctx, index, value).await;
+        result.push(v?);
+        deferred.extend(rest);
+    }
+    Ok(ctx.resolve_deferred(GqlValue::List(result), deferred).await)
+}
+
+async fn resolve_item<'a, T: SelectionSetResolver + 'a>(
+    ctx: &'a SelectionSetContext<'_>,
+    index: usize,
+    value: &'a T,
+) -> (ResolverResult<GqlValue>, Option<BoxFuture<'a, ()>>) {
+    let ctx_item = ctx.with_list_index(index);
+    let (early_sender, early_value) = ctx_item.early_value_channel();
+    let ctx_item = ctx_item.with_early_value(early_sender);
+    let resolve_fut = async move { value.resolve_selection_set(&ctx_item).await }.boxed();
+    resolve_early(resolve_fut, early_value).await
+}
+
 #[async_trait::async_trait]
 impl<T: FieldResolver, const N: usize> FieldResolver for [T; N] {
     async fn resolve_field(&self, ctx: &Context<'_>) -> ResolverResult<Option<GqlValue>> {
@@ -20,6 +68,9 @@ impl<T: FieldResolver, const N: usize> FieldResolver for [T; N] {
     fn type_name() -> String {
         format!("[{}]!", T::type_name())
     }
+    fn register(registry: &mut ResolverRegistry) {
+        T::register(registry);
+    }
 }
 
 impl<T: FieldResolver, const N: usize> CollectFields for [T; N] {}
@@ -30,12 +81,7 @@ impl<T: SelectionSetResolver, const N: usize> SelectionSetResolver for [T; N] {
         &self,
         ctx: &SelectionSetContext<'_>,
     ) -> ResolverResult<GqlValue> {
-        let mut result = Vec::new();
-        for value in self {
-            let v = value.resolve_selection_set(ctx).await?;
-            result.push(v);
-        }
-        Ok(GqlValue::List(result))
+        resolve_list(ctx, self.iter()).await
     }
 }
 
@@ -53,6 +99,9 @@ impl<T: FieldResolver> FieldResolver for HashSet<T> {
     fn type_name() -> String {
         format!("[{}]!", T::type_name())
     }
+    fn register(registry: &mut ResolverRegistry) {
+        T::register(registry);
+    }
 }
 
 impl<T: FieldResolver> CollectFields for HashSet<T> {}
@@ -63,12 +112,7 @@ impl<T: SelectionSetResolver> SelectionSetResolver for HashSet<T> {
         &self,
         ctx: &SelectionSetContext<'_>,
     ) -> ResolverResult<GqlValue> {
-        let mut result = Vec::new();
-        for value in self {
-            let v = value.resolve_selection_set(ctx).await?;
-            result.push(v);
-        }
-        Ok(GqlValue::List(result))
+        resolve_list(ctx, self.iter()).await
     }
 }
 
@@ -86,6 +130,9 @@ impl<'a, T: FieldResolver + 'a> FieldResolver for &'a [T] {
     fn type_name() -> String {
         format!("[{}]!", T::type_name())
     }
+    fn register(registry: &mut ResolverRegistry) {
+        T::register(registry);
+    }
 }
 
 impl<'a, T: FieldResolver + 'a> CollectFields for &'a [T] {}
@@ -96,12 +143,7 @@ impl<'a, T: SelectionSetResolver + 'a> SelectionSetResolver for &'a [T] {
         &self,
         ctx: &SelectionSetContext<'_>,
     ) -> ResolverResult<GqlValue> {
-        let mut result = Vec::new();
-        for value in self.iter() {
-            let v = value.resolve_selection_set(ctx).await?;
-            result.push(v);
-        }
-        Ok(GqlValue::List(result))
+        resolve_list(ctx, self.iter()).await
     }
 }
 
@@ -119,6 +161,9 @@ impl<T: FieldResolver> FieldResolver for VecDeque<T> {
     fn type_name() -> String {
         format!("[{}]!", T::type_name())
     }
+    fn register(registry: &mut ResolverRegistry) {
+        T::register(registry);
+    }
 }
 
 impl<T: FieldResolver> CollectFields for VecDeque<T> {}
@@ -129,12 +174,7 @@ impl<T: SelectionSetResolver> SelectionSetResolver for VecDeque<T> {
         &self,
         ctx: &SelectionSetContext<'_>,
     ) -> ResolverResult<GqlValue> {
-        let mut result = Vec::new();
-        for value in self.iter() {
-            let v = value.resolve_selection_set(ctx).await?;
-            result.push(v);
-        }
-        Ok(GqlValue::List(result))
+        resolve_list(ctx, self.iter()).await
     }
 }
 
@@ -152,6 +192,9 @@ impl<T: FieldResolver> FieldResolver for LinkedList<T> {
     fn type_name() -> String {
         format!("[{}]!", T::type_name())
     }
+    fn register(registry: &mut ResolverRegistry) {
+        T::register(registry);
+    }
 }
 
 impl<T: FieldResolver> CollectFields for LinkedList<T> {}
@@ -162,12 +205,7 @@ impl<T: SelectionSetResolver> SelectionSetResolver for LinkedList<T> {
         &self,
         ctx: &SelectionSetContext<'_>,
     ) -> ResolverResult<GqlValue> {
-        let mut result = Vec::new();
-        for value in self.iter() {
-            let v = value.resolve_selection_set(ctx).await?;
-            result.push(v);
-        }
-        Ok(GqlValue::List(result))
+        resolve_list(ctx, self.iter()).await
     }
 }
 
@@ -185,6 +223,9 @@ impl<T: FieldResolver> FieldResolver for Vec<T> {
     fn type_name() -> String {
         format!("[{}]!", T::type_name())
     }
+    fn register(registry: &mut ResolverRegistry) {
+        T::register(registry);
+    }
 }
 
 impl<T: FieldResolver> CollectFields for Vec<T> {}
@@ -195,11 +236,6 @@ impl<T: SelectionSetResolver> SelectionSetResolver for Vec<T> {
         &self,
         ctx: &SelectionSetContext<'_>,
     ) -> ResolverResult<GqlValue> {
-        let mut result = Vec::new();
-        for value in self.iter() {
-            let v = value.resolve_selection_set(ctx).await?;
-            result.push(v);
-        }
-        Ok(GqlValue::List(result))
+        resolve_list(ctx, self.iter()).await
     }
 }
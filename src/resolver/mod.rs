@@ -4,6 +4,7 @@ mod list;
 mod number;
 mod object;
 mod optional;
+mod registry;
 mod string;
 
 use std::{collections::BTreeMap, sync::Arc};
@@ -14,10 +15,15 @@ use futures_util::{
     FutureExt,
 };
 use graphql_parser::query::{Selection, TypeCondition};
+pub(crate) use registry::check_resolvers;
+pub use registry::{FieldMetadata, ResolverRegistry};
 
 use crate::{
     context::{Context, SelectionSetContext},
-    GqlDirective, GqlError, GqlValue, ResolverResult, TypeDefinition,
+    custom_scalar::serialize_output_value,
+    incremental::{defer_fragment, is_deferred, resolve_early, stream_options, EarlyValue},
+    GqlDirective, GqlError, GqlErrorType, GqlValue, IntrospectionMode, ResolverResult,
+    TypeDefinition,
 };
 
 #[async_trait]
@@ -48,6 +54,9 @@ pub trait FieldResolver: Send + Sync {
     async fn resolve_field(&self, ctx: &Context<'_>) -> ResolverResult<Option<GqlValue>>;
 
     fn type_name() -> String;
+
+    /// Adds the fields of this type and the types it returns to the registry.
+    fn register(_registry: &mut ResolverRegistry) {}
 }
 
 #[async_trait::async_trait]
@@ -59,6 +68,9 @@ impl<T: FieldResolver> FieldResolver for &T {
     fn type_name() -> String {
         T::type_name()
     }
+    fn register(registry: &mut ResolverRegistry) {
+        T::register(registry);
+    }
 }
 
 impl<T: FieldResolver> CollectFields for &T {}
@@ -72,6 +84,9 @@ impl<T: FieldResolver> FieldResolver for Arc<T> {
     fn type_name() -> String {
         T::type_name()
     }
+    fn register(registry: &mut ResolverRegistry) {
+        T::register(registry);
+    }
 }
 
 impl<T: FieldResolver> CollectFields for Arc<T> {}
@@ -85,6 +100,9 @@ impl<T: FieldResolver> FieldResolver for Box<T> {
     fn type_name() -> String {
         T::type_name()
     }
+    fn register(registry: &mut ResolverRegistry) {
+        T::register(registry);
+    }
 }
 
 impl<T: FieldResolver> CollectFields for Box<T> {}
@@ -107,26 +125,9 @@ async fn resolve_selection<'a, 'ctx: 'a, T: CollectFields + SelectionSetResolver
     root_type: &'a T,
     parallel: bool,
 ) -> ResolverResult<GqlValue> {
-    let mut fields = Fields(Vec::new());
+    let mut fields = Fields::default();
     fields.collect_fields(ctx, root_type)?;
-
-    let res = if parallel {
-        try_join_all(fields.0).await?
-    } else {
-        let mut results = Vec::new();
-        for resolver in fields.0 {
-            results.push(resolver.await?);
-        }
-        results
-    };
-
-    let mut gql_obj_map = BTreeMap::new();
-
-    for value in res {
-        build_gql_object(&mut gql_obj_map, value);
-    }
-
-    Ok(GqlValue::Object(gql_obj_map))
+    fields.resolve(ctx, parallel).await
 }
 
 fn build_gql_object(target_obj: &mut BTreeMap<String, GqlValue>, gql_value: (String, GqlValue)) {
@@ -160,10 +161,62 @@ fn build_gql_object(target_obj: &mut BTreeMap<String, GqlValue>, gql_value: (Str
     }
 }
 
+fn serialize_field_value(ctx: &Context<'_>, value: GqlValue) -> ResolverResult<GqlValue> {
+    match ctx.field_definition {
+        Some(field_def) if !ctx.schema.custom_scalars.is_empty() => {
+            serialize_output_value(ctx.schema, &field_def.meta_type, value)
+                .map_err(|err| GqlError::new(err, Some(ctx.item.position)))
+        }
+        _ => Ok(value),
+    }
+}
+
 pub type ResolveFieldFuture<'a> = BoxFuture<'a, ResolverResult<(String, GqlValue)>>;
-pub struct Fields<'a>(Vec<ResolveFieldFuture<'a>>);
+type EarlyFieldValue<'a> = EarlyValue<'a, (String, GqlValue)>;
+
+#[derive(Default)]
+pub struct Fields<'a> {
+    fields: Vec<(ResolveFieldFuture<'a>, Option<EarlyFieldValue<'a>>)>,
+    deferred: Vec<BoxFuture<'a, ()>>,
+}
 
 impl<'a> Fields<'a> {
+    pub(crate) async fn resolve(
+        self,
+        ctx: &SelectionSetContext<'_>,
+        parallel: bool,
+    ) -> ResolverResult<GqlValue> {
+        let resolvers = self
+            .fields
+            .into_iter()
+            .map(|(resolve_fut, early_value)| async move {
+                let (result, rest) = resolve_early(resolve_fut, early_value).await;
+                result.map(|value| (value, rest))
+            });
+        let res = if parallel {
+            try_join_all(resolvers).await?
+        } else {
+            let mut results = Vec::new();
+            for resolver in resolvers {
+                results.push(resolver.await?);
+            }
+            results
+        };
+
+        let mut gql_obj_map = BTreeMap::new();
+        let mut deferred = Vec::new();
+
+        for (value, rest) in res {
+            build_gql_object(&mut gql_obj_map, value);
+            deferred.extend(rest);
+        }
+        deferred.extend(self.deferred);
+
+        Ok(ctx
+            .resolve_deferred(GqlValue::Object(gql_obj_map), deferred)
+            .await)
+    }
+
     pub fn collect_fields<'ctx: 'a, T: CollectFields + ?Sized>(
         &mut self,
         ctx: &SelectionSetContext<'ctx>,
@@ -176,22 +229,46 @@ impl<'a> Fields<'a> {
                         continue;
                     }
                     if field.name == "__typename" {
+                        if ctx.operation.introspection_mode == IntrospectionMode::Disabled {
+                            return Err(GqlError::new(
+                                "Introspection is disabled",
+                                Some(field.position),
+                            )
+                            .with_error_type(GqlErrorType::BadRequest));
+                        }
                         ctx.with_field(field);
                         let field_name = field.name.clone();
                         let type_name = root_type.introspection_type_name();
 
-                        self.0.push(Box::pin(async move {
-                            Ok((field_name, GqlValue::String(type_name)))
-                        }));
+                        self.fields.push((
+                            Box::pin(async move { Ok((field_name, GqlValue::String(type_name))) }),
+                            None,
+                        ));
                         continue;
                     }
 
-                    self.0.push(Box::pin({
+                    let (early_sender, early_value) = ctx.early_value_channel();
+                    let early_value = early_value.map(|early_value| {
+                        let field_name = field.name.clone();
+                        early_value
+                            .map(|value| {
+                                value.map(|(value, delivered)| ((field_name, value), delivered))
+                            })
+                            .boxed()
+                    });
+                    let resolver = Box::pin({
                         let ctx = ctx.clone();
                         async move {
-                            let ctx_field = &ctx.with_field(field);
-                            let field_name = ctx_field.item.name.clone();
                             let type_name = T::type_name();
+                            let field_definition = ctx
+                                .schema
+                                .type_definitions
+                                .get(&type_name)
+                                .and_then(|ty_def| ty_def.get_field_by_name(&field.name));
+                            let ctx_field = &ctx
+                                .with_field(field)
+                                .with_field_definition(field_definition);
+                            let field_name = ctx_field.item.name.clone();
                             let empty_vec = vec![];
 
                             let query_directives = &field.directives;
@@ -218,15 +295,24 @@ impl<'a> Fields<'a> {
                             if schema_ty_directives.is_empty()
                                 && schema_field_directives.is_empty()
                                 && schema_impl_interface_directives.is_empty()
-                                && query_directives.is_empty()
+                                && !query_directives.iter().any(|directive| {
+                                    ctx.schema
+                                        .custom_directives
+                                        .contains_key(directive.name.as_str())
+                                })
                             {
-                                Ok((
-                                    field_name,
-                                    root_type
-                                        .resolve_field(ctx_field)
-                                        .await?
-                                        .unwrap_or_default(),
-                                ))
+                                // Custom directives may change the value, so it is only sent
+                                // ahead of deferred fragments and streamed items without them.
+                                let stream = stream_options(ctx_field, query_directives)?;
+                                let ctx_field = &ctx_field
+                                    .clone()
+                                    .with_early_value(early_sender)
+                                    .with_stream(stream);
+                                let value = root_type
+                                    .resolve_field(ctx_field)
+                                    .await?
+                                    .unwrap_or_default();
+                                Ok((field_name, serialize_field_value(ctx_field, value)?))
                             } else {
                                 let mut resolve_fut = resolve_fut.boxed();
 
@@ -306,10 +392,12 @@ impl<'a> Fields<'a> {
                                         })
                                     }
                                 }
-                                Ok((field_name, resolve_fut.await?.unwrap_or_default()))
+                                let value = resolve_fut.await?.unwrap_or_default();
+                                Ok((field_name, serialize_field_value(ctx_field, value)?))
                             }
                         }
-                    }))
+                    });
+                    self.fields.push((resolver, early_value));
                 }
                 Selection::FragmentSpread(fragment_spread) => {
                     let operation_fragment = ctx
@@ -331,10 +419,16 @@ impl<'a> Fields<'a> {
                         &root_type.introspection_type_name(),
                         Some(&fragment_def.type_condition),
                     ) {
-                        root_type.collect_all_fields(
-                            &ctx.with_selection_set(&fragment_def.selection_set),
-                            self,
-                        )?;
+                        let ctx_fragment = ctx.with_selection_set(&fragment_def.selection_set);
+                        if is_deferred(ctx, &fragment_spread.directives) {
+                            self.deferred.push(defer_fragment(
+                                ctx_fragment,
+                                root_type,
+                                &fragment_spread.directives,
+                            ));
+                        } else {
+                            root_type.collect_all_fields(&ctx_fragment, self)?;
+                        }
                     }
                 }
                 Selection::InlineFragment(inline_fragment) => {
@@ -342,20 +436,28 @@ impl<'a> Fields<'a> {
                         continue;
                     }
 
-                    if is_fragment_condition(
+                    let ctx_fragment = ctx.with_selection_set(&inline_fragment.selection_set);
+                    if is_deferred(ctx, &inline_fragment.directives)
+                        && (inline_fragment.type_condition.is_none()
+                            || is_fragment_condition(
+                                ctx,
+                                &root_type.introspection_type_name(),
+                                inline_fragment.type_condition.as_ref(),
+                            ))
+                    {
+                        self.deferred.push(defer_fragment(
+                            ctx_fragment,
+                            root_type,
+                            &inline_fragment.directives,
+                        ));
+                    } else if is_fragment_condition(
                         ctx,
                         &root_type.introspection_type_name(),
                         inline_fragment.type_condition.as_ref(),
                     ) {
-                        root_type.collect_all_fields(
-                            &ctx.with_selection_set(&inline_fragment.selection_set),
-                            self,
-                        )?;
+                        root_type.collect_all_fields(&ctx_fragment, self)?;
                     } else if inline_fragment.type_condition.is_none() {
-                        self.collect_fields(
-                            &ctx.with_selection_set(&inline_fragment.selection_set),
-                            root_type,
-                        )?;
+                        self.collect_fields(&ctx_fragment, root_type)?;
                     }
                 }
             }
@@ -377,7 +479,7 @@ fn is_fragment_condition<'a, 'ctx: 'a>(
                 ctx.schema
                     .type_definitions
                     .get(type_name)
-                    .map_or(false, |ty_def| {
+                    .is_some_and(|ty_def| {
                         if let TypeDefinition::Object(obj) = ty_def {
                             obj.implements_interfaces.contains(on_type)
                         } else {
@@ -1,6 +1,6 @@
 use crate::{
-    CollectFields, Context, FieldResolver, GqlValue, ResolverResult, SelectionSetContext,
-    SelectionSetResolver,
+    CollectFields, Context, FieldResolver, GqlValue, ResolverRegistry, ResolverResult,
+    SelectionSetContext, SelectionSetResolver,
 };
 
 #[async_trait::async_trait]
@@ -14,6 +14,9 @@ impl<T: FieldResolver> FieldResolver for Option<T> {
     fn type_name() -> String {
         T::type_name()
     }
+    fn register(registry: &mut ResolverRegistry) {
+        T::register(registry);
+    }
 }
 
 impl<T: FieldResolver> CollectFields for Option<T> {}
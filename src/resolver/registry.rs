@@ -0,0 +1,204 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use crate::{FieldResolver, GqlError, Schema, TypeDefinition};
+
+/// A field of a `#[GqlType]` resolver, including the options set by `#[field(...)]`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldMetadata {
+    pub name: String,
+    pub arguments: Vec<String>,
+    pub is_deprecated: bool,
+    pub deprecation_reason: Option<String>,
+    pub complexity: Option<usize>,
+}
+
+/// Fields of the resolvers reachable from the root resolvers, keyed by type name.
+#[derive(Debug, Default)]
+pub struct ResolverRegistry {
+    types: BTreeMap<String, Vec<FieldMetadata>>,
+    unchecked_types: BTreeSet<String>,
+}
+
+impl ResolverRegistry {
+    pub fn register<T: FieldResolver + ?Sized>(&mut self) {
+        T::register(self);
+    }
+
+    pub fn contains_type(&self, type_name: &str) -> bool {
+        self.types.contains_key(type_name) || self.unchecked_types.contains(type_name)
+    }
+
+    pub fn add_type(&mut self, type_name: &str, fields: Vec<FieldMetadata>) {
+        self.types.insert(type_name.to_string(), fields);
+    }
+
+    /// A type resolved by a hand-written `FieldResolver`, whose fields are not checked.
+    pub fn add_unchecked_type(&mut self, type_name: &str) {
+        self.unchecked_types.insert(type_name.to_string());
+    }
+
+    pub fn fields(&self, type_name: &str) -> Option<&Vec<FieldMetadata>> {
+        self.types.get(type_name)
+    }
+
+    pub fn field(&self, type_name: &str, field_name: &str) -> Option<&FieldMetadata> {
+        self.fields(type_name)?
+            .iter()
+            .find(|field| field.name == field_name)
+    }
+
+    pub fn types(&self) -> impl Iterator<Item = (&String, &Vec<FieldMetadata>)> {
+        self.types.iter()
+    }
+}
+
+/// Reports schema types and fields without a resolver, and resolvers which are not in the schema.
+///
+/// Only the types reachable from the registered resolvers are checked. A type returned by a field
+/// with a hand-written resolver, such as a renamed `Connection`, is not checked.
+///
+/// `root_types` maps the type names of the root resolvers to the root type names of the schema,
+/// and `ignored_root_fields` are resolved by the container itself, such as `_entities`.
+pub(crate) fn check_resolvers(
+    schema: &Schema,
+    registry: &ResolverRegistry,
+    root_types: &[(String, &str)],
+    ignored_root_fields: &[&str],
+) -> Vec<GqlError> {
+    let resolver_type_name = |schema_type_name: &str| -> String {
+        root_types
+            .iter()
+            .find(|(_, root)| *root == schema_type_name)
+            .map_or(schema_type_name, |(name, _)| name)
+            .to_string()
+    };
+
+    // `true` if the type is reached through a field with a resolver.
+    let mut queue: Vec<(String, bool)> = root_types
+        .iter()
+        .map(|(_, root)| (root.to_string(), true))
+        .collect();
+    for (type_name, _) in registry.types() {
+        let schema_type_name = root_types
+            .iter()
+            .find(|(name, _)| name == type_name)
+            .map_or(type_name.as_str(), |(_, root)| root);
+        queue.push((schema_type_name.to_string(), true));
+    }
+
+    let mut checked_types = BTreeSet::new();
+    let mut missing_types = BTreeSet::new();
+    let mut visited = BTreeSet::new();
+    while let Some((schema_type_name, is_resolved)) = queue.pop() {
+        // Types starting with `_` are defined by the container, such as `_Service` of federation.
+        if schema_type_name.starts_with('_')
+            || !visited.insert((schema_type_name.clone(), is_resolved))
+        {
+            continue;
+        }
+        let type_name = resolver_type_name(&schema_type_name);
+        match schema.type_definitions.get(&schema_type_name) {
+            Some(TypeDefinition::Object(obj)) => match registry.fields(&type_name) {
+                Some(fields) => {
+                    let is_root = root_types.iter().any(|(_, root)| *root == schema_type_name);
+                    for schema_field in &obj.fields {
+                        let has_resolver = fields
+                            .iter()
+                            .any(|field| field.name == schema_field.name)
+                            || is_root && ignored_root_fields.contains(&schema_field.name.as_str());
+                        queue.push((schema_field.meta_type.name().to_string(), has_resolver));
+                    }
+                    checked_types.insert(schema_type_name);
+                }
+                None if registry.unchecked_types.contains(&type_name) || is_resolved => {}
+                None => {
+                    missing_types.insert(schema_type_name);
+                }
+            },
+            Some(TypeDefinition::Interface(interface)) => {
+                if registry.fields(&type_name).is_some() {
+                    checked_types.insert(schema_type_name.clone());
+                }
+                // Interfaces are resolved by the objects implementing them.
+                for def in schema.type_definitions.values() {
+                    if let TypeDefinition::Object(obj) = def {
+                        if obj.implements_interfaces.contains(&interface.name) {
+                            queue.push((obj.name.clone(), is_resolved));
+                        }
+                    }
+                }
+            }
+            Some(TypeDefinition::Union(union)) => {
+                for member in &union.types {
+                    queue.push((member.clone(), is_resolved));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    let mut errors = Vec::new();
+    let mut type_names: Vec<&String> = checked_types.iter().chain(&missing_types).collect();
+    type_names.sort();
+    for schema_type_name in type_names {
+        if missing_types.contains(schema_type_name) {
+            errors.push(GqlError::new(
+                format!("{} has no resolver.", schema_type_name),
+                None,
+            ));
+            continue;
+        }
+        let schema_fields = match &schema.type_definitions[schema_type_name] {
+            TypeDefinition::Object(obj) => &obj.fields,
+            TypeDefinition::Interface(interface) => &interface.fields,
+            _ => continue,
+        };
+        let type_name = resolver_type_name(schema_type_name);
+        let is_root = root_types.iter().any(|(_, root)| root == schema_type_name);
+        let fields = &registry.types[&type_name];
+
+        for schema_field in schema_fields {
+            let is_ignored = is_root && ignored_root_fields.contains(&schema_field.name.as_str());
+            if !is_ignored && !fields.iter().any(|field| field.name == schema_field.name) {
+                errors.push(GqlError::new(
+                    format!(
+                        "{}.{} has no resolver.",
+                        schema_type_name, schema_field.name
+                    ),
+                    Some(schema_field.position),
+                ));
+            }
+        }
+        for field in fields {
+            if !schema_fields
+                .iter()
+                .any(|schema_field| schema_field.name == field.name)
+            {
+                errors.push(GqlError::new(
+                    format!(
+                        "{}.{} is not defined in the schema.",
+                        schema_type_name, field.name
+                    ),
+                    None,
+                ));
+            }
+        }
+    }
+
+    for (type_name, _) in registry.types() {
+        let schema_type_name = root_types
+            .iter()
+            .find(|(name, _)| name == type_name)
+            .map_or(type_name.as_str(), |(_, root)| root);
+        if !matches!(
+            schema.type_definitions.get(schema_type_name),
+            Some(TypeDefinition::Object(_) | TypeDefinition::Interface(_))
+        ) {
+            errors.push(GqlError::new(
+                format!("{} is not defined in the schema.", schema_type_name),
+                None,
+            ));
+        }
+    }
+    errors
+}
@@ -1,7 +1,11 @@
-use http::HeaderMap;
+use http::{header, HeaderMap, HeaderValue, StatusCode};
 use serde::{Deserialize, Serialize};
 
-use crate::{error::GqlError, GqlValue};
+use crate::{
+    error::GqlError,
+    request::{GRAPHQL_RESPONSE_CONTENT_TYPE, JSON_CONTENT_TYPE},
+    GqlValue,
+};
 
 #[derive(Serialize, Deserialize, Default, Debug)]
 pub struct Response {
@@ -45,6 +49,86 @@ impl Response {
     pub fn is_error(&self) -> bool {
         !self.is_ok()
     }
+
+    fn content_type(&self) -> &str {
+        self.http_headers
+            .get(header::CONTENT_TYPE)
+            .map(String::as_str)
+            .unwrap_or(JSON_CONTENT_TYPE)
+    }
+
+    // With `application/graphql-response+json`, a response without data is a request error.
+    fn status_code(&self) -> StatusCode {
+        if self.content_type() == GRAPHQL_RESPONSE_CONTENT_TYPE
+            && matches!(self.data, GqlValue::Null)
+            && self.is_error()
+        {
+            StatusCode::BAD_REQUEST
+        } else {
+            StatusCode::OK
+        }
+    }
+}
+
+#[derive(Serialize, Debug)]
+#[serde(untagged)]
+pub enum BatchResponse {
+    Single(Response),
+    Batch(Vec<Response>),
+}
+
+impl BatchResponse {
+    pub fn is_ok(&self) -> bool {
+        match self {
+            BatchResponse::Single(response) => response.is_ok(),
+            BatchResponse::Batch(responses) => responses.iter().all(Response::is_ok),
+        }
+    }
+
+    pub fn is_error(&self) -> bool {
+        !self.is_ok()
+    }
+
+    pub fn into_http_response(self) -> http::Response<String> {
+        let (status, headers, content_type) = match &self {
+            BatchResponse::Single(response) => (
+                response.status_code(),
+                vec![&response.http_headers],
+                response.content_type(),
+            ),
+            BatchResponse::Batch(responses) => (
+                StatusCode::OK,
+                responses.iter().map(|res| &res.http_headers).collect(),
+                responses
+                    .first()
+                    .map(Response::content_type)
+                    .unwrap_or(JSON_CONTENT_TYPE),
+            ),
+        };
+
+        let mut http_response = http::Response::new(String::new());
+        *http_response.status_mut() = status;
+        for http_headers in headers {
+            for (name, value) in http_headers {
+                if let Ok(value) = HeaderValue::from_str(value) {
+                    http_response.headers_mut().append(name, value);
+                }
+            }
+        }
+        http_response.headers_mut().insert(
+            header::CONTENT_TYPE,
+            HeaderValue::from_str(content_type)
+                .unwrap_or(HeaderValue::from_static(JSON_CONTENT_TYPE)),
+        );
+        *http_response.body_mut() = serde_json::to_string(&self).unwrap_or_default();
+        http_response
+    }
+}
+
+impl From<Response> for BatchResponse {
+    fn from(response: Response) -> Self {
+        BatchResponse::Single(response)
+    }
 }
 
 #[cfg(test)]
@@ -53,7 +137,9 @@ mod tests {
 
     use serde_json::Number;
 
-    use crate::{GqlValue, Response};
+    use http::{header, StatusCode};
+
+    use crate::{BatchResponse, GqlError, GqlValue, Response, GRAPHQL_RESPONSE_CONTENT_TYPE};
 
     #[test]
     fn test_json_serialize() {
@@ -70,4 +156,33 @@ mod tests {
             r#"{"data":{"a":1,"b":2}}"#
         );
     }
+
+    #[test]
+    fn test_http_response() {
+        let res = Response::from_errors(vec![GqlError::new("invalid query", None)]);
+        let http_response = BatchResponse::Single(res).into_http_response();
+        assert_eq!(http_response.status(), StatusCode::OK);
+        assert_eq!(
+            http_response.headers().get(header::CONTENT_TYPE).unwrap(),
+            "application/json"
+        );
+
+        let mut res = Response::from_errors(vec![GqlError::new("invalid query", None)]);
+        res.http_headers.insert(
+            header::CONTENT_TYPE,
+            GRAPHQL_RESPONSE_CONTENT_TYPE.to_string(),
+        );
+        res.http_headers
+            .insert(header::CACHE_CONTROL, "no-cache".to_string());
+        let http_response = BatchResponse::Single(res).into_http_response();
+        assert_eq!(http_response.status(), StatusCode::BAD_REQUEST);
+        assert_eq!(
+            http_response.headers().get(header::CONTENT_TYPE).unwrap(),
+            GRAPHQL_RESPONSE_CONTENT_TYPE
+        );
+        assert_eq!(
+            http_response.headers().get(header::CACHE_CONTROL).unwrap(),
+            "no-cache"
+        );
+    }
 }
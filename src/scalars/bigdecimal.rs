@@ -0,0 +1,24 @@
+use std::str::FromStr;
+
+use bigdecimal::BigDecimal;
+
+use crate::{GqlInputType, GqlValue};
+
+use super::parse_string;
+
+// BigDecimal: serialized as a string to keep the precision, e.g. `"12.50"`. Numbers are also accepted as input.
+impl GqlInputType for BigDecimal {
+    fn from_gql_value(value: Option<GqlValue>) -> Result<Self, String> {
+        let value = match value {
+            Some(GqlValue::Number(n)) => Some(GqlValue::String(n.to_string())),
+            value => value,
+        };
+        parse_string(value, "BigDecimal", BigDecimal::from_str)
+    }
+
+    fn to_gql_value(&self) -> GqlValue {
+        GqlValue::String(self.to_string())
+    }
+}
+
+scalar_resolver!(BigDecimal, "BigDecimal");
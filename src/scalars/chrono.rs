@@ -0,0 +1,75 @@
+use chrono::{DateTime, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime, SecondsFormat, Utc};
+
+use crate::{GqlInputType, GqlValue};
+
+use super::parse_string;
+
+// DateTime: RFC 3339, e.g. `2022-01-31T12:00:00Z`
+impl GqlInputType for DateTime<Utc> {
+    fn from_gql_value(value: Option<GqlValue>) -> Result<Self, String> {
+        parse_string(value, "DateTime", |v| {
+            DateTime::parse_from_rfc3339(v).map(|datetime| datetime.with_timezone(&Utc))
+        })
+    }
+
+    fn to_gql_value(&self) -> GqlValue {
+        GqlValue::String(self.to_rfc3339_opts(SecondsFormat::AutoSi, true))
+    }
+}
+
+scalar_resolver!(DateTime<Utc>, "DateTime");
+
+impl GqlInputType for DateTime<FixedOffset> {
+    fn from_gql_value(value: Option<GqlValue>) -> Result<Self, String> {
+        parse_string(value, "DateTime", DateTime::parse_from_rfc3339)
+    }
+
+    fn to_gql_value(&self) -> GqlValue {
+        GqlValue::String(self.to_rfc3339_opts(SecondsFormat::AutoSi, false))
+    }
+}
+
+scalar_resolver!(DateTime<FixedOffset>, "DateTime");
+
+// Date: `2022-01-31`
+impl GqlInputType for NaiveDate {
+    fn from_gql_value(value: Option<GqlValue>) -> Result<Self, String> {
+        parse_string(value, "Date", |v| NaiveDate::parse_from_str(v, "%Y-%m-%d"))
+    }
+
+    fn to_gql_value(&self) -> GqlValue {
+        GqlValue::String(self.format("%Y-%m-%d").to_string())
+    }
+}
+
+scalar_resolver!(NaiveDate, "Date");
+
+// Time: `12:00:00` with optional fractional seconds
+impl GqlInputType for NaiveTime {
+    fn from_gql_value(value: Option<GqlValue>) -> Result<Self, String> {
+        parse_string(value, "Time", |v| {
+            NaiveTime::parse_from_str(v, "%H:%M:%S%.f")
+        })
+    }
+
+    fn to_gql_value(&self) -> GqlValue {
+        GqlValue::String(self.format("%H:%M:%S%.f").to_string())
+    }
+}
+
+scalar_resolver!(NaiveTime, "Time");
+
+// LocalDateTime: `2022-01-31T12:00:00` with optional fractional seconds
+impl GqlInputType for NaiveDateTime {
+    fn from_gql_value(value: Option<GqlValue>) -> Result<Self, String> {
+        parse_string(value, "LocalDateTime", |v| {
+            NaiveDateTime::parse_from_str(v, "%Y-%m-%dT%H:%M:%S%.f")
+        })
+    }
+
+    fn to_gql_value(&self) -> GqlValue {
+        GqlValue::String(self.format("%Y-%m-%dT%H:%M:%S%.f").to_string())
+    }
+}
+
+scalar_resolver!(NaiveDateTime, "LocalDateTime");
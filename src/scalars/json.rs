@@ -0,0 +1,109 @@
+use std::ops::{Deref, DerefMut};
+
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::Value;
+
+use crate::{
+    types::value::serialize_into_gql_value, CollectFields, Context, FieldResolver, GqlError,
+    GqlInputType, GqlValue, ResolverResult, SelectionSetContext, SelectionSetResolver,
+};
+
+// JSON: any JSON value, passed through as is.
+impl GqlInputType for Value {
+    fn from_gql_value(value: Option<GqlValue>) -> Result<Self, String> {
+        match value {
+            Some(value) => serde_json::to_value(value).map_err(|err| err.to_string()),
+            None => Ok(Value::Null),
+        }
+    }
+
+    fn to_gql_value(&self) -> GqlValue {
+        serialize_into_gql_value(self).unwrap_or_default()
+    }
+}
+
+scalar_resolver!(Value, "JSON");
+
+/// A `JSON` scalar (de)serialized from and into `T` with serde.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct Json<T>(pub T);
+
+impl<T> Json<T> {
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T> Deref for Json<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T> DerefMut for Json<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl<T> From<T> for Json<T> {
+    fn from(value: T) -> Self {
+        Json(value)
+    }
+}
+
+impl<T> Json<T>
+where
+    T: Serialize,
+{
+    fn serialize_value(&self) -> ResolverResult<GqlValue> {
+        serialize_into_gql_value(&self.0).map_err(|err| GqlError::new(err.to_string(), None))
+    }
+}
+
+impl<T> GqlInputType for Json<T>
+where
+    T: Serialize + DeserializeOwned + Send + Sync,
+{
+    fn from_gql_value(value: Option<GqlValue>) -> Result<Self, String> {
+        let value =
+            serde_json::to_value(value.unwrap_or_default()).map_err(|err| err.to_string())?;
+        serde_json::from_value(value)
+            .map(Json)
+            .map_err(|err| format!("Invalid value for JSON, {}", err))
+    }
+
+    fn to_gql_value(&self) -> GqlValue {
+        serialize_into_gql_value(&self.0).unwrap_or_default()
+    }
+}
+
+#[async_trait::async_trait]
+impl<T> FieldResolver for Json<T>
+where
+    T: Serialize + Send + Sync,
+{
+    async fn resolve_field(&self, _ctx: &Context<'_>) -> ResolverResult<Option<GqlValue>> {
+        self.serialize_value().map(Some)
+    }
+    fn type_name() -> String {
+        "JSON".to_string()
+    }
+}
+
+impl<T> CollectFields for Json<T> where T: Serialize + Send + Sync {}
+
+#[async_trait::async_trait]
+impl<T> SelectionSetResolver for Json<T>
+where
+    T: Serialize + Send + Sync,
+{
+    async fn resolve_selection_set(
+        &self,
+        _ctx: &SelectionSetContext<'_>,
+    ) -> ResolverResult<GqlValue> {
+        self.serialize_value()
+    }
+}
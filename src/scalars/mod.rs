@@ -0,0 +1,66 @@
+// Resolvers shared by the scalars behind cargo features. The value is resolved via `GqlInputType::to_gql_value`.
+#[allow(unused_macros)]
+macro_rules! scalar_resolver {
+    ($ty:ty, $name:literal) => {
+        #[async_trait::async_trait]
+        impl crate::FieldResolver for $ty {
+            async fn resolve_field(
+                &self,
+                _ctx: &crate::Context<'_>,
+            ) -> crate::ResolverResult<Option<crate::GqlValue>> {
+                Ok(Some(crate::GqlInputType::to_gql_value(self)))
+            }
+            fn type_name() -> String {
+                $name.to_string()
+            }
+        }
+
+        impl crate::CollectFields for $ty {}
+
+        #[async_trait::async_trait]
+        impl crate::SelectionSetResolver for $ty {
+            async fn resolve_selection_set(
+                &self,
+                _ctx: &crate::SelectionSetContext<'_>,
+            ) -> crate::ResolverResult<crate::GqlValue> {
+                Ok(crate::GqlInputType::to_gql_value(self))
+            }
+        }
+    };
+}
+
+#[cfg(feature = "bigdecimal")]
+mod bigdecimal;
+#[cfg(feature = "chrono")]
+mod chrono;
+#[cfg(feature = "json")]
+mod json;
+
+#[cfg(feature = "json")]
+pub use json::Json;
+#[cfg(feature = "rust_decimal")]
+mod rust_decimal;
+#[cfg(feature = "time")]
+mod time;
+#[cfg(feature = "url")]
+mod url;
+#[cfg(feature = "uuid")]
+mod uuid;
+
+#[allow(dead_code)]
+fn parse_string<T, E: std::fmt::Display>(
+    value: Option<crate::GqlValue>,
+    type_name: &str,
+    parse: impl FnOnce(&str) -> Result<T, E>,
+) -> Result<T, String> {
+    match value {
+        Some(crate::GqlValue::String(v)) => {
+            parse(&v).map_err(|err| format!("{}: invalid value for {}, {}", v, type_name, err))
+        }
+        Some(invalid_value) => Err(format!(
+            "{}: invalid gql value for {}",
+            invalid_value, type_name
+        )),
+        None => Err(format!("Expected type: {}, but not found", type_name)),
+    }
+}
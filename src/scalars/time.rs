@@ -0,0 +1,69 @@
+use time::{
+    format_description::{well_known::Rfc3339, FormatItem},
+    macros::format_description,
+    Date, OffsetDateTime, PrimitiveDateTime, Time,
+};
+
+use crate::{GqlInputType, GqlValue};
+
+use super::parse_string;
+
+const DATE_FORMAT: &[FormatItem<'_>] = format_description!("[year]-[month]-[day]");
+const TIME_FORMAT: &[FormatItem<'_>] =
+    format_description!("[hour]:[minute]:[second][optional [.[subsecond]]]");
+const LOCAL_DATE_TIME_FORMAT: &[FormatItem<'_>] =
+    format_description!("[year]-[month]-[day]T[hour]:[minute]:[second][optional [.[subsecond]]]");
+
+// DateTime: RFC 3339, e.g. `2022-01-31T12:00:00Z`
+impl GqlInputType for OffsetDateTime {
+    fn from_gql_value(value: Option<GqlValue>) -> Result<Self, String> {
+        parse_string(value, "DateTime", |v| OffsetDateTime::parse(v, &Rfc3339))
+    }
+
+    fn to_gql_value(&self) -> GqlValue {
+        GqlValue::String(self.format(&Rfc3339).unwrap_or_default())
+    }
+}
+
+scalar_resolver!(OffsetDateTime, "DateTime");
+
+// Date: `2022-01-31`
+impl GqlInputType for Date {
+    fn from_gql_value(value: Option<GqlValue>) -> Result<Self, String> {
+        parse_string(value, "Date", |v| Date::parse(v, DATE_FORMAT))
+    }
+
+    fn to_gql_value(&self) -> GqlValue {
+        GqlValue::String(self.format(DATE_FORMAT).unwrap_or_default())
+    }
+}
+
+scalar_resolver!(Date, "Date");
+
+// Time: `12:00:00` with optional fractional seconds
+impl GqlInputType for Time {
+    fn from_gql_value(value: Option<GqlValue>) -> Result<Self, String> {
+        parse_string(value, "Time", |v| Time::parse(v, TIME_FORMAT))
+    }
+
+    fn to_gql_value(&self) -> GqlValue {
+        GqlValue::String(self.format(TIME_FORMAT).unwrap_or_default())
+    }
+}
+
+scalar_resolver!(Time, "Time");
+
+// LocalDateTime: `2022-01-31T12:00:00` with optional fractional seconds
+impl GqlInputType for PrimitiveDateTime {
+    fn from_gql_value(value: Option<GqlValue>) -> Result<Self, String> {
+        parse_string(value, "LocalDateTime", |v| {
+            PrimitiveDateTime::parse(v, LOCAL_DATE_TIME_FORMAT)
+        })
+    }
+
+    fn to_gql_value(&self) -> GqlValue {
+        GqlValue::String(self.format(LOCAL_DATE_TIME_FORMAT).unwrap_or_default())
+    }
+}
+
+scalar_resolver!(PrimitiveDateTime, "LocalDateTime");
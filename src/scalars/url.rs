@@ -0,0 +1,18 @@
+use url::Url;
+
+use crate::{GqlInputType, GqlValue};
+
+use super::parse_string;
+
+// Url: absolute URL string, e.g. `https://example.com/path`
+impl GqlInputType for Url {
+    fn from_gql_value(value: Option<GqlValue>) -> Result<Self, String> {
+        parse_string(value, "Url", Url::parse)
+    }
+
+    fn to_gql_value(&self) -> GqlValue {
+        GqlValue::String(self.to_string())
+    }
+}
+
+scalar_resolver!(Url, "Url");
@@ -0,0 +1,18 @@
+use uuid::Uuid;
+
+use crate::{GqlInputType, GqlValue};
+
+use super::parse_string;
+
+// UUID: hyphenated lowercase, e.g. `67e55044-10b1-426f-9247-bb680e5fe0c8`
+impl GqlInputType for Uuid {
+    fn from_gql_value(value: Option<GqlValue>) -> Result<Self, String> {
+        parse_string(value, "UUID", Uuid::parse_str)
+    }
+
+    fn to_gql_value(&self) -> GqlValue {
+        GqlValue::String(self.hyphenated().to_string())
+    }
+}
+
+scalar_resolver!(Uuid, "UUID");
@@ -0,0 +1,109 @@
+use std::{
+    convert::Infallible,
+    future::Future,
+    io,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use bytes::Buf;
+use futures_util::{stream, TryStreamExt};
+use http_body::Body;
+use tower_service::Service;
+
+use crate::{
+    execute_batch, receive_batch_http_request, Container, MultipartOptions, SelectionSetResolver,
+};
+
+pub struct GraphQLService<Query, Mutation, Subscription>
+where
+    Query: SelectionSetResolver,
+    Mutation: SelectionSetResolver,
+    Subscription: SelectionSetResolver,
+{
+    container: Container<Query, Mutation, Subscription>,
+    multipart_options: MultipartOptions,
+}
+
+impl<Query, Mutation, Subscription> GraphQLService<Query, Mutation, Subscription>
+where
+    Query: SelectionSetResolver + 'static,
+    Mutation: SelectionSetResolver + 'static,
+    Subscription: SelectionSetResolver + 'static,
+{
+    pub fn new(container: Container<Query, Mutation, Subscription>) -> Self {
+        GraphQLService {
+            container,
+            multipart_options: Default::default(),
+        }
+    }
+
+    pub fn multipart_options(mut self, options: MultipartOptions) -> Self {
+        self.multipart_options = options;
+        self
+    }
+}
+
+impl<Query, Mutation, Subscription> Clone for GraphQLService<Query, Mutation, Subscription>
+where
+    Query: SelectionSetResolver,
+    Mutation: SelectionSetResolver,
+    Subscription: SelectionSetResolver,
+{
+    fn clone(&self) -> Self {
+        GraphQLService {
+            container: self.container.clone(),
+            multipart_options: self.multipart_options,
+        }
+    }
+}
+
+impl<Query, Mutation, Subscription, B> Service<http::Request<B>>
+    for GraphQLService<Query, Mutation, Subscription>
+where
+    Query: SelectionSetResolver + 'static,
+    Mutation: SelectionSetResolver + 'static,
+    Subscription: SelectionSetResolver + 'static,
+    B: Body + Send + 'static,
+    B::Data: Send,
+    B::Error: std::fmt::Display,
+{
+    type Response = http::Response<String>;
+    type Error = Infallible;
+    type Future = Pin<Box<dyn Future<Output = Result<Self::Response, Self::Error>> + Send>>;
+
+    fn poll_ready(&mut self, _: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, req: http::Request<B>) -> Self::Future {
+        let container = self.container.clone();
+        let multipart_options = self.multipart_options;
+
+        Box::pin(async move {
+            let (parts, body) = req.into_parts();
+            let body_stream = stream::unfold(Box::pin(body), |mut body| async move {
+                let chunk = body.data().await?;
+                let chunk = chunk
+                    .map(|mut data| data.copy_to_bytes(data.remaining()))
+                    .map_err(|err| io::Error::other(err.to_string()));
+                Some((chunk, body))
+            });
+
+            let request = receive_batch_http_request(
+                &parts.method,
+                &parts.headers,
+                parts.uri.query(),
+                body_stream.into_async_read(),
+                multipart_options,
+            )
+            .await;
+            match request {
+                Ok(request) => Ok(execute_batch(&container, request)
+                    .await
+                    .into_http_response()),
+                Err(error) => Ok(error.into_http_response()),
+            }
+        })
+    }
+}
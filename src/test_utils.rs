@@ -14,6 +14,8 @@ pub fn build_test_request(
         operation_name,
         variables,
         extensions: Default::default(),
+        uploads: Default::default(),
+        http_headers: Default::default(),
     }
 }
 
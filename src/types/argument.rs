@@ -1,6 +1,10 @@
 use graphql_parser::{schema::InputValue, Pos};
 
-use super::{directive::GqlDirective, value::GqlValue, value_type::GqlValueType};
+use super::{
+    directive::{deprecation_reason, GqlDirective},
+    value::GqlValue,
+    value_type::GqlValueType,
+};
 
 #[derive(Debug, Clone)]
 pub struct InputValueType {
@@ -19,6 +23,14 @@ impl InputValueType {
             .map(InputValueType::from)
             .collect()
     }
+
+    pub fn is_deprecated(&self) -> bool {
+        self.directives.iter().any(|dir| dir.name == "deprecated")
+    }
+
+    pub fn deprecation_reason(&self) -> Option<String> {
+        deprecation_reason(&self.directives)
+    }
 }
 
 impl<'a> From<InputValue<'a, String>> for InputValueType {
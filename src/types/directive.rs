@@ -22,6 +22,18 @@ impl GqlDirective {
     }
 }
 
+pub(crate) const DEFAULT_DEPRECATION_REASON: &str = "No longer supported";
+
+pub(crate) fn deprecation_reason(directives: &[GqlDirective]) -> Option<String> {
+    directives
+        .iter()
+        .find(|dir| dir.name == "deprecated")
+        .map(|dir| match dir.arguments.get("reason") {
+            Some(GqlValue::String(reason)) => reason.clone(),
+            _ => DEFAULT_DEPRECATION_REASON.to_string(),
+        })
+}
+
 impl<'a> From<Directive<'a, String>> for GqlDirective {
     fn from(directive: Directive<'a, String>) -> Self {
         let mut arguments = BTreeMap::new();
@@ -44,6 +56,7 @@ pub struct DirectiveDefinition {
     pub description: Option<String>,
     pub arguments: Vec<InputValueType>,
     pub locations: Vec<DirectiveLocation>,
+    pub is_repeatable: bool,
 }
 
 impl DirectiveDefinition {
@@ -67,6 +80,7 @@ impl DirectiveDefinition {
                 DirectiveLocation::FragmentSpread,
                 DirectiveLocation::InlineFragment,
             ],
+            is_repeatable: false,
         }
     }
 
@@ -90,6 +104,7 @@ impl DirectiveDefinition {
                 DirectiveLocation::FragmentSpread,
                 DirectiveLocation::InlineFragment,
             ],
+            is_repeatable: false,
         }
     }
 
@@ -103,13 +118,120 @@ impl DirectiveDefinition {
                 description: None,
                 position: Pos::default(),
                 meta_type: GqlValueType::NamedType("String".to_string()),
-                default_value: None,
+                default_value: Some(GqlValue::String(DEFAULT_DEPRECATION_REASON.to_string())),
                 directives: Default::default(),
             }],
             locations: vec![
                 DirectiveLocation::FieldDefinition,
+                DirectiveLocation::ArgumentDefinition,
+                DirectiveLocation::InputFieldDefinition,
                 DirectiveLocation::EnumValue,
             ],
+            is_repeatable: false,
+        }
+    }
+
+    pub fn defer_directive() -> Self {
+        DirectiveDefinition {
+            position: Pos::default(),
+            name: "defer".to_string(),
+            description: None,
+            arguments: vec![
+                InputValueType {
+                    name: "if".to_string(),
+                    description: None,
+                    position: Pos::default(),
+                    meta_type: GqlValueType::NonNullType(Box::new(GqlValueType::NamedType(
+                        "Boolean".to_string(),
+                    ))),
+                    default_value: Some(GqlValue::Boolean(true)),
+                    directives: Default::default(),
+                },
+                InputValueType {
+                    name: "label".to_string(),
+                    description: None,
+                    position: Pos::default(),
+                    meta_type: GqlValueType::NamedType("String".to_string()),
+                    default_value: None,
+                    directives: Default::default(),
+                },
+            ],
+            locations: vec![
+                DirectiveLocation::FragmentSpread,
+                DirectiveLocation::InlineFragment,
+            ],
+            is_repeatable: false,
+        }
+    }
+
+    pub fn stream_directive() -> Self {
+        DirectiveDefinition {
+            position: Pos::default(),
+            name: "stream".to_string(),
+            description: None,
+            arguments: vec![
+                InputValueType {
+                    name: "if".to_string(),
+                    description: None,
+                    position: Pos::default(),
+                    meta_type: GqlValueType::NonNullType(Box::new(GqlValueType::NamedType(
+                        "Boolean".to_string(),
+                    ))),
+                    default_value: Some(GqlValue::Boolean(true)),
+                    directives: Default::default(),
+                },
+                InputValueType {
+                    name: "label".to_string(),
+                    description: None,
+                    position: Pos::default(),
+                    meta_type: GqlValueType::NamedType("String".to_string()),
+                    default_value: None,
+                    directives: Default::default(),
+                },
+                InputValueType {
+                    name: "initialCount".to_string(),
+                    description: None,
+                    position: Pos::default(),
+                    meta_type: GqlValueType::NonNullType(Box::new(GqlValueType::NamedType(
+                        "Int".to_string(),
+                    ))),
+                    default_value: Some(GqlValue::Number(0.into())),
+                    directives: Default::default(),
+                },
+            ],
+            locations: vec![DirectiveLocation::Field],
+            is_repeatable: false,
+        }
+    }
+
+    pub fn one_of_directive() -> Self {
+        DirectiveDefinition {
+            position: Pos::default(),
+            name: "oneOf".to_string(),
+            description: None,
+            arguments: vec![],
+            locations: vec![DirectiveLocation::InputObject],
+            is_repeatable: false,
+        }
+    }
+
+    pub fn specified_by_directive() -> Self {
+        DirectiveDefinition {
+            position: Pos::default(),
+            name: "specifiedBy".to_string(),
+            description: None,
+            arguments: vec![InputValueType {
+                name: "url".to_string(),
+                description: None,
+                position: Pos::default(),
+                meta_type: GqlValueType::NonNullType(Box::new(GqlValueType::NamedType(
+                    "String".to_string(),
+                ))),
+                default_value: None,
+                directives: Default::default(),
+            }],
+            locations: vec![DirectiveLocation::Scalar],
+            is_repeatable: false,
         }
     }
 }
@@ -1,4 +1,4 @@
-use super::directive::GqlDirective;
+use super::directive::{deprecation_reason, GqlDirective};
 use graphql_parser::{
     schema::{EnumType as ParserEnumType, EnumValue},
     Pos,
@@ -77,4 +77,8 @@ impl EnumTypeValue {
         }
         false
     }
+
+    pub fn deprecation_reason(&self) -> Option<String> {
+        deprecation_reason(&self.directives)
+    }
 }
@@ -2,7 +2,7 @@ use graphql_parser::schema::Field;
 use graphql_parser::Pos;
 
 use super::argument::InputValueType;
-use super::directive::GqlDirective;
+use super::directive::{deprecation_reason, GqlDirective};
 use super::value_type::GqlValueType;
 
 #[derive(Debug, Clone)]
@@ -29,6 +29,10 @@ impl FieldType {
         }
         false
     }
+
+    pub fn deprecation_reason(&self) -> Option<String> {
+        deprecation_reason(&self.directives)
+    }
 }
 
 impl<'a> From<Field<'a, String>> for FieldType {
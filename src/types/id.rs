@@ -20,6 +20,24 @@ impl DerefMut for ID {
     }
 }
 
+impl ID {
+    pub fn global_id(type_name: &str, local_id: impl std::fmt::Display) -> ID {
+        ID(base64::encode(format!("{}:{}", type_name, local_id)))
+    }
+
+    pub fn decode_global_id(&self) -> Result<(String, String), String> {
+        let invalid_id = || format!("{} is invalid global id", self.0);
+        let decoded = base64::decode(&self.0).map_err(|_| invalid_id())?;
+        let decoded = String::from_utf8(decoded).map_err(|_| invalid_id())?;
+        match decoded.split_once(':') {
+            Some((type_name, local_id)) if !type_name.is_empty() => {
+                Ok((type_name.to_string(), local_id.to_string()))
+            }
+            _ => Err(invalid_id()),
+        }
+    }
+}
+
 impl<T: Into<String>> From<T> for ID {
     fn from(v: T) -> Self {
         ID(v.into())
@@ -41,3 +59,18 @@ macro_rules! try_from_integers {
 }
 
 try_from_integers!(i8, i16, i32, i64, i128, u8, u16, u32, u64, u128, isize, usize);
+
+#[cfg(test)]
+mod tests {
+    use super::ID;
+
+    #[test]
+    fn test_global_id() {
+        let id = ID::global_id("User", 10);
+        assert_eq!(
+            id.decode_global_id(),
+            Ok(("User".to_string(), "10".to_string()))
+        );
+        assert!(ID::from("invalid").decode_global_id().is_err());
+    }
+}
@@ -34,3 +34,11 @@ impl<'a> From<ParserInputObjectType<'a, String>> for InputObjectType {
         }
     }
 }
+
+impl InputObjectType {
+    pub fn is_one_of(&self) -> bool {
+        self.directives
+            .iter()
+            .any(|directive| directive.name == "oneOf")
+    }
+}
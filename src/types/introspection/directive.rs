@@ -5,7 +5,10 @@ use crate::{
     FieldResolver, GqlValue, ResolverResult, Schema, SelectionSetContext, SelectionSetResolver,
 };
 
-use super::input_value::{__InputValue, build_input_value_introspection};
+use super::{
+    input_value::{__InputValue, build_input_value_introspection},
+    introspection_type::include_deprecated,
+};
 
 pub(crate) struct __Directive<'a> {
     pub schema: &'a Schema,
@@ -21,6 +24,7 @@ fn dir_location_str(location: &DirectiveLocation) -> String {
         DirectiveLocation::FragmentDefinition => "FRAGMENT_DEFINITION".to_string(),
         DirectiveLocation::FragmentSpread => "FRAGMENT_SPREAD".to_string(),
         DirectiveLocation::InlineFragment => "INLINE_FRAGMENT".to_string(),
+        DirectiveLocation::VariableDefinition => "VARIABLE_DEFINITION".to_string(),
         DirectiveLocation::Schema => "SCHEMA".to_string(),
         DirectiveLocation::Scalar => "SCALAR".to_string(),
         DirectiveLocation::Object => "OBJECT".to_string(),
@@ -54,15 +58,22 @@ impl<'a> __Directive<'a> {
         &self.detail.locations
     }
 
-    async fn args(&'a self) -> Vec<__InputValue<'a>> {
+    async fn args(&'a self, include_deprecated: bool) -> Vec<__InputValue<'a>> {
         let mut result = Vec::new();
 
         for arg in &self.detail.arguments {
+            if !include_deprecated && arg.is_deprecated() {
+                continue;
+            }
             let value = build_input_value_introspection(self.schema, arg);
             result.push(value);
         }
         result
     }
+
+    async fn is_repeatable(&self) -> bool {
+        self.detail.is_repeatable
+    }
 }
 
 #[async_trait::async_trait]
@@ -102,7 +113,7 @@ impl<'a> FieldResolver for __Directive<'a> {
         }
 
         if ctx.item.name == "args" {
-            let args = self.args().await;
+            let args = self.args(include_deprecated(ctx)?).await;
             let ctx_selection_set = ctx.with_selection_set(&ctx.item.selection_set);
 
             return SelectionSetResolver::resolve_selection_set(&args, &ctx_selection_set)
@@ -110,6 +121,15 @@ impl<'a> FieldResolver for __Directive<'a> {
                 .map(Some);
         }
 
+        if ctx.item.name == "isRepeatable" {
+            let is_repeatable = self.is_repeatable().await;
+            let ctx_selection_set = ctx.with_selection_set(&ctx.item.selection_set);
+
+            return SelectionSetResolver::resolve_selection_set(&is_repeatable, &ctx_selection_set)
+                .await
+                .map(Some);
+        }
+
         Ok(None)
     }
     fn type_name() -> String {
@@ -26,4 +26,8 @@ impl __EnumValue {
     async fn isDeprecated(&self) -> bool {
         self.detail.is_deprecated()
     }
+
+    async fn deprecationReason(&self) -> Option<String> {
+        self.detail.deprecation_reason()
+    }
 }
@@ -5,7 +5,7 @@ use crate::{
 
 use super::{
     input_value::{__InputValue, build_input_value_introspection},
-    introspection_type::__Type,
+    introspection_type::{__Type, include_deprecated},
 };
 
 pub(crate) struct __Field<'a> {
@@ -29,10 +29,13 @@ impl<'a> __Field<'a> {
         self.detail.description.as_deref()
     }
 
-    async fn args(&'a self) -> Vec<__InputValue<'a>> {
+    async fn args(&'a self, include_deprecated: bool) -> Vec<__InputValue<'a>> {
         let mut result = Vec::new();
 
         for arg in &self.detail.arguments {
+            if !include_deprecated && arg.is_deprecated() {
+                continue;
+            }
             let value = build_input_value_introspection(self.schema, arg);
             result.push(value);
         }
@@ -46,6 +49,10 @@ impl<'a> __Field<'a> {
     async fn is_deprecated(&self) -> bool {
         self.detail.is_deprecated()
     }
+
+    async fn deprecation_reason(&self) -> Option<String> {
+        self.detail.deprecation_reason()
+    }
 }
 
 #[async_trait::async_trait]
@@ -78,7 +85,7 @@ impl<'a> FieldResolver for __Field<'a> {
         }
 
         if ctx.item.name == "args" {
-            let args = self.args().await;
+            let args = self.args(include_deprecated(ctx)?).await;
             let ctx_selection_set = ctx.with_selection_set(&ctx.item.selection_set);
 
             return SelectionSetResolver::resolve_selection_set(&args, &ctx_selection_set)
@@ -103,6 +110,15 @@ impl<'a> FieldResolver for __Field<'a> {
                 .await
                 .map(Some);
         }
+
+        if ctx.item.name == "deprecationReason" {
+            let reason = self.deprecation_reason().await;
+            let ctx_selection_set = ctx.with_selection_set(&ctx.item.selection_set);
+
+            return SelectionSetResolver::resolve_selection_set(&reason, &ctx_selection_set)
+                .await
+                .map(Some);
+        }
         Ok(None)
     }
     fn type_name() -> String {
@@ -35,6 +35,14 @@ impl<'a> __InputValue<'a> {
     async fn default_value(&self) -> Option<String> {
         self.detail.default_value.as_ref().map(|v| v.to_string())
     }
+
+    async fn is_deprecated(&self) -> bool {
+        self.detail.is_deprecated()
+    }
+
+    async fn deprecation_reason(&self) -> Option<String> {
+        self.detail.deprecation_reason()
+    }
 }
 
 #[async_trait::async_trait]
@@ -76,13 +84,31 @@ impl<'a> FieldResolver for __InputValue<'a> {
         }
 
         if ctx.item.name == "defaultValue" {
-            let is_deprecated = self.default_value().await;
+            let default_value = self.default_value().await;
+            let ctx_selection_set = ctx.with_selection_set(&ctx.item.selection_set);
+
+            return SelectionSetResolver::resolve_selection_set(&default_value, &ctx_selection_set)
+                .await
+                .map(Some);
+        }
+
+        if ctx.item.name == "isDeprecated" {
+            let is_deprecated = self.is_deprecated().await;
             let ctx_selection_set = ctx.with_selection_set(&ctx.item.selection_set);
 
             return SelectionSetResolver::resolve_selection_set(&is_deprecated, &ctx_selection_set)
                 .await
                 .map(Some);
         }
+
+        if ctx.item.name == "deprecationReason" {
+            let reason = self.deprecation_reason().await;
+            let ctx_selection_set = ctx.with_selection_set(&ctx.item.selection_set);
+
+            return SelectionSetResolver::resolve_selection_set(&reason, &ctx_selection_set)
+                .await
+                .map(Some);
+        }
         Ok(None)
     }
     fn type_name() -> String {
@@ -26,16 +26,20 @@ pub fn introspection_sdl() -> &'static str {
         enumValues(includeDeprecated: Boolean = false): [__EnumValue!]
 
         # INPUT_OBJECT only
-        inputFields: [__InputValue!]
+        inputFields(includeDeprecated: Boolean = false): [__InputValue!]
+        isOneOf: Boolean
 
         # NON_NULL and LIST only
         ofType: __Type
+
+        # SCALAR only
+        specifiedByURL: String
     }
 
     type __Field {
         name: String!
         description: String
-        args: [__InputValue!]!
+        args(includeDeprecated: Boolean = false): [__InputValue!]!
         type: __Type!
         isDeprecated: Boolean!
         deprecationReason: String
@@ -46,6 +50,8 @@ pub fn introspection_sdl() -> &'static str {
         description: String
         type: __Type!
         defaultValue: String
+        isDeprecated: Boolean!
+        deprecationReason: String
     }
 
     type __EnumValue {
@@ -70,7 +76,8 @@ pub fn introspection_sdl() -> &'static str {
         name: String!
         description: String
         locations: [__DirectiveLocation!]!
-        args: [__InputValue!]!
+        args(includeDeprecated: Boolean = false): [__InputValue!]!
+        isRepeatable: Boolean!
     }
 
     enum __DirectiveLocation {
@@ -81,6 +88,7 @@ pub fn introspection_sdl() -> &'static str {
         FRAGMENT_DEFINITION
         FRAGMENT_SPREAD
         INLINE_FRAGMENT
+        VARIABLE_DEFINITION
         SCHEMA
         SCALAR
         OBJECT
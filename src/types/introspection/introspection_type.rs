@@ -11,8 +11,8 @@ use super::{
 
 enum TypeDetail<'a> {
     Named(&'a TypeDefinition),
-    NonNull(&'a str),
-    List(&'a str),
+    NonNull(&'a GqlValueType),
+    List(&'a GqlValueType),
 }
 
 pub struct __Type<'a> {
@@ -32,17 +32,17 @@ pub(crate) enum __TypeKind {
     NonNull,
 }
 
-impl ToString for __TypeKind {
-    fn to_string(&self) -> String {
+impl std::fmt::Display for __TypeKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            __TypeKind::Scalar => "SCALAR".to_string(),
-            __TypeKind::Object => "OBJECT".to_string(),
-            __TypeKind::Interface => "INTERFACE".to_string(),
-            __TypeKind::Union => "UNION".to_string(),
-            __TypeKind::Enum => "ENUM".to_string(),
-            __TypeKind::InputObject => "INPUT_OBJECT".to_string(),
-            __TypeKind::List => "LIST".to_string(),
-            __TypeKind::NonNull => "NON_NULL".to_string(),
+            __TypeKind::Scalar => write!(f, "SCALAR"),
+            __TypeKind::Object => write!(f, "OBJECT"),
+            __TypeKind::Interface => write!(f, "INTERFACE"),
+            __TypeKind::Union => write!(f, "UNION"),
+            __TypeKind::Enum => write!(f, "ENUM"),
+            __TypeKind::InputObject => write!(f, "INPUT_OBJECT"),
+            __TypeKind::List => write!(f, "LIST"),
+            __TypeKind::NonNull => write!(f, "NON_NULL"),
         }
     }
 }
@@ -64,8 +64,8 @@ impl<'a> __Type<'a> {
                     None => panic!("Unknown type: '{}'", named),
                 }
             }
-            GqlValueType::ListType(list) => TypeDetail::List(list.name()),
-            GqlValueType::NonNullType(non_null) => TypeDetail::NonNull(non_null.name()),
+            GqlValueType::ListType(list) => TypeDetail::List(list),
+            GqlValueType::NonNullType(non_null) => TypeDetail::NonNull(non_null),
         };
         __Type { schema, detail }
     }
@@ -101,12 +101,13 @@ impl<'a> __Type<'a> {
         }
     }
 
-    async fn fields(&self) -> Option<Vec<__Field<'a>>> {
+    async fn fields(&self, include_deprecated: bool) -> Option<Vec<__Field<'a>>> {
         if let TypeDetail::Named(def) = self.detail {
             match def.fields() {
                 Some(fields) => {
                     let result = fields
                         .iter()
+                        .filter(|field| include_deprecated || !field.is_deprecated())
                         .map(|field| __Field::new(self.schema, field.clone()))
                         .collect();
                     Some(result)
@@ -169,10 +170,13 @@ impl<'a> __Type<'a> {
         }
     }
 
-    async fn enum_values(&self) -> Option<Vec<__EnumValue>> {
+    async fn enum_values(&self, include_deprecated: bool) -> Option<Vec<__EnumValue>> {
         if let TypeDetail::Named(TypeDefinition::Enum(enu)) = &self.detail {
             let mut values = Vec::new();
             for v in &enu.values {
+                if !include_deprecated && v.is_deprecated() {
+                    continue;
+                }
                 let value = build_enum_value_introspection(v);
                 values.push(value);
             }
@@ -182,10 +186,13 @@ impl<'a> __Type<'a> {
         }
     }
 
-    async fn input_fields(&self) -> Option<Vec<__InputValue<'a>>> {
+    async fn input_fields(&self, include_deprecated: bool) -> Option<Vec<__InputValue<'a>>> {
         if let TypeDetail::Named(TypeDefinition::InputObject(input_obj)) = &self.detail {
             let mut values = Vec::new();
             for v in &input_obj.fields {
+                if !include_deprecated && v.is_deprecated() {
+                    continue;
+                }
                 let value = build_input_value_introspection(self.schema, v);
                 values.push(value);
             }
@@ -195,22 +202,27 @@ impl<'a> __Type<'a> {
         }
     }
 
+    async fn specified_by_url(&self) -> Option<&'a str> {
+        if let TypeDetail::Named(TypeDefinition::Scalar(scalar)) = &self.detail {
+            scalar.specified_by_url()
+        } else {
+            None
+        }
+    }
+
+    async fn is_one_of(&self) -> Option<bool> {
+        if let TypeDetail::Named(TypeDefinition::InputObject(input_obj)) = &self.detail {
+            Some(input_obj.is_one_of())
+        } else {
+            None
+        }
+    }
+
     async fn of_type(&self) -> Option<__Type<'a>> {
         match self.detail {
             TypeDetail::Named(_) => None,
-            TypeDetail::NonNull(type_name) => {
-                let type_def = self.schema.type_definitions.get(type_name);
-                match type_def {
-                    Some(def) => Some(__Type::from_type_definition(self.schema, def)),
-                    None => panic!("Unknown type: '{}'", type_name),
-                }
-            }
-            TypeDetail::List(type_name) => {
-                let type_def = self.schema.type_definitions.get(type_name);
-                match type_def {
-                    Some(def) => Some(__Type::from_type_definition(self.schema, def)),
-                    None => panic!("Unknown type: '{}'", type_name),
-                }
+            TypeDetail::NonNull(of_type) | TypeDetail::List(of_type) => {
+                Some(__Type::from_value_type(self.schema, of_type))
             }
         }
     }
@@ -263,7 +275,7 @@ impl<'a> FieldResolver for __Type<'a> {
         }
 
         if ctx.item.name == "fields" {
-            let fields = self.fields().await;
+            let fields = self.fields(include_deprecated(ctx)?).await;
             let ctx_selection_set = ctx.with_selection_set(&ctx.item.selection_set);
 
             match fields {
@@ -302,7 +314,7 @@ impl<'a> FieldResolver for __Type<'a> {
             }
         }
         if ctx.item.name == "enumValues" {
-            let values = self.enum_values().await;
+            let values = self.enum_values(include_deprecated(ctx)?).await;
             let ctx_selection_set = ctx.with_selection_set(&ctx.item.selection_set);
 
             match values {
@@ -315,7 +327,7 @@ impl<'a> FieldResolver for __Type<'a> {
             }
         }
         if ctx.item.name == "inputFields" {
-            let values = self.input_fields().await;
+            let values = self.input_fields(include_deprecated(ctx)?).await;
             let ctx_selection_set = ctx.with_selection_set(&ctx.item.selection_set);
 
             match values {
@@ -327,6 +339,32 @@ impl<'a> FieldResolver for __Type<'a> {
                 None => return Ok(None),
             }
         }
+        if ctx.item.name == "specifiedByURL" {
+            let url = self.specified_by_url().await;
+            let ctx_selection_set = ctx.with_selection_set(&ctx.item.selection_set);
+
+            match url {
+                Some(v) => {
+                    return SelectionSetResolver::resolve_selection_set(v, &ctx_selection_set)
+                        .await
+                        .map(Some);
+                }
+                None => return Ok(None),
+            }
+        }
+        if ctx.item.name == "isOneOf" {
+            let is_one_of = self.is_one_of().await;
+            let ctx_selection_set = ctx.with_selection_set(&ctx.item.selection_set);
+
+            match is_one_of {
+                Some(v) => {
+                    return SelectionSetResolver::resolve_selection_set(&v, &ctx_selection_set)
+                        .await
+                        .map(Some);
+                }
+                None => return Ok(None),
+            }
+        }
         if ctx.item.name == "ofType" {
             let ty = self.of_type().await;
             let ctx_selection_set = ctx.with_selection_set(&ctx.item.selection_set);
@@ -358,3 +396,9 @@ impl<'a> SelectionSetResolver for __Type<'a> {
         resolve_selection_parallelly(ctx, self).await
     }
 }
+
+pub(crate) fn include_deprecated(ctx: &Context<'_>) -> ResolverResult<bool> {
+    Ok(ctx
+        .get_arg_value::<Option<bool>>("includeDeprecated")?
+        .unwrap_or_default())
+}
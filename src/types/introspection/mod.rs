@@ -7,4 +7,4 @@ mod introspection_type;
 mod schema;
 pub use introspection_sdl::introspection_sdl;
 pub use introspection_type::__Type;
-pub use schema::{__Schema, build_schema_introspection};
+pub use schema::build_schema_introspection;
@@ -8,6 +8,8 @@ mod interface;
 mod introspection;
 mod object;
 mod scalar;
+mod schema_check;
+mod schema_diff;
 mod type_definition;
 mod union_type;
 pub mod value;
@@ -17,11 +19,12 @@ pub mod schema;
 pub use argument::InputValueType;
 pub use field::FieldType;
 pub use id::ID;
-pub use introspection::__Schema;
 pub use introspection::__Type;
 pub use introspection::build_schema_introspection;
 pub use scalar::ScalarType;
 pub use schema::Schema;
+pub use schema_check::check_schema;
+pub use schema_diff::{diff_schema, SchemaChange, SchemaChangeLevel};
 pub use type_definition::TypeDefinition;
 pub use value::{GqlConstValue, GqlValue};
 pub use value_type::GqlValueType;
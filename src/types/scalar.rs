@@ -3,7 +3,7 @@ use graphql_parser::{
     Pos,
 };
 
-use super::directive::GqlDirective;
+use super::{directive::GqlDirective, value::GqlValue};
 
 #[derive(Debug, Clone)]
 pub struct ScalarType {
@@ -27,11 +27,15 @@ impl<'a> From<ParserScalarType<'a, String>> for ScalarType {
 
 impl ScalarType {
     pub fn is_valid_value(&self, value: &Value<'_, String>) -> bool {
+        // Literals of custom scalars without a registered CustomScalar are not checked.
+        if !self.is_builtin() {
+            return true;
+        }
         match value {
             Value::Variable(_) => false,
-            Value::Int(_) => self.name == *"Int",
+            Value::Int(_) => matches!(self.name.as_str(), "Int" | "Float" | "ID"),
             Value::Float(_) => self.name == *"Float",
-            Value::String(_) => self.name == *"String",
+            Value::String(_) => matches!(self.name.as_str(), "String" | "ID"),
             Value::Boolean(_) => self.name == *"Boolean",
             Value::Null => true,
             Value::Enum(_) => false,
@@ -40,6 +44,23 @@ impl ScalarType {
         }
     }
 
+    pub fn is_builtin(&self) -> bool {
+        matches!(
+            self.name.as_str(),
+            "String" | "Int" | "Float" | "Boolean" | "ID"
+        )
+    }
+
+    pub fn specified_by_url(&self) -> Option<&str> {
+        self.directives
+            .iter()
+            .find(|directive| directive.name == "specifiedBy")
+            .and_then(|directive| match directive.arguments.get("url") {
+                Some(GqlValue::String(url)) => Some(url.as_str()),
+                _ => None,
+            })
+    }
+
     pub fn string_scalar() -> Self {
         ScalarType {
             name: "String".to_string(),
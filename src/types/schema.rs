@@ -1,10 +1,14 @@
-use std::{collections::HashMap, ops::Deref, sync::Arc};
+use std::{
+    collections::{BTreeMap, HashMap},
+    ops::Deref,
+    sync::Arc,
+};
 
 use graphql_parser::schema::TypeDefinition as ParserTypeDefinition;
 
 use crate::{
-    error::GqlError, CustomDirective, EnumType, GqlDirective, InputObjectType, InterfaceType,
-    ObjectType, UnionType,
+    error::GqlError, CustomDirective, CustomScalar, EnumType, GqlDirective, GqlValue,
+    InputObjectType, InterfaceType, ObjectType, ResolverRegistry, UnionType,
 };
 
 use super::{
@@ -24,6 +28,7 @@ pub struct SchemaInner {
     pub mutation_type_name: String,
     pub subscription_type_name: String,
     pub custom_directives: HashMap<&'static str, Box<dyn CustomDirective>>,
+    pub custom_scalars: HashMap<String, Box<dyn CustomScalar>>,
 }
 
 pub struct Schema(Arc<SchemaInner>);
@@ -32,6 +37,77 @@ impl Schema {
     pub fn new(schema: SchemaInner) -> Self {
         Schema(Arc::new(schema))
     }
+
+    // Only possible before the schema is shared.
+    pub(crate) fn set_custom_scalars(
+        &mut self,
+        custom_scalars: HashMap<String, Box<dyn CustomScalar>>,
+    ) -> Result<(), GqlError> {
+        self.get_mut()?.custom_scalars = custom_scalars;
+        Ok(())
+    }
+
+    fn get_mut(&mut self) -> Result<&mut SchemaInner, GqlError> {
+        Arc::get_mut(&mut self.0)
+            .ok_or_else(|| GqlError::new("Schema cannot be modified after it is shared.", None))
+    }
+
+    // Marks the fields deprecated by `#[field(deprecation)]`, only possible before the schema is shared.
+    pub(crate) fn set_field_deprecations(
+        &mut self,
+        registry: &ResolverRegistry,
+    ) -> Result<(), GqlError> {
+        let schema = self.get_mut()?;
+        for (type_name, fields) in registry.types() {
+            for field in fields.iter().filter(|field| field.is_deprecated) {
+                let reason = field.deprecation_reason.as_ref();
+                let root_fields = if type_name == &schema.query_type_name {
+                    Some(&mut schema.queries)
+                } else if type_name == &schema.mutation_type_name {
+                    Some(&mut schema.mutations)
+                } else if type_name == &schema.subscription_type_name {
+                    Some(&mut schema.subscriptions)
+                } else {
+                    None
+                };
+                if let Some(field_type) = root_fields.and_then(|fields| fields.get_mut(&field.name))
+                {
+                    deprecate_field(field_type, reason);
+                }
+                let type_fields = match schema.type_definitions.get_mut(type_name) {
+                    Some(TypeDefinition::Object(obj)) => Some(&mut obj.fields),
+                    Some(TypeDefinition::Interface(interface)) => Some(&mut interface.fields),
+                    _ => None,
+                };
+                let interface_fields = schema
+                    .interfaces
+                    .get_mut(type_name)
+                    .map(|interface| &mut interface.fields);
+                for field_types in type_fields.into_iter().chain(interface_fields) {
+                    if let Some(field_type) = field_types.iter_mut().find(|f| f.name == field.name)
+                    {
+                        deprecate_field(field_type, reason);
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+fn deprecate_field(field: &mut FieldType, reason: Option<&String>) {
+    if field.directives.iter().any(|dir| dir.name == "deprecated") {
+        return;
+    }
+    let mut arguments = BTreeMap::new();
+    if let Some(reason) = reason {
+        arguments.insert("reason".to_string(), GqlValue::String(reason.clone()));
+    }
+    field.directives.push(GqlDirective {
+        position: field.position,
+        name: "deprecated".to_string(),
+        arguments,
+    });
 }
 
 impl Deref for Schema {
@@ -85,13 +161,18 @@ pub fn build_schema(
         "deprecated".to_string(),
         DirectiveDefinition::deprecated_directive(),
     );
+    directives.insert("defer".to_string(), DirectiveDefinition::defer_directive());
+    directives.insert(
+        "stream".to_string(),
+        DirectiveDefinition::stream_directive(),
+    );
 
     let mut definitions = schema_documents.to_vec();
     definitions.push(introspection_sdl());
 
     for doc in definitions {
-        let parsed_schema =
-            graphql_parser::parse_schema::<String>(doc).expect("failed to parse graphql schema");
+        let parsed_schema = graphql_parser::parse_schema::<String>(doc)
+            .map_err(|err| GqlError::new(err.to_string(), None))?;
         for node in parsed_schema.definitions {
             match node {
                 graphql_parser::schema::Definition::SchemaDefinition(schema_def) => {
@@ -119,6 +200,7 @@ pub fn build_schema(
                         description: directive.description,
                         arguments,
                         locations: directive.locations,
+                        is_repeatable: directive.repeatable,
                     };
                     directives.insert(result.name.to_string(), result);
                 }
@@ -342,6 +424,9 @@ pub fn build_schema(
         }
     }
 
+    check_deprecated_input_values(&type_definitions)?;
+    check_one_of_input_objects(&type_definitions)?;
+
     let mut query_type_name = "Query".to_string();
     let mut mutation_type_name = "Mutation".to_string();
     let mut subscription_type_name = "Subscription".to_string();
@@ -396,29 +481,143 @@ pub fn build_schema(
         subscription_type_name,
         interfaces,
         custom_directives,
+        custom_scalars: Default::default(),
     })))
 }
 
+// @deprecated is not allowed on required arguments and input fields.
+fn check_deprecated_input_values(
+    type_definitions: &HashMap<String, TypeDefinition>,
+) -> Result<(), GqlError> {
+    let is_required_deprecated = |value: &InputValueType| {
+        value.meta_type.is_non_null() && value.default_value.is_none() && value.is_deprecated()
+    };
+
+    for def in type_definitions.values() {
+        for field in def.fields().into_iter().flatten() {
+            if let Some(arg) = field
+                .arguments
+                .iter()
+                .find(|arg| is_required_deprecated(arg))
+            {
+                return Err(GqlError::new(
+                    format!(
+                        "Required argument {}.{}({}:) cannot be deprecated.",
+                        def.name(),
+                        field.name,
+                        arg.name
+                    ),
+                    Some(arg.position),
+                ));
+            }
+        }
+        if let TypeDefinition::InputObject(input_obj) = def {
+            if let Some(field) = input_obj
+                .fields
+                .iter()
+                .find(|field| is_required_deprecated(field))
+            {
+                return Err(GqlError::new(
+                    format!(
+                        "Required input field {}.{} cannot be deprecated.",
+                        input_obj.name, field.name
+                    ),
+                    Some(field.position),
+                ));
+            }
+        }
+    }
+    Ok(())
+}
+
+// All fields of a @oneOf input object must be nullable and have no default value.
+fn check_one_of_input_objects(
+    type_definitions: &HashMap<String, TypeDefinition>,
+) -> Result<(), GqlError> {
+    for def in type_definitions.values() {
+        if let TypeDefinition::InputObject(input_obj) = def {
+            if !input_obj.is_one_of() {
+                continue;
+            }
+            for field in &input_obj.fields {
+                if field.meta_type.is_non_null() {
+                    return Err(GqlError::new(
+                        format!(
+                            "Field {}.{} of OneOf input object must be nullable.",
+                            input_obj.name, field.name
+                        ),
+                        Some(field.position),
+                    ));
+                }
+                if field.default_value.is_some() {
+                    return Err(GqlError::new(
+                        format!(
+                            "Field {}.{} of OneOf input object cannot have a default value.",
+                            input_obj.name, field.name
+                        ),
+                        Some(field.position),
+                    ));
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use std::fs;
 
     use super::build_schema;
+    use crate::ResolverRegistry;
 
     #[test]
     fn it_works() {
         let contents = fs::read_to_string("tests/schemas/github.graphql");
-        let schema = build_schema(&vec![contents.unwrap().as_str()], Default::default()).unwrap();
+        let schema = build_schema(&[contents.unwrap().as_str()], Default::default()).unwrap();
 
-        assert!(schema.queries.get("repository").is_some());
-        assert!(schema.type_definitions.get("AddCommentInput").is_some());
+        assert!(schema.queries.contains_key("repository"));
+        assert!(schema.type_definitions.contains_key("AddCommentInput"));
 
         let base = fs::read_to_string("tests/schemas/test_schema.graphql").unwrap();
         let extend = fs::read_to_string("tests/schemas/extend.graphql").unwrap();
-        let schema =
-            build_schema(&vec![base.as_str(), extend.as_str()], Default::default()).unwrap();
+        let schema = build_schema(&[base.as_str(), extend.as_str()], Default::default()).unwrap();
+
+        assert!(schema.queries.contains_key("pets"));
+        assert!(schema.queries.contains_key("authors"));
+    }
 
-        assert!(schema.queries.get("pets").is_some());
-        assert!(schema.queries.get("authors").is_some());
+    #[test]
+    fn test_set_field_deprecations_after_shared() {
+        let mut schema = build_schema(&["type Query { a: Int }"], Default::default()).unwrap();
+        assert!(schema
+            .set_field_deprecations(&ResolverRegistry::default())
+            .is_ok());
+
+        let _shared = schema.0.clone();
+        assert!(schema
+            .set_field_deprecations(&ResolverRegistry::default())
+            .is_err());
+    }
+
+    #[test]
+    fn test_one_of_input_object_definition() {
+        let schema = |input: &str| {
+            let doc = format!("type Query {{ a(by: UserBy): Int }} {}", input);
+            build_schema(&[doc.as_str()], Default::default()).map(|_| ())
+        };
+        assert!(schema("input UserBy @oneOf { id: ID email: String }").is_ok());
+
+        let err = schema("input UserBy @oneOf { id: ID! email: String }").unwrap_err();
+        assert_eq!(
+            err.message,
+            "Field UserBy.id of OneOf input object must be nullable."
+        );
+
+        let err = schema(r#"input UserBy @oneOf { id: ID email: String = "a" }"#).unwrap_err();
+        assert_eq!(
+            err.message,
+            "Field UserBy.email of OneOf input object cannot have a default value."
+        );
     }
 }
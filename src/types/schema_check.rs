@@ -0,0 +1,185 @@
+use crate::{GqlDirective, GqlError, InputValueType, Schema, TypeDefinition};
+
+/// Checks the type references, interface implementations, union members and directives of a schema.
+pub fn check_schema(schema: &Schema) -> Vec<GqlError> {
+    let mut errors = Vec::new();
+    let mut type_names: Vec<&String> = schema.type_definitions.keys().collect();
+    type_names.sort();
+
+    for type_name in type_names {
+        let type_def = &schema.type_definitions[type_name];
+        check_directives(schema, type_def.directives(), &mut errors);
+
+        for field in type_def.fields().into_iter().flatten() {
+            let field_type_name = field.meta_type.name();
+            match schema.type_definitions.get(field_type_name) {
+                Some(TypeDefinition::InputObject(_)) => errors.push(GqlError::new(
+                    format!(
+                        "{}.{} must be an output type, but {} is an input object.",
+                        type_name, field.name, field_type_name
+                    ),
+                    Some(field.position),
+                )),
+                Some(_) => {}
+                None => errors.push(GqlError::new(
+                    format!(
+                        "Unknown type {} of {}.{}.",
+                        field_type_name, type_name, field.name
+                    ),
+                    Some(field.position),
+                )),
+            }
+            check_directives(schema, &field.directives, &mut errors);
+            for arg in &field.arguments {
+                let owner = format!("{}.{}({}:)", type_name, field.name, arg.name);
+                check_input_value(schema, &owner, arg, &mut errors);
+            }
+        }
+
+        match type_def {
+            TypeDefinition::Object(obj) => {
+                for interface_name in &obj.implements_interfaces {
+                    let interface = match schema.type_definitions.get(interface_name) {
+                        Some(TypeDefinition::Interface(interface)) => interface,
+                        _ => {
+                            errors.push(GqlError::new(
+                                format!(
+                                    "{} implements {}, but it is not an interface.",
+                                    obj.name, interface_name
+                                ),
+                                Some(obj.position),
+                            ));
+                            continue;
+                        }
+                    };
+                    for interface_field in &interface.fields {
+                        if !obj.fields.iter().any(|f| f.name == interface_field.name) {
+                            errors.push(GqlError::new(
+                                format!(
+                                    "{} must have the field {} of the interface {}.",
+                                    obj.name, interface_field.name, interface_name
+                                ),
+                                Some(obj.position),
+                            ));
+                        }
+                    }
+                }
+            }
+            TypeDefinition::Union(union_type) => {
+                for member in &union_type.types {
+                    if !matches!(
+                        schema.type_definitions.get(member),
+                        Some(TypeDefinition::Object(_))
+                    ) {
+                        errors.push(GqlError::new(
+                            format!(
+                                "Member {} of the union {} must be an object type.",
+                                member, union_type.name
+                            ),
+                            Some(union_type.position),
+                        ));
+                    }
+                }
+            }
+            TypeDefinition::Enum(enum_type) => {
+                for value in &enum_type.values {
+                    check_directives(schema, &value.directives, &mut errors);
+                }
+            }
+            TypeDefinition::InputObject(input_obj) => {
+                for field in &input_obj.fields {
+                    let owner = format!("{}.{}", input_obj.name, field.name);
+                    check_input_value(schema, &owner, field, &mut errors);
+                }
+            }
+            TypeDefinition::Scalar(_) | TypeDefinition::Interface(_) => {}
+        }
+    }
+    errors
+}
+
+fn check_input_value(
+    schema: &Schema,
+    owner: &str,
+    value: &InputValueType,
+    errors: &mut Vec<GqlError>,
+) {
+    let value_type_name = value.meta_type.name();
+    match schema.type_definitions.get(value_type_name) {
+        Some(type_def) if !type_def.is_input_type() => errors.push(GqlError::new(
+            format!(
+                "{} must be an input type, but {} is not.",
+                owner, value_type_name
+            ),
+            Some(value.position),
+        )),
+        Some(_) => {}
+        None => errors.push(GqlError::new(
+            format!("Unknown type {} of {}.", value_type_name, owner),
+            Some(value.position),
+        )),
+    }
+    check_directives(schema, &value.directives, errors);
+}
+
+fn check_directives(schema: &Schema, directives: &[GqlDirective], errors: &mut Vec<GqlError>) {
+    for directive in directives {
+        if !schema.directives.contains_key(&directive.name) {
+            errors.push(GqlError::new(
+                format!("Unknown directive @{}.", directive.name),
+                Some(directive.position),
+            ));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::build_schema;
+
+    use super::check_schema;
+
+    #[test]
+    fn test_check_schema() {
+        let schema_doc = r#"
+        type Query {
+          user(filter: UserFilter, role: User): User
+          posts: [Post!]! @cached
+        }
+        type User implements Node {
+          name: String!
+        }
+        interface Node {
+          id: ID!
+        }
+        input UserFilter {
+          name: String
+        }
+        union SearchResult = User | UserFilter
+        "#;
+        let schema = build_schema(&[schema_doc], Default::default()).unwrap();
+        let messages: Vec<String> = check_schema(&schema)
+            .into_iter()
+            .map(|err| err.message)
+            .collect();
+        assert_eq!(
+            messages,
+            vec![
+                "Query.user(role:) must be an input type, but User is not.",
+                "Unknown type Post of Query.posts.",
+                "Unknown directive @cached.",
+                "Member UserFilter of the union SearchResult must be an object type.",
+                "User must have the field id of the interface Node.",
+            ]
+        );
+
+        let schema = build_schema(
+            &[std::fs::read_to_string("tests/schemas/starwars.graphql")
+                .unwrap()
+                .as_str()],
+            Default::default(),
+        )
+        .unwrap();
+        assert!(check_schema(&schema).is_empty());
+    }
+}
@@ -0,0 +1,661 @@
+use std::collections::HashMap;
+
+use crate::{
+    DirectiveDefinition, EnumType, FieldType, GqlValueType, InputObjectType, InputValueType,
+    Schema, TypeDefinition, UnionType,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum SchemaChangeLevel {
+    Breaking,
+    Dangerous,
+    Safe,
+}
+
+impl std::fmt::Display for SchemaChangeLevel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SchemaChangeLevel::Breaking => write!(f, "BREAKING"),
+            SchemaChangeLevel::Dangerous => write!(f, "DANGEROUS"),
+            SchemaChangeLevel::Safe => write!(f, "SAFE"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SchemaChange {
+    pub level: SchemaChangeLevel,
+    pub path: String,
+    pub message: String,
+}
+
+impl SchemaChange {
+    fn new(level: SchemaChangeLevel, path: impl Into<String>, message: impl Into<String>) -> Self {
+        SchemaChange {
+            level,
+            path: path.into(),
+            message: message.into(),
+        }
+    }
+
+    pub fn is_breaking(&self) -> bool {
+        self.level == SchemaChangeLevel::Breaking
+    }
+}
+
+pub fn diff_schema(old_schema: &Schema, new_schema: &Schema) -> Vec<SchemaChange> {
+    let mut changes = Vec::new();
+
+    diff_root_type_names(old_schema, new_schema, &mut changes);
+
+    for (name, old_def) in &old_schema.type_definitions {
+        if name.starts_with("__") {
+            continue;
+        }
+        match new_schema.type_definitions.get(name) {
+            Some(new_def) => diff_type_definition(old_def, new_def, &mut changes),
+            None => changes.push(SchemaChange::new(
+                SchemaChangeLevel::Breaking,
+                name,
+                format!("Type {} was removed", name),
+            )),
+        }
+    }
+
+    for name in new_schema.type_definitions.keys() {
+        if name.starts_with("__") || old_schema.type_definitions.contains_key(name) {
+            continue;
+        }
+        changes.push(SchemaChange::new(
+            SchemaChangeLevel::Safe,
+            name,
+            format!("Type {} was added", name),
+        ));
+    }
+
+    diff_directives(&old_schema.directives, &new_schema.directives, &mut changes);
+
+    changes.sort_by(|a, b| a.level.cmp(&b.level).then_with(|| a.path.cmp(&b.path)));
+    changes
+}
+
+fn diff_root_type_names(old_schema: &Schema, new_schema: &Schema, changes: &mut Vec<SchemaChange>) {
+    let root_names = [
+        (
+            "query",
+            &old_schema.query_type_name,
+            &new_schema.query_type_name,
+        ),
+        (
+            "mutation",
+            &old_schema.mutation_type_name,
+            &new_schema.mutation_type_name,
+        ),
+        (
+            "subscription",
+            &old_schema.subscription_type_name,
+            &new_schema.subscription_type_name,
+        ),
+    ];
+
+    for (operation, old_name, new_name) in root_names {
+        if old_name != new_name {
+            changes.push(SchemaChange::new(
+                SchemaChangeLevel::Breaking,
+                "schema",
+                format!(
+                    "Root {} type changed from {} to {}",
+                    operation, old_name, new_name
+                ),
+            ));
+        }
+    }
+}
+
+fn diff_type_definition(
+    old_def: &TypeDefinition,
+    new_def: &TypeDefinition,
+    changes: &mut Vec<SchemaChange>,
+) {
+    match (old_def, new_def) {
+        (TypeDefinition::Scalar(_), TypeDefinition::Scalar(_)) => {}
+        (TypeDefinition::Object(old_obj), TypeDefinition::Object(new_obj)) => {
+            diff_fields(&old_obj.name, &old_obj.fields, &new_obj.fields, changes);
+            diff_implements_interfaces(
+                &old_obj.name,
+                &old_obj.implements_interfaces,
+                &new_obj.implements_interfaces,
+                changes,
+            );
+        }
+        (TypeDefinition::Interface(old_interface), TypeDefinition::Interface(new_interface)) => {
+            diff_fields(
+                &old_interface.name,
+                &old_interface.fields,
+                &new_interface.fields,
+                changes,
+            );
+        }
+        (TypeDefinition::Union(old_union), TypeDefinition::Union(new_union)) => {
+            diff_union(old_union, new_union, changes);
+        }
+        (TypeDefinition::Enum(old_enum), TypeDefinition::Enum(new_enum)) => {
+            diff_enum(old_enum, new_enum, changes);
+        }
+        (TypeDefinition::InputObject(old_input), TypeDefinition::InputObject(new_input)) => {
+            diff_input_object(old_input, new_input, changes);
+        }
+        _ => changes.push(SchemaChange::new(
+            SchemaChangeLevel::Breaking,
+            old_def.name(),
+            format!(
+                "Type {} changed from {} to {}",
+                old_def.name(),
+                old_def,
+                new_def
+            ),
+        )),
+    }
+}
+
+fn diff_fields(
+    type_name: &str,
+    old_fields: &[FieldType],
+    new_fields: &[FieldType],
+    changes: &mut Vec<SchemaChange>,
+) {
+    for old_field in old_fields {
+        let path = format!("{}.{}", type_name, old_field.name);
+        let new_field = match new_fields.iter().find(|f| f.name == old_field.name) {
+            Some(f) => f,
+            None => {
+                changes.push(SchemaChange::new(
+                    SchemaChangeLevel::Breaking,
+                    &path,
+                    format!("Field {} was removed", path),
+                ));
+                continue;
+            }
+        };
+
+        if old_field.meta_type != new_field.meta_type {
+            let level = if is_safe_output_type_change(&old_field.meta_type, &new_field.meta_type) {
+                SchemaChangeLevel::Safe
+            } else {
+                SchemaChangeLevel::Breaking
+            };
+            changes.push(SchemaChange::new(
+                level,
+                &path,
+                format!(
+                    "Field {} changed type from {} to {}",
+                    path, old_field.meta_type, new_field.meta_type
+                ),
+            ));
+        }
+
+        if !old_field.is_deprecated() && new_field.is_deprecated() {
+            changes.push(SchemaChange::new(
+                SchemaChangeLevel::Safe,
+                &path,
+                format!("Field {} was deprecated", path),
+            ));
+        }
+
+        diff_arguments(&path, &old_field.arguments, &new_field.arguments, changes);
+    }
+
+    for new_field in new_fields {
+        if old_fields.iter().any(|f| f.name == new_field.name) {
+            continue;
+        }
+        let path = format!("{}.{}", type_name, new_field.name);
+        changes.push(SchemaChange::new(
+            SchemaChangeLevel::Safe,
+            &path,
+            format!("Field {} was added", path),
+        ));
+    }
+}
+
+fn diff_arguments(
+    parent_path: &str,
+    old_args: &[InputValueType],
+    new_args: &[InputValueType],
+    changes: &mut Vec<SchemaChange>,
+) {
+    for old_arg in old_args {
+        let path = format!("{}({}:)", parent_path, old_arg.name);
+        match new_args.iter().find(|arg| arg.name == old_arg.name) {
+            Some(new_arg) => diff_input_value(&path, "Argument", old_arg, new_arg, changes),
+            None => changes.push(SchemaChange::new(
+                SchemaChangeLevel::Breaking,
+                &path,
+                format!("Argument {} was removed from {}", old_arg.name, parent_path),
+            )),
+        }
+    }
+
+    for new_arg in new_args {
+        if old_args.iter().any(|arg| arg.name == new_arg.name) {
+            continue;
+        }
+        let path = format!("{}({}:)", parent_path, new_arg.name);
+        if is_required_input_value(new_arg) {
+            changes.push(SchemaChange::new(
+                SchemaChangeLevel::Breaking,
+                &path,
+                format!(
+                    "Required argument {} was added to {}",
+                    new_arg.name, parent_path
+                ),
+            ));
+        } else {
+            changes.push(SchemaChange::new(
+                SchemaChangeLevel::Dangerous,
+                &path,
+                format!(
+                    "Optional argument {} was added to {}",
+                    new_arg.name, parent_path
+                ),
+            ));
+        }
+    }
+}
+
+fn diff_input_object(
+    old_input: &InputObjectType,
+    new_input: &InputObjectType,
+    changes: &mut Vec<SchemaChange>,
+) {
+    for old_field in &old_input.fields {
+        let path = format!("{}.{}", old_input.name, old_field.name);
+        match new_input.fields.iter().find(|f| f.name == old_field.name) {
+            Some(new_field) => {
+                diff_input_value(&path, "Input field", old_field, new_field, changes)
+            }
+            None => changes.push(SchemaChange::new(
+                SchemaChangeLevel::Breaking,
+                &path,
+                format!("Input field {} was removed", path),
+            )),
+        }
+    }
+
+    for new_field in &new_input.fields {
+        if old_input.fields.iter().any(|f| f.name == new_field.name) {
+            continue;
+        }
+        let path = format!("{}.{}", new_input.name, new_field.name);
+        if is_required_input_value(new_field) {
+            changes.push(SchemaChange::new(
+                SchemaChangeLevel::Breaking,
+                &path,
+                format!("Required input field {} was added", path),
+            ));
+        } else {
+            changes.push(SchemaChange::new(
+                SchemaChangeLevel::Dangerous,
+                &path,
+                format!("Optional input field {} was added", path),
+            ));
+        }
+    }
+}
+
+fn diff_input_value(
+    path: &str,
+    label: &str,
+    old_value: &InputValueType,
+    new_value: &InputValueType,
+    changes: &mut Vec<SchemaChange>,
+) {
+    if old_value.meta_type != new_value.meta_type {
+        let level = if is_safe_input_type_change(&old_value.meta_type, &new_value.meta_type) {
+            SchemaChangeLevel::Safe
+        } else {
+            SchemaChangeLevel::Breaking
+        };
+        changes.push(SchemaChange::new(
+            level,
+            path,
+            format!(
+                "{} {} changed type from {} to {}",
+                label, path, old_value.meta_type, new_value.meta_type
+            ),
+        ));
+    }
+
+    if old_value.default_value != new_value.default_value {
+        let old_default = old_value
+            .default_value
+            .as_ref()
+            .map(|v| v.to_string())
+            .unwrap_or_else(|| "none".to_string());
+        let new_default = new_value
+            .default_value
+            .as_ref()
+            .map(|v| v.to_string())
+            .unwrap_or_else(|| "none".to_string());
+        changes.push(SchemaChange::new(
+            SchemaChangeLevel::Dangerous,
+            path,
+            format!(
+                "{} {} changed default value from {} to {}",
+                label, path, old_default, new_default
+            ),
+        ));
+    }
+}
+
+fn diff_enum(old_enum: &EnumType, new_enum: &EnumType, changes: &mut Vec<SchemaChange>) {
+    for old_value in &old_enum.values {
+        if !new_enum.contains(&old_value.name) {
+            let path = format!("{}.{}", old_enum.name, old_value.name);
+            changes.push(SchemaChange::new(
+                SchemaChangeLevel::Breaking,
+                &path,
+                format!("Enum value {} was removed", path),
+            ));
+        }
+    }
+
+    for new_value in &new_enum.values {
+        if !old_enum.contains(&new_value.name) {
+            let path = format!("{}.{}", new_enum.name, new_value.name);
+            changes.push(SchemaChange::new(
+                SchemaChangeLevel::Dangerous,
+                &path,
+                format!("Enum value {} was added", path),
+            ));
+        }
+    }
+}
+
+fn diff_union(old_union: &UnionType, new_union: &UnionType, changes: &mut Vec<SchemaChange>) {
+    for old_member in &old_union.types {
+        if !new_union.types.contains(old_member) {
+            changes.push(SchemaChange::new(
+                SchemaChangeLevel::Breaking,
+                &old_union.name,
+                format!("{} was removed from union {}", old_member, old_union.name),
+            ));
+        }
+    }
+
+    for new_member in &new_union.types {
+        if !old_union.types.contains(new_member) {
+            changes.push(SchemaChange::new(
+                SchemaChangeLevel::Dangerous,
+                &new_union.name,
+                format!("{} was added to union {}", new_member, new_union.name),
+            ));
+        }
+    }
+}
+
+fn diff_implements_interfaces(
+    type_name: &str,
+    old_interfaces: &[String],
+    new_interfaces: &[String],
+    changes: &mut Vec<SchemaChange>,
+) {
+    for old_interface in old_interfaces {
+        if !new_interfaces.contains(old_interface) {
+            changes.push(SchemaChange::new(
+                SchemaChangeLevel::Breaking,
+                type_name,
+                format!("{} no longer implements {}", type_name, old_interface),
+            ));
+        }
+    }
+
+    for new_interface in new_interfaces {
+        if !old_interfaces.contains(new_interface) {
+            changes.push(SchemaChange::new(
+                SchemaChangeLevel::Dangerous,
+                type_name,
+                format!("{} now implements {}", type_name, new_interface),
+            ));
+        }
+    }
+}
+
+fn diff_directives(
+    old_directives: &HashMap<String, DirectiveDefinition>,
+    new_directives: &HashMap<String, DirectiveDefinition>,
+    changes: &mut Vec<SchemaChange>,
+) {
+    for (name, old_directive) in old_directives {
+        let path = format!("@{}", name);
+        let new_directive = match new_directives.get(name) {
+            Some(dir) => dir,
+            None => {
+                changes.push(SchemaChange::new(
+                    SchemaChangeLevel::Breaking,
+                    &path,
+                    format!("Directive {} was removed", path),
+                ));
+                continue;
+            }
+        };
+
+        for location in &old_directive.locations {
+            if !new_directive.locations.contains(location) {
+                changes.push(SchemaChange::new(
+                    SchemaChangeLevel::Breaking,
+                    &path,
+                    format!(
+                        "Location {} was removed from directive {}",
+                        location.as_str(),
+                        path
+                    ),
+                ));
+            }
+        }
+
+        diff_arguments(
+            &path,
+            &old_directive.arguments,
+            &new_directive.arguments,
+            changes,
+        );
+    }
+
+    for name in new_directives.keys() {
+        if old_directives.contains_key(name) {
+            continue;
+        }
+        let path = format!("@{}", name);
+        changes.push(SchemaChange::new(
+            SchemaChangeLevel::Safe,
+            &path,
+            format!("Directive {} was added", path),
+        ));
+    }
+}
+
+fn is_required_input_value(value: &InputValueType) -> bool {
+    value.meta_type.is_non_null() && value.default_value.is_none()
+}
+
+fn is_safe_output_type_change(old_type: &GqlValueType, new_type: &GqlValueType) -> bool {
+    match (old_type, new_type) {
+        (GqlValueType::NamedType(old_name), GqlValueType::NamedType(new_name)) => {
+            old_name == new_name
+        }
+        (GqlValueType::ListType(old_ty), GqlValueType::ListType(new_ty)) => {
+            is_safe_output_type_change(old_ty, new_ty)
+        }
+        (GqlValueType::NonNullType(old_ty), GqlValueType::NonNullType(new_ty)) => {
+            is_safe_output_type_change(old_ty, new_ty)
+        }
+        (_, GqlValueType::NonNullType(new_ty)) => is_safe_output_type_change(old_type, new_ty),
+        _ => false,
+    }
+}
+
+fn is_safe_input_type_change(old_type: &GqlValueType, new_type: &GqlValueType) -> bool {
+    match (old_type, new_type) {
+        (GqlValueType::NamedType(old_name), GqlValueType::NamedType(new_name)) => {
+            old_name == new_name
+        }
+        (GqlValueType::ListType(old_ty), GqlValueType::ListType(new_ty)) => {
+            is_safe_input_type_change(old_ty, new_ty)
+        }
+        (GqlValueType::NonNullType(old_ty), GqlValueType::NonNullType(new_ty)) => {
+            is_safe_input_type_change(old_ty, new_ty)
+        }
+        (GqlValueType::NonNullType(old_ty), _) => is_safe_input_type_change(old_ty, new_type),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::build_schema;
+
+    use super::{diff_schema, SchemaChangeLevel};
+
+    fn levels_of(old: &str, new: &str) -> Vec<(SchemaChangeLevel, String)> {
+        let old_schema = build_schema(&[old], Default::default()).unwrap();
+        let new_schema = build_schema(&[new], Default::default()).unwrap();
+        diff_schema(&old_schema, &new_schema)
+            .into_iter()
+            .map(|change| (change.level, change.path))
+            .collect()
+    }
+
+    #[test]
+    fn test_identical_schema() {
+        let schema = r#"
+            type Query { user(id: ID!): User }
+            type User { name: String! }
+        "#;
+        assert!(levels_of(schema, schema).is_empty());
+    }
+
+    #[test]
+    fn test_field_changes() {
+        let old = r#"
+            type Query { user(id: ID!): User }
+            type User { name: String! age: Int email: String }
+        "#;
+        let new = r#"
+            type Query { user(id: ID!): User }
+            type User { name: String age: Int! nickname: String }
+        "#;
+        let changes = levels_of(old, new);
+        assert!(changes.contains(&(SchemaChangeLevel::Breaking, "User.email".to_string())));
+        assert!(changes.contains(&(SchemaChangeLevel::Breaking, "User.name".to_string())));
+        assert!(changes.contains(&(SchemaChangeLevel::Safe, "User.age".to_string())));
+        assert!(changes.contains(&(SchemaChangeLevel::Safe, "User.nickname".to_string())));
+    }
+
+    #[test]
+    fn test_deprecated_field_removed() {
+        let old = r#"
+            type Query { user: User }
+            type User { name: String! nickname: String @deprecated }
+        "#;
+        let new = r#"
+            type Query { user: User }
+            type User { name: String! }
+        "#;
+        let changes = levels_of(old, new);
+        assert_eq!(
+            changes,
+            vec![(SchemaChangeLevel::Breaking, "User.nickname".to_string())]
+        );
+    }
+
+    #[test]
+    fn test_argument_changes() {
+        let old = r#"
+            type Query { users(first: Int, after: String): [String!]! }
+        "#;
+        let new = r#"
+            type Query { users(first: Int!, last: Int, filter: String!): [String!]! }
+        "#;
+        let changes = levels_of(old, new);
+        assert!(changes.contains(&(
+            SchemaChangeLevel::Breaking,
+            "Query.users(first:)".to_string()
+        )));
+        assert!(changes.contains(&(
+            SchemaChangeLevel::Breaking,
+            "Query.users(after:)".to_string()
+        )));
+        assert!(changes.contains(&(
+            SchemaChangeLevel::Breaking,
+            "Query.users(filter:)".to_string()
+        )));
+        assert!(changes.contains(&(
+            SchemaChangeLevel::Dangerous,
+            "Query.users(last:)".to_string()
+        )));
+    }
+
+    #[test]
+    fn test_enum_and_union_changes() {
+        let old = r#"
+            type Query { episode: Episode search: SearchResult }
+            enum Episode { NEWHOPE EMPIRE JEDI }
+            type Human { name: String }
+            type Droid { name: String }
+            union SearchResult = Human | Droid
+        "#;
+        let new = r#"
+            type Query { episode: Episode search: SearchResult }
+            enum Episode { NEWHOPE EMPIRE FORCE }
+            type Human { name: String }
+            type Droid { name: String }
+            type Starship { name: String }
+            union SearchResult = Human | Starship
+        "#;
+        let changes = levels_of(old, new);
+        assert!(changes.contains(&(SchemaChangeLevel::Breaking, "Episode.JEDI".to_string())));
+        assert!(changes.contains(&(SchemaChangeLevel::Dangerous, "Episode.FORCE".to_string())));
+        assert!(changes.contains(&(SchemaChangeLevel::Breaking, "SearchResult".to_string())));
+        assert!(changes.contains(&(SchemaChangeLevel::Dangerous, "SearchResult".to_string())));
+        assert!(changes.contains(&(SchemaChangeLevel::Safe, "Starship".to_string())));
+    }
+
+    #[test]
+    fn test_input_object_changes() {
+        let old = r#"
+            type Query { search(input: SearchInput): String }
+            input SearchInput { keyword: String! limit: Int }
+        "#;
+        let new = r#"
+            type Query { search(input: SearchInput): String }
+            input SearchInput { keyword: String limit: Int = 10 category: String! }
+        "#;
+        let changes = levels_of(old, new);
+        assert!(changes.contains(&(SchemaChangeLevel::Safe, "SearchInput.keyword".to_string())));
+        assert!(changes.contains(&(
+            SchemaChangeLevel::Dangerous,
+            "SearchInput.limit".to_string()
+        )));
+        assert!(changes.contains(&(
+            SchemaChangeLevel::Breaking,
+            "SearchInput.category".to_string()
+        )));
+    }
+
+    #[test]
+    fn test_type_removed_and_kind_changed() {
+        let old = r#"
+            type Query { node: Node }
+            type Node { id: ID! }
+            type Review { stars: Int }
+        "#;
+        let new = r#"
+            type Query { node: Node }
+            interface Node { id: ID! }
+        "#;
+        let changes = levels_of(old, new);
+        assert!(changes.contains(&(SchemaChangeLevel::Breaking, "Node".to_string())));
+        assert!(changes.contains(&(SchemaChangeLevel::Breaking, "Review".to_string())));
+    }
+}
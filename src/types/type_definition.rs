@@ -17,15 +17,15 @@ pub enum TypeDefinition {
     InputObject(InputObjectType),
 }
 
-impl ToString for TypeDefinition {
-    fn to_string(&self) -> String {
+impl std::fmt::Display for TypeDefinition {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            TypeDefinition::Scalar(_) => "Scalar".to_string(),
-            TypeDefinition::Object(_) => "Object".to_string(),
-            TypeDefinition::Interface(_) => "Interface".to_string(),
-            TypeDefinition::Union(_) => "Union".to_string(),
-            TypeDefinition::Enum(_) => "Enum".to_string(),
-            TypeDefinition::InputObject(_) => "InputObject".to_string(),
+            TypeDefinition::Scalar(_) => write!(f, "Scalar"),
+            TypeDefinition::Object(_) => write!(f, "Object"),
+            TypeDefinition::Interface(_) => write!(f, "Interface"),
+            TypeDefinition::Union(_) => write!(f, "Union"),
+            TypeDefinition::Enum(_) => write!(f, "Enum"),
+            TypeDefinition::InputObject(_) => write!(f, "InputObject"),
         }
     }
 }
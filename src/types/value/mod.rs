@@ -52,29 +52,33 @@ impl PartialEq for GqlConstValue {
     }
 }
 
-#[derive(Debug, Clone, Eq)]
+#[derive(Debug, Clone, Eq, Default)]
 pub enum GqlValue {
     Variable(String),
     Number(Number),
     String(String),
     Boolean(bool),
+    #[default]
     Null,
     Enum(String),
     List(Vec<GqlValue>),
     Object(BTreeMap<String, GqlValue>),
+    /// Index of a file in a multipart request, which JSON and query literals cannot represent.
+    Upload(usize),
 }
 
-impl ToString for GqlValue {
-    fn to_string(&self) -> String {
+impl std::fmt::Display for GqlValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            GqlValue::Variable(v) => v.clone(),
-            GqlValue::Number(v) => v.to_string(),
-            GqlValue::String(v) => v.clone(),
-            GqlValue::Boolean(v) => v.to_string(),
-            GqlValue::Null => "null".to_string(),
-            GqlValue::Enum(v) => v.clone(),
-            GqlValue::List(v) => format!("{:?}", v),
-            GqlValue::Object(v) => format!("{:?}", v),
+            GqlValue::Variable(v) => write!(f, "{}", v),
+            GqlValue::Number(v) => write!(f, "{}", v),
+            GqlValue::String(v) => write!(f, "{}", v),
+            GqlValue::Boolean(v) => write!(f, "{}", v),
+            GqlValue::Null => write!(f, "null"),
+            GqlValue::Enum(v) => write!(f, "{}", v),
+            GqlValue::List(v) => write!(f, "{:?}", v),
+            GqlValue::Object(v) => write!(f, "{:?}", v),
+            GqlValue::Upload(v) => write!(f, "Upload({})", v),
         }
     }
 }
@@ -94,6 +98,7 @@ impl PartialEq for GqlValue {
                 l0.iter().zip(r0.iter()).all(|(l, r)| l == r)
             }
             (Self::Object(l0), Self::Object(r0)) => l0 == r0,
+            (Self::Upload(l0), Self::Upload(r0)) => l0 == r0,
             _ => false,
         }
     }
@@ -110,6 +115,7 @@ impl Serialize for GqlValue {
             GqlValue::Enum(v) => serializer.serialize_str(v),
             GqlValue::List(v) => v.serialize(serializer),
             GqlValue::Object(v) => v.serialize(serializer),
+            GqlValue::Upload(v) => v.serialize(serializer),
         }
     }
 }
@@ -175,6 +181,13 @@ impl<'de> Visitor<'de> for GqlValueVisitor {
         Ok(GqlValue::Null)
     }
 
+    fn visit_unit<E>(self) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        Ok(GqlValue::Null)
+    }
+
     fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
     where
         A: serde::de::SeqAccess<'de>,
@@ -207,12 +220,6 @@ impl<'de> Deserialize<'de> for GqlValue {
     }
 }
 
-impl Default for GqlValue {
-    fn default() -> Self {
-        GqlValue::Null
-    }
-}
-
 impl<'a> From<Value<'a, String>> for GqlValue {
     fn from(value: Value<'a, String>) -> Self {
         match value {
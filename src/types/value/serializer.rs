@@ -120,9 +120,9 @@ impl ser::Serializer for Serializer {
         Ok(GqlValue::Null)
     }
 
-    fn serialize_some<T: ?Sized>(self, value: &T) -> Result<Self::Ok, Self::Error>
+    fn serialize_some<T>(self, value: &T) -> Result<Self::Ok, Self::Error>
     where
-        T: serde::Serialize,
+        T: ?Sized + serde::Serialize,
     {
         value.serialize(self)
     }
@@ -144,18 +144,18 @@ impl ser::Serializer for Serializer {
         Ok(GqlValue::String(variant.to_string()))
     }
 
-    fn serialize_newtype_struct<T: ?Sized>(
+    fn serialize_newtype_struct<T>(
         self,
         _name: &'static str,
         value: &T,
     ) -> Result<Self::Ok, Self::Error>
     where
-        T: serde::Serialize,
+        T: ?Sized + serde::Serialize,
     {
         value.serialize(self)
     }
 
-    fn serialize_newtype_variant<T: ?Sized>(
+    fn serialize_newtype_variant<T>(
         self,
         _name: &'static str,
         _variant_index: u32,
@@ -163,7 +163,7 @@ impl ser::Serializer for Serializer {
         value: &T,
     ) -> Result<Self::Ok, Self::Error>
     where
-        T: serde::Serialize,
+        T: ?Sized + serde::Serialize,
     {
         value.serialize(self).map(|v| {
             let mut obj = BTreeMap::new();
@@ -234,9 +234,9 @@ impl ser::SerializeSeq for SerializeSeq {
 
     type Error = SerializerError;
 
-    fn serialize_element<T: ?Sized>(&mut self, value: &T) -> Result<(), Self::Error>
+    fn serialize_element<T>(&mut self, value: &T) -> Result<(), Self::Error>
     where
-        T: serde::Serialize,
+        T: ?Sized + serde::Serialize,
     {
         let value = value.serialize(Serializer)?;
         self.0.push(value);
@@ -255,9 +255,9 @@ impl ser::SerializeTuple for SerializeTuple {
 
     type Error = SerializerError;
 
-    fn serialize_element<T: ?Sized>(&mut self, value: &T) -> Result<(), Self::Error>
+    fn serialize_element<T>(&mut self, value: &T) -> Result<(), Self::Error>
     where
-        T: serde::Serialize,
+        T: ?Sized + serde::Serialize,
     {
         let value = value.serialize(Serializer)?;
         self.0.push(value);
@@ -276,9 +276,9 @@ impl ser::SerializeTupleStruct for SerializeTupleStruct {
     type Error = SerializerError;
 
     #[inline]
-    fn serialize_field<T: ?Sized>(&mut self, value: &T) -> Result<(), Self::Error>
+    fn serialize_field<T>(&mut self, value: &T) -> Result<(), Self::Error>
     where
-        T: ser::Serialize,
+        T: ?Sized + ser::Serialize,
     {
         let value = value.serialize(Serializer)?;
         self.0.push(value);
@@ -298,9 +298,9 @@ impl ser::SerializeTupleVariant for SerializeTupleVariant {
     type Error = SerializerError;
 
     #[inline]
-    fn serialize_field<T: ?Sized>(&mut self, value: &T) -> Result<(), Self::Error>
+    fn serialize_field<T>(&mut self, value: &T) -> Result<(), Self::Error>
     where
-        T: ser::Serialize,
+        T: ?Sized + ser::Serialize,
     {
         let value = value.serialize(Serializer)?;
         self.1.push(value);
@@ -325,9 +325,9 @@ impl ser::SerializeMap for SerializeMap {
     type Error = SerializerError;
 
     #[inline]
-    fn serialize_key<T: ?Sized>(&mut self, key: &T) -> Result<(), Self::Error>
+    fn serialize_key<T>(&mut self, key: &T) -> Result<(), Self::Error>
     where
-        T: ser::Serialize,
+        T: ?Sized + ser::Serialize,
     {
         let key = key.serialize(KeySerializer)?;
         self.key = Some(key);
@@ -335,9 +335,9 @@ impl ser::SerializeMap for SerializeMap {
     }
 
     #[inline]
-    fn serialize_value<T: ?Sized>(&mut self, value: &T) -> Result<(), Self::Error>
+    fn serialize_value<T>(&mut self, value: &T) -> Result<(), Self::Error>
     where
-        T: ser::Serialize,
+        T: ?Sized + ser::Serialize,
     {
         let value = value.serialize(Serializer)?;
         self.map.insert(self.key.take().unwrap(), value);
@@ -357,13 +357,9 @@ impl ser::SerializeStruct for SerializeStruct {
     type Error = SerializerError;
 
     #[inline]
-    fn serialize_field<T: ?Sized>(
-        &mut self,
-        key: &'static str,
-        value: &T,
-    ) -> Result<(), Self::Error>
+    fn serialize_field<T>(&mut self, key: &'static str, value: &T) -> Result<(), Self::Error>
     where
-        T: ser::Serialize,
+        T: ?Sized + ser::Serialize,
     {
         let value = value.serialize(Serializer)?;
         self.0.insert(key.to_string(), value);
@@ -383,13 +379,9 @@ impl ser::SerializeStructVariant for SerializeStructVariant {
     type Error = SerializerError;
 
     #[inline]
-    fn serialize_field<T: ?Sized>(
-        &mut self,
-        key: &'static str,
-        value: &T,
-    ) -> Result<(), Self::Error>
+    fn serialize_field<T>(&mut self, key: &'static str, value: &T) -> Result<(), Self::Error>
     where
-        T: ser::Serialize,
+        T: ?Sized + ser::Serialize,
     {
         let value = value.serialize(Serializer)?;
         self.1.insert(key.to_string(), value);
@@ -488,9 +480,9 @@ impl serde::Serializer for KeySerializer {
         Err(cannot_serialize_except_string())
     }
 
-    fn serialize_some<T: ?Sized>(self, _value: &T) -> Result<Self::Ok, Self::Error>
+    fn serialize_some<T>(self, _value: &T) -> Result<Self::Ok, Self::Error>
     where
-        T: serde::Serialize,
+        T: ?Sized + serde::Serialize,
     {
         Err(cannot_serialize_except_string())
     }
@@ -512,18 +504,18 @@ impl serde::Serializer for KeySerializer {
         Ok(variant.to_string())
     }
 
-    fn serialize_newtype_struct<T: ?Sized>(
+    fn serialize_newtype_struct<T>(
         self,
         _name: &'static str,
         _value: &T,
     ) -> Result<Self::Ok, Self::Error>
     where
-        T: serde::Serialize,
+        T: ?Sized + serde::Serialize,
     {
         Err(cannot_serialize_except_string())
     }
 
-    fn serialize_newtype_variant<T: ?Sized>(
+    fn serialize_newtype_variant<T>(
         self,
         _name: &'static str,
         _variant_index: u32,
@@ -531,7 +523,7 @@ impl serde::Serializer for KeySerializer {
         _value: &T,
     ) -> Result<Self::Ok, Self::Error>
     where
-        T: serde::Serialize,
+        T: ?Sized + serde::Serialize,
     {
         Err(cannot_serialize_except_string())
     }
@@ -2,7 +2,7 @@ use graphql_parser::schema::Type;
 
 use crate::GqlValue;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum GqlValueType {
     NamedType(String),
     ListType(Box<GqlValueType>),
@@ -35,29 +35,37 @@ impl GqlValueType {
     pub fn is_sub_type(&self, sub: &GqlValueType, default_value: &Option<GqlValue>) -> bool {
         match (self, sub) {
             (GqlValueType::NonNullType(base_type), GqlValueType::NonNullType(sub_type)) => {
-                base_type.is_sub_type(&*sub_type, default_value)
+                base_type.is_sub_type(sub_type, default_value)
             }
             (GqlValueType::NamedType(base_type_name), GqlValueType::NonNullType(sub_type)) => {
                 base_type_name.eq(&sub_type.name())
             }
-            (GqlValueType::NonNullType(base_type), GqlValueType::NamedType(sub_type)) => {
-                if default_value.is_some() {
-                    base_type.name().eq(sub_type)
-                } else {
-                    false
-                }
+            (GqlValueType::NonNullType(base_type), GqlValueType::NamedType(sub_type))
+                if default_value.is_some() =>
+            {
+                base_type.name().eq(sub_type)
             }
             (GqlValueType::NamedType(base_type_name), GqlValueType::NamedType(sub_type_name)) => {
                 base_type_name.eq(sub_type_name)
             }
             (GqlValueType::ListType(base_type), GqlValueType::ListType(sub_type)) => {
-                base_type.is_sub_type(&*sub_type, default_value)
+                base_type.is_sub_type(sub_type, default_value)
             }
             _ => false,
         }
     }
 }
 
+impl std::fmt::Display for GqlValueType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GqlValueType::NamedType(name) => write!(f, "{}", name),
+            GqlValueType::ListType(list_type) => write!(f, "[{}]", list_type),
+            GqlValueType::NonNullType(non_null_type) => write!(f, "{}!", non_null_type),
+        }
+    }
+}
+
 impl<'a> From<Type<'a, String>> for GqlValueType {
     fn from(meta_type: Type<'a, String>) -> Self {
         match meta_type {
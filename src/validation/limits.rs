@@ -0,0 +1,157 @@
+use graphql_parser::query::{Selection, SelectionSet, TypeCondition};
+
+use crate::{operation::Operation, GqlError, OperationType, ResolverRegistry, Schema};
+
+pub fn check_depth_limit(operation: &Operation<'_>, limit: usize) -> Result<(), GqlError> {
+    let depth = selection_set_depth(operation, &operation.selection_set);
+    if depth > limit {
+        return Err(GqlError::new(
+            format!(
+                "Query depth {} exceeds the maximum depth limit {}.",
+                depth, limit
+            ),
+            None,
+        ));
+    }
+    Ok(())
+}
+
+fn selection_set_depth(
+    operation: &Operation<'_>,
+    selection_set: &SelectionSet<'_, String>,
+) -> usize {
+    selection_set
+        .items
+        .iter()
+        .map(|selection| match selection {
+            Selection::Field(field) => {
+                if field.selection_set.items.is_empty() {
+                    1
+                } else {
+                    selection_set_depth(operation, &field.selection_set) + 1
+                }
+            }
+            Selection::FragmentSpread(fragment_spread) => operation
+                .fragment_definitions
+                .get(&fragment_spread.fragment_name)
+                .map(|fragment| selection_set_depth(operation, &fragment.selection_set))
+                .unwrap_or_default(),
+            Selection::InlineFragment(inline_fragment) => {
+                selection_set_depth(operation, &inline_fragment.selection_set)
+            }
+        })
+        .max()
+        .unwrap_or_default()
+}
+
+/// Each field costs its `#[field(complexity = ...)]`, or 1 if it is not set.
+pub fn check_complexity_limit(
+    schema: &Schema,
+    registry: &ResolverRegistry,
+    operation: &Operation<'_>,
+    limit: usize,
+) -> Result<(), GqlError> {
+    let root_type_name = match operation.operation_type {
+        OperationType::Query => &schema.query_type_name,
+        OperationType::Mutation => &schema.mutation_type_name,
+        OperationType::Subscription => &schema.subscription_type_name,
+    };
+    let complexity = ComplexityCalculator {
+        schema,
+        registry,
+        operation,
+    }
+    .selection_set_complexity(Some(root_type_name), &operation.selection_set);
+    if complexity > limit {
+        return Err(GqlError::new(
+            format!(
+                "Query complexity {} exceeds the maximum complexity limit {}.",
+                complexity, limit
+            ),
+            None,
+        ));
+    }
+    Ok(())
+}
+
+struct ComplexityCalculator<'a, 'op> {
+    schema: &'a Schema,
+    registry: &'a ResolverRegistry,
+    operation: &'a Operation<'op>,
+}
+
+impl<'a, 'op> ComplexityCalculator<'a, 'op> {
+    fn selection_set_complexity(
+        &self,
+        type_name: Option<&str>,
+        selection_set: &SelectionSet<'_, String>,
+    ) -> usize {
+        selection_set
+            .items
+            .iter()
+            .map(|selection| match selection {
+                Selection::Field(field) => {
+                    let complexity = type_name
+                        .and_then(|type_name| self.registry.field(type_name, &field.name))
+                        .and_then(|metadata| metadata.complexity)
+                        .unwrap_or(1);
+                    let field_type_name = type_name
+                        .and_then(|type_name| self.schema.type_definitions.get(type_name))
+                        .and_then(|type_def| type_def.get_field_by_name(&field.name))
+                        .map(|field_def| field_def.meta_type.name());
+                    complexity
+                        + self.selection_set_complexity(field_type_name, &field.selection_set)
+                }
+                Selection::FragmentSpread(fragment_spread) => self
+                    .operation
+                    .fragment_definitions
+                    .get(&fragment_spread.fragment_name)
+                    .map(|fragment| {
+                        let TypeCondition::On(on_type) = &fragment.type_condition;
+                        self.selection_set_complexity(Some(on_type), &fragment.selection_set)
+                    })
+                    .unwrap_or_default(),
+                Selection::InlineFragment(inline_fragment) => {
+                    let on_type = match &inline_fragment.type_condition {
+                        Some(TypeCondition::On(on_type)) => Some(on_type.as_str()),
+                        None => type_name,
+                    };
+                    self.selection_set_complexity(on_type, &inline_fragment.selection_set)
+                }
+            })
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::validation::test_utils::{build_test_operation, parse_test_query};
+
+    use super::check_depth_limit;
+
+    #[test]
+    fn test_depth_limit() {
+        let query_doc = r#"
+        {
+            hero {
+                name
+                friends {
+                    ...FriendFields
+                }
+            }
+        }
+        fragment FriendFields on Character {
+            name
+            ... on Human {
+                friends {
+                    name
+                }
+            }
+        }
+        "#;
+        let doc = parse_test_query(query_doc);
+        let operation = build_test_operation(&doc);
+        assert!(check_depth_limit(&operation, 4).is_ok());
+        assert!(check_depth_limit(&operation, 3).is_err());
+    }
+}
@@ -1,9 +1,20 @@
-use graphql_parser::query::Document;
+use graphql_parser::query::{Definition, Document, OperationDefinition};
 
-use crate::{operation::Operation, types::schema::Schema, GqlError, Variables};
+use crate::{
+    custom_scalar::coerce_input_value,
+    operation::{build_operation, Operation},
+    types::schema::Schema,
+    GqlError, GqlValueType, Variables,
+};
 
-use self::visitor::{visit, NewVisitor, ValidationContext};
+use self::{
+    utils::check_one_of_input_value,
+    visitor::{visit, NewVisitor, ValidationContext},
+};
 
+pub(crate) use limits::{check_complexity_limit, check_depth_limit};
+
+mod limits;
 mod rules;
 mod test_utils;
 mod utils;
@@ -18,32 +29,131 @@ pub fn apply_validation<'a>(
 ) -> Result<(), Vec<GqlError>> {
     let mut ctx = ValidationContext::new(schema, variables, operation);
     let mut visitor = NewVisitor
-        .with(rules::DefaultValueOfCorrectType::default())
-        .with(rules::FieldsOnCorrectType::default())
-        .with(rules::FragmentsOnCompositeTypes::default())
+        .with(rules::ArgumentsOfCorrectType::default())
+        .with(rules::DefaultValueOfCorrectType)
+        .with(rules::FieldsOnCorrectType)
+        .with(rules::FragmentsOnCompositeTypes)
         .with(rules::KnownArgumentNames::default())
         .with(rules::KnownDirectives::default())
-        .with(rules::KnownFragmentName::default())
-        .with(rules::KnownTypeNames::default())
+        .with(rules::KnownFragmentName)
+        .with(rules::KnownTypeNames)
         .with(rules::NoFragmentCycles::default())
         .with(rules::NoUndefinedVariables::default())
         // .with(rules::NoUnusedFragment::default())
         .with(rules::NoUnusedVariables::default())
-        .with(rules::OverlappingFieldsCanBeMerged::default())
+        .with(rules::OneOfInputObjects::default())
+        .with(rules::OverlappingFieldsCanBeMerged)
         .with(rules::PossibleFragmentSpreads::default())
-        .with(rules::ProvidedNonNullArguments::default())
-        .with(rules::ScalarLeafs::default())
+        .with(rules::ProvidedNonNullArguments)
+        .with(rules::ScalarLeafs)
         .with(rules::UniqueArgumentNames::default())
         .with(rules::UniqueVariableNames::default())
-        .with(rules::VariablesAreInputTypes::default())
+        .with(rules::VariablesAreInputTypes)
         .with(rules::VariablesInAllowedPosition::default());
-    // .with(rules::ArgumentsOfCorrectType::default())
 
     visit(&mut visitor, &mut ctx, query_doc, operation_name);
 
+    if let Some(variables) = variables {
+        for variable_definition in &operation.variable_definitions {
+            if let Some(value) = variables.0.get(&variable_definition.name) {
+                let ty = GqlValueType::from(variable_definition.var_type.clone());
+                let message = check_one_of_input_value(schema, &ty, value, &[])
+                    .or_else(|| coerce_input_value(schema, &ty, value.clone(), false).err());
+                if let Some(message) = message {
+                    ctx.add_error(
+                        format!(
+                            "Invalid value for variable {}: {}",
+                            variable_definition.name, message
+                        ),
+                        vec![variable_definition.position],
+                    );
+                }
+            }
+        }
+    }
+
     if !ctx.errors.is_empty() {
         return Err(ctx.errors.into_iter().map(|v| v.into()).collect());
     }
 
     Ok(())
 }
+
+/// Validates each operation of a document against the schema, such as the operations of a client.
+pub fn validate_document(schema: &Schema, query_doc: &str) -> Vec<GqlError> {
+    let doc = match graphql_parser::parse_query::<String>(query_doc) {
+        Ok(doc) => doc,
+        Err(err) => return vec![GqlError::new(err.to_string(), None)],
+    };
+    let operation_names = doc
+        .definitions
+        .iter()
+        .filter_map(|def| match def {
+            Definition::Operation(OperationDefinition::Query(query)) => Some(query.name.clone()),
+            Definition::Operation(OperationDefinition::Mutation(mutation)) => {
+                Some(mutation.name.clone())
+            }
+            Definition::Operation(OperationDefinition::Subscription(subscription)) => {
+                Some(subscription.name.clone())
+            }
+            Definition::Operation(OperationDefinition::SelectionSet(_)) => Some(None),
+            Definition::Fragment(_) => None,
+        })
+        .collect::<Vec<_>>();
+
+    // Each operation is validated with the fragments only, so that rules see one operation at a time.
+    let operation_docs: Vec<Document<'_, String>> = (0..operation_names.len())
+        .map(|index| {
+            let mut operation_doc = doc.clone();
+            let mut operation_index = 0;
+            operation_doc.definitions.retain(|def| match def {
+                Definition::Operation(_) => {
+                    operation_index += 1;
+                    operation_index == index + 1
+                }
+                Definition::Fragment(_) => true,
+            });
+            operation_doc
+        })
+        .collect();
+    let operations: Vec<_> = operation_docs
+        .iter()
+        .zip(&operation_names)
+        .map(|(operation_doc, operation_name)| {
+            build_operation(operation_doc, operation_name.clone(), Default::default())
+        })
+        .collect();
+
+    let mut errors: Vec<GqlError> = Vec::new();
+    for ((operation_doc, operation_name), operation) in
+        operation_docs.iter().zip(&operation_names).zip(&operations)
+    {
+        let operation_errors = match operation {
+            Ok(operation) => apply_validation(
+                schema,
+                operation_doc,
+                None,
+                operation,
+                operation_name.as_deref(),
+            )
+            .err()
+            .unwrap_or_default(),
+            Err(err) => vec![err.clone()],
+        };
+        for err in operation_errors {
+            // Errors in fragments are reported once even if several operations use them.
+            let is_reported = errors.iter().any(|reported| {
+                reported.message == err.message
+                    && reported
+                        .locations
+                        .iter()
+                        .map(|loc| (loc.line, loc.column))
+                        .eq(err.locations.iter().map(|loc| (loc.line, loc.column)))
+            });
+            if !is_reported {
+                errors.push(err);
+            }
+        }
+    }
+    errors
+}
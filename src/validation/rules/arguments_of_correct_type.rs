@@ -1,67 +1,175 @@
-use graphql_parser::schema::Value;
+use graphql_parser::{
+    query::Field,
+    schema::{Directive, Value},
+};
 
-use crate::validation::visitor::{ValidationContext, Visitor};
+use crate::{
+    validation::{
+        utils::check_valid_input_value,
+        visitor::{ValidationContext, Visitor},
+    },
+    GqlValueType,
+};
 
 #[derive(Default)]
-pub struct ArgumentsOfCorrectType<'a> {
-    pub current_args: Option<&'a Vec<(String, Value<'a, String>)>>,
+pub struct ArgumentsOfCorrectType {
+    current_args: Option<Vec<(String, GqlValueType)>>,
 }
 
-impl<'a> Visitor<'a> for ArgumentsOfCorrectType<'a> {
+impl<'a> Visitor<'a> for ArgumentsOfCorrectType {
     fn enter_directive(
         &mut self,
-        _ctx: &mut ValidationContext,
-        directive: &'a graphql_parser::schema::Directive<'a, String>,
+        ctx: &mut ValidationContext,
+        directive: &'a Directive<'a, String>,
     ) {
-        self.current_args = Some(&directive.arguments);
+        self.current_args = ctx.schema.directives.get(&directive.name).map(|dir| {
+            dir.arguments
+                .iter()
+                .map(|arg| (arg.name.clone(), arg.meta_type.clone()))
+                .collect()
+        });
     }
 
     fn exit_directive(
         &mut self,
         _ctx: &mut ValidationContext,
-        _directive: &'a graphql_parser::schema::Directive<'a, String>,
+        _directive: &'a Directive<'a, String>,
     ) {
         self.current_args = None;
     }
 
-    fn enter_field(
-        &mut self,
-        _ctx: &mut ValidationContext,
-        field: &'a graphql_parser::query::Field<'a, String>,
-    ) {
-        self.current_args = Some(&field.arguments);
+    fn enter_field(&mut self, ctx: &mut ValidationContext, field: &'a Field<'a, String>) {
+        self.current_args = ctx
+            .parent_type()
+            .and_then(|ty| ty.get_field_by_name(&field.name))
+            .map(|target_field| {
+                target_field
+                    .arguments
+                    .iter()
+                    .map(|arg| (arg.name.clone(), arg.meta_type.clone()))
+                    .collect()
+            });
     }
 
-    fn exit_field(
-        &mut self,
-        _ctx: &mut ValidationContext,
-        _field: &'a graphql_parser::query::Field<'a, String>,
-    ) {
+    fn exit_field(&mut self, _ctx: &mut ValidationContext, _field: &'a Field<'a, String>) {
         self.current_args = None;
     }
 
     fn enter_argument(
         &mut self,
-        _ctx: &mut ValidationContext,
+        ctx: &mut ValidationContext,
         arg_name: &'a str,
-        _arg_value: &'a Value<'a, String>,
+        arg_value: &'a Value<'a, String>,
     ) {
-        match &self.current_args {
-            Some(args) => {
-                let target_arg = args.iter().find(|arg| arg.0 == arg_name);
-                if target_arg.is_none() {}
-
-                // if let Some(vars) = &ctx.variables {
-                //     if let Some(def) = vars.0.get(arg_name) {
-                //         if let Some(err_msg) =
-                //             check_valid_input_value(&ctx.schema, &def.var_type, arg_value)
-                //         {
-                //             ctx.add_error(format!("Invalid value for argument {}", err_msg), vec![])
-                //         }
-                //     }
-                // }
+        let arg_type = self
+            .current_args
+            .as_ref()
+            .and_then(|args| args.iter().find(|(name, _)| name == arg_name))
+            .map(|(_, ty)| ty.to_parser_type());
+
+        if let Some(ty) = arg_type {
+            if let Some(message) = check_valid_input_value(ctx.schema, &ty, arg_value) {
+                ctx.add_error(
+                    format!("Invalid value for argument {}: {}", arg_name, message),
+                    vec![],
+                );
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{check_fails_rule, check_passes_rule};
+
+    use super::*;
+
+    fn factory() -> ArgumentsOfCorrectType {
+        ArgumentsOfCorrectType::default()
+    }
+
+    #[test]
+    fn good_int_value() {
+        let query_doc = r#"
+        {
+            argTest {
+                intArgField(intArg: 2)
+            }
+        }
+        "#;
+        check_passes_rule!(query_doc, factory);
+    }
+
+    #[test]
+    fn int_into_float_and_id() {
+        let query_doc = r#"
+        {
+            argTest {
+                floatArgField(floatArg: 2)
+                idArgField(idArg: 1)
+            }
+        }
+        "#;
+        check_passes_rule!(query_doc, factory);
+    }
+
+    #[test]
+    fn good_enum_value() {
+        let query_doc = r#"
+        {
+            hero(episode: JEDI) {
+                name
+            }
+        }
+        "#;
+        check_passes_rule!(query_doc, factory);
+    }
+
+    #[test]
+    fn variable_value() {
+        let query_doc = r#"
+        query ($intArg: Int) {
+            argTest {
+                intArgField(intArg: $intArg)
+            }
+        }
+        "#;
+        check_passes_rule!(query_doc, factory);
+    }
+
+    #[test]
+    fn string_into_int() {
+        let query_doc = r#"
+        {
+            argTest {
+                intArgField(intArg: "2")
+            }
+        }
+        "#;
+        check_fails_rule!(query_doc, factory);
+    }
+
+    #[test]
+    fn unknown_enum_value() {
+        let query_doc = r#"
+        {
+            hero(episode: UNKNOWN) {
+                name
+            }
+        }
+        "#;
+        check_fails_rule!(query_doc, factory);
+    }
+
+    #[test]
+    fn null_into_non_null() {
+        let query_doc = r#"
+        {
+            argTest {
+                nonNullIntArgField(intArg: null)
             }
-            None => {}
         }
+        "#;
+        check_fails_rule!(query_doc, factory);
     }
 }
@@ -33,7 +33,7 @@ mod tests {
     use super::*;
 
     pub fn factory() -> FieldsOnCorrectType {
-        FieldsOnCorrectType::default()
+        FieldsOnCorrectType
     }
 
     #[test]
@@ -56,7 +56,7 @@ mod tests {
     use super::*;
 
     fn factory() -> FragmentsOnCompositeTypes {
-        FragmentsOnCompositeTypes::default()
+        FragmentsOnCompositeTypes
     }
 
     #[test]
@@ -58,7 +58,7 @@ impl<'a> Visitor<'a> for KnownDirectives {
         ctx: &mut ValidationContext,
         directive: &'a graphql_parser::schema::Directive<'a, String>,
     ) {
-        let is_exist = ctx.schema.directives.get(&directive.name).is_some();
+        let is_exist = ctx.schema.directives.contains_key(&directive.name);
         if !is_exist {
             ctx.add_error(
                 format!("Unknown directive {}", directive.name),
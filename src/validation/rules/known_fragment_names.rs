@@ -27,7 +27,7 @@ mod tests {
     use super::*;
 
     fn factory() -> KnownFragmentName {
-        KnownFragmentName::default()
+        KnownFragmentName
     }
     #[test]
     fn include_known_fragment() {
@@ -61,7 +61,7 @@ mod tests {
     use super::*;
 
     fn factory() -> KnownTypeNames {
-        KnownTypeNames::default()
+        KnownTypeNames
     }
 
     #[test]
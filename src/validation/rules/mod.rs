@@ -10,6 +10,7 @@ mod no_fragment_cycles;
 mod no_undefined_variables;
 mod no_unused_fragments;
 mod no_unused_variables;
+mod one_of_input_objects;
 mod overlapping_fields_can_be_merged;
 mod possible_fragment_spreads;
 mod provided_non_null_arguments;
@@ -29,8 +30,8 @@ pub use known_fragment_names::KnownFragmentName;
 pub use known_type_names::KnownTypeNames;
 pub use no_fragment_cycles::NoFragmentCycles;
 pub use no_undefined_variables::NoUndefinedVariables;
-pub use no_unused_fragments::NoUnusedFragment;
 pub use no_unused_variables::NoUnusedVariables;
+pub use one_of_input_objects::OneOfInputObjects;
 pub use overlapping_fields_can_be_merged::OverlappingFieldsCanBeMerged;
 pub use possible_fragment_spreads::PossibleFragmentSpreads;
 pub use provided_non_null_arguments::ProvidedNonNullArguments;
@@ -92,7 +92,7 @@ impl<'a> Visitor<'a> for NoFragmentCycles<'a> {
         if let Some(current_fragment) = self.current_fragment {
             self.fragment_spreads
                 .entry(current_fragment)
-                .or_insert_with(Vec::new)
+                .or_default()
                 .push(&fragment_spread.fragment_name);
         }
     }
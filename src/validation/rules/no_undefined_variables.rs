@@ -125,14 +125,11 @@ impl<'a> Visitor<'a> for NoUndefinedVariables<'a> {
         arg_value: &'a graphql_parser::schema::Value<'a, String>,
     ) {
         if let Some(scope) = &self.current_scope {
-            self.used_variables
-                .entry(*scope)
-                .or_insert_with(HashMap::new)
-                .extend(
-                    referenced_variables(arg_value)
-                        .into_iter()
-                        .map(|n| (n, Pos::default())),
-                );
+            self.used_variables.entry(*scope).or_default().extend(
+                referenced_variables(arg_value)
+                    .into_iter()
+                    .map(|n| (n, Pos::default())),
+            );
         }
     }
 
@@ -144,7 +141,7 @@ impl<'a> Visitor<'a> for NoUndefinedVariables<'a> {
         if let Some(scope) = &self.current_scope {
             self.fragment_spreads
                 .entry(*scope)
-                .or_insert_with(Vec::new)
+                .or_default()
                 .push(&fragment_spread.fragment_name);
         }
     }
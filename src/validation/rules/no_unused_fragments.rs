@@ -10,6 +10,7 @@ use crate::validation::{
     visitor::{ValidationContext, Visitor},
 };
 
+#[allow(dead_code)]
 #[derive(Default)]
 pub struct NoUnusedFragment<'a> {
     current_scope: Option<Scope<'a>>,
@@ -17,6 +18,7 @@ pub struct NoUnusedFragment<'a> {
     fragment_definitions: HashSet<(&'a str, Pos)>,
 }
 
+#[allow(dead_code)]
 impl<'a> NoUnusedFragment<'a> {
     fn get_reachable_fragments(&self, from: &Scope<'a>, result: &mut HashSet<&'a str>) {
         if let Scope::Fragment(name) = from {
@@ -84,7 +86,7 @@ impl<'a> Visitor<'a> for NoUnusedFragment<'a> {
         if let Some(scope) = &self.current_scope {
             self.fragment_spreads
                 .entry(*scope)
-                .or_insert_with(Vec::new)
+                .or_default()
                 .push(&fragment_spread.fragment_name)
         }
     }
@@ -108,7 +108,7 @@ impl<'a> Visitor<'a> for NoUnusedVariables<'a> {
         if let Some(scope) = &self.current_scope {
             self.used_variables
                 .entry(*scope)
-                .or_insert_with(Vec::new)
+                .or_default()
                 .append(&mut referenced_variables(arg_value))
         }
     }
@@ -121,7 +121,7 @@ impl<'a> Visitor<'a> for NoUnusedVariables<'a> {
         if let Some(scope) = &self.current_scope {
             self.fragment_spreads
                 .entry(*scope)
-                .or_insert_with(Vec::new)
+                .or_default()
                 .push(&fragment_spread.fragment_name);
         }
     }
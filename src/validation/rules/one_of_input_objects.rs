@@ -0,0 +1,185 @@
+use graphql_parser::{
+    query::{Field, OperationDefinition, VariableDefinition},
+    schema::{Directive, Value},
+};
+
+use crate::{
+    validation::{
+        utils::check_one_of_input_value,
+        visitor::{ValidationContext, Visitor},
+    },
+    GqlValue, GqlValueType,
+};
+
+#[derive(Default)]
+pub struct OneOfInputObjects<'a> {
+    variable_definitions: Vec<&'a VariableDefinition<'a, String>>,
+    current_args: Option<Vec<(String, GqlValueType)>>,
+}
+
+impl<'a> Visitor<'a> for OneOfInputObjects<'a> {
+    fn enter_operation_definition(
+        &mut self,
+        _ctx: &mut ValidationContext<'a>,
+        _name: Option<&'a str>,
+        _operation_definition: &'a OperationDefinition<'a, String>,
+    ) {
+        self.variable_definitions.clear();
+    }
+
+    fn enter_variable_definition(
+        &mut self,
+        _ctx: &mut ValidationContext,
+        variable_definition: &'a VariableDefinition<'a, String>,
+    ) {
+        self.variable_definitions.push(variable_definition);
+    }
+
+    fn enter_directive(
+        &mut self,
+        ctx: &mut ValidationContext,
+        directive: &'a Directive<'a, String>,
+    ) {
+        self.current_args = ctx.schema.directives.get(&directive.name).map(|dir| {
+            dir.arguments
+                .iter()
+                .map(|arg| (arg.name.clone(), arg.meta_type.clone()))
+                .collect()
+        });
+    }
+
+    fn exit_directive(
+        &mut self,
+        _ctx: &mut ValidationContext,
+        _directive: &'a Directive<'a, String>,
+    ) {
+        self.current_args = None;
+    }
+
+    fn enter_field(&mut self, ctx: &mut ValidationContext, field: &'a Field<'a, String>) {
+        self.current_args = ctx
+            .parent_type()
+            .and_then(|ty| ty.get_field_by_name(&field.name))
+            .map(|target_field| {
+                target_field
+                    .arguments
+                    .iter()
+                    .map(|arg| (arg.name.clone(), arg.meta_type.clone()))
+                    .collect()
+            });
+    }
+
+    fn exit_field(&mut self, _ctx: &mut ValidationContext, _field: &'a Field<'a, String>) {
+        self.current_args = None;
+    }
+
+    fn enter_argument(
+        &mut self,
+        ctx: &mut ValidationContext,
+        arg_name: &'a str,
+        arg_value: &'a Value<'a, String>,
+    ) {
+        let arg_type = self
+            .current_args
+            .as_ref()
+            .and_then(|args| args.iter().find(|(name, _)| name == arg_name))
+            .map(|(_, ty)| ty);
+
+        if let Some(ty) = arg_type {
+            if let Some(message) = check_one_of_input_value(
+                ctx.schema,
+                ty,
+                &GqlValue::from(arg_value.clone()),
+                &self.variable_definitions,
+            ) {
+                ctx.add_error(
+                    format!("Invalid value for argument {}: {}", arg_name, message),
+                    vec![],
+                );
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{check_fails_rule, check_passes_rule};
+
+    use super::*;
+
+    fn factory<'a>() -> OneOfInputObjects<'a> {
+        OneOfInputObjects::default()
+    }
+
+    #[test]
+    fn one_of_with_single_field() {
+        let query_doc = r#"
+        {
+            searchCharacter(by: { id: "1" }) {
+                name
+            }
+        }
+        "#;
+        check_passes_rule!(query_doc, factory);
+    }
+
+    #[test]
+    fn one_of_with_non_null_variable() {
+        let query_doc = r#"
+        query ($name: String!) {
+            searchCharacter(by: { name: $name }) {
+                name
+            }
+        }
+        "#;
+        check_passes_rule!(query_doc, factory);
+    }
+
+    #[test]
+    fn one_of_with_multiple_fields() {
+        let query_doc = r#"
+        {
+            searchCharacter(by: { id: "1", name: "Luke" }) {
+                name
+            }
+        }
+        "#;
+        check_fails_rule!(query_doc, factory);
+    }
+
+    #[test]
+    fn one_of_with_no_fields() {
+        let query_doc = r#"
+        {
+            searchCharacter(by: {}) {
+                name
+            }
+        }
+        "#;
+        check_fails_rule!(query_doc, factory);
+    }
+
+    #[test]
+    fn one_of_with_null_field() {
+        let query_doc = r#"
+        {
+            searchCharacter(by: { id: null }) {
+                name
+            }
+        }
+        "#;
+        check_fails_rule!(query_doc, factory);
+    }
+
+    #[test]
+    fn one_of_with_nullable_variable() {
+        let query_doc = r#"
+        query ($name: String) {
+            searchCharacter(by: { name: $name }) {
+                name
+            }
+        }
+        "#;
+        check_fails_rule!(query_doc, factory);
+    }
+}
@@ -16,7 +16,7 @@ impl<'a> Visitor<'a> for ScalarLeafs {
                 {
                     if ty.is_leaf_type() && !field.selection_set.items.is_empty() {
                         ctx.add_error(
-                        format!("Field {} must not have a selection items because type {} has no subfields", &field.name, ty.to_string()),
+                        format!("Field {} must not have a selection items because type {} has no subfields", &field.name, ty),
                         vec![field.position])
                     } else if !ty.is_leaf_type() && field.selection_set.items.is_empty() {
                         ctx.add_error(
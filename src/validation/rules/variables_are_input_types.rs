@@ -22,8 +22,7 @@ impl<'a> Visitor<'a> for VariablesAreInputTypes {
                 ctx.add_error(
                     format!(
                         "Variable {} cannot be non-input type {}",
-                        &variable_definition.name,
-                        variable_type.to_string()
+                        &variable_definition.name, variable_type
                     ),
                     vec![variable_definition.position],
                 );
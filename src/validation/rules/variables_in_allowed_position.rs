@@ -101,7 +101,7 @@ impl<'a> Visitor<'a> for VariablesInAllowedPosition<'a> {
         if let Some(scope) = &self.current_scope {
             self.variable_definitions
                 .entry(*scope)
-                .or_insert_with(Vec::new)
+                .or_default()
                 .push(variable_definition)
         }
     }
@@ -114,7 +114,7 @@ impl<'a> Visitor<'a> for VariablesInAllowedPosition<'a> {
         if let Some(scope) = &self.current_scope {
             self.fragment_spreads
                 .entry(*scope)
-                .or_insert_with(HashSet::new)
+                .or_default()
                 .insert(&fragment_spread.fragment_name);
         }
     }
@@ -129,10 +129,11 @@ impl<'a> Visitor<'a> for VariablesInAllowedPosition<'a> {
         if let Value::Variable(var_name) = value {
             if let Some(ty) = expected_type {
                 if let Some(scope) = &self.current_scope {
-                    self.variable_usages
-                        .entry(*scope)
-                        .or_insert_with(Vec::new)
-                        .push((var_name, pos, ty.clone()))
+                    self.variable_usages.entry(*scope).or_default().push((
+                        var_name,
+                        pos,
+                        ty.clone(),
+                    ))
                 }
             }
         }
@@ -35,19 +35,19 @@ where
 #[macro_export]
 macro_rules! check_passes_rule {
     ($query_doc: expr, $factory: expr $(,)?) => {
-        let schema = &crate::validation::test_utils::test_schema();
-        let doc = &crate::validation::test_utils::parse_test_query($query_doc);
-        let operation = crate::validation::test_utils::build_test_operation(doc);
-        crate::validation::test_utils::assert_passes_rule(doc, schema, &operation, $factory);
+        let schema = &$crate::validation::test_utils::test_schema();
+        let doc = &$crate::validation::test_utils::parse_test_query($query_doc);
+        let operation = $crate::validation::test_utils::build_test_operation(doc);
+        $crate::validation::test_utils::assert_passes_rule(doc, schema, &operation, $factory);
     };
 }
 #[macro_export]
 macro_rules! check_fails_rule {
     ($query_doc: expr, $factory: expr $(,)?) => {
-        let schema = &crate::validation::test_utils::test_schema();
-        let doc = &crate::validation::test_utils::parse_test_query($query_doc);
-        let operation = crate::validation::test_utils::build_test_operation(doc);
-        crate::validation::test_utils::assert_fails_rule(doc, schema, &operation, $factory);
+        let schema = &$crate::validation::test_utils::test_schema();
+        let doc = &$crate::validation::test_utils::parse_test_query($query_doc);
+        let operation = $crate::validation::test_utils::build_test_operation(doc);
+        $crate::validation::test_utils::assert_fails_rule(doc, schema, &operation, $factory);
     };
 }
 
@@ -1,12 +1,12 @@
 use std::collections::HashSet;
 
 use graphql_parser::{
-    query::{OperationDefinition, TypeCondition},
+    query::{OperationDefinition, TypeCondition, VariableDefinition},
     schema::{Type, Value},
     Pos,
 };
 
-use crate::{types::GqlValueType, Schema, TypeDefinition};
+use crate::{types::GqlValueType, GqlValue, Schema, TypeDefinition};
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
 pub enum Scope<'a> {
@@ -30,6 +30,10 @@ pub fn check_valid_input_value(
     ty: &Type<'_, String>,
     value: &Value<'_, String>,
 ) -> Option<String> {
+    // Variables are checked by VariablesInAllowedPosition.
+    if let Value::Variable(_) = value {
+        return None;
+    }
     match ty {
         Type::NamedType(type_name) => {
             if let Value::Null = value {
@@ -38,13 +42,18 @@ pub fn check_valid_input_value(
             let type_def = schema.type_definitions.get(type_name);
             match type_def {
                 Some(def) => match def {
-                    TypeDefinition::Scalar(scalar) => {
-                        if scalar.is_valid_value(value) {
-                            None
-                        } else {
-                            Some("Invalid type".to_string())
+                    TypeDefinition::Scalar(scalar) => match schema.custom_scalars.get(type_name) {
+                        Some(custom_scalar) => custom_scalar
+                            .parse_literal(GqlValue::from(value.clone()))
+                            .err(),
+                        None => {
+                            if scalar.is_valid_value(value) {
+                                None
+                            } else {
+                                Some(format!("{} is invalid value for {}", value, type_name))
+                            }
                         }
-                    }
+                    },
                     TypeDefinition::InputObject(input_object) => match value {
                         Value::Object(object_value) => {
                             let mut value_keys: HashSet<String> =
@@ -53,11 +62,13 @@ pub fn check_valid_input_value(
                             for field in &input_object.fields {
                                 value_keys.remove(&field.name);
                                 if let Some(value) = object_value.get(&field.name) {
-                                    return check_valid_input_value(
+                                    if let Some(msg) = check_valid_input_value(
                                         schema,
                                         &field.meta_type.to_parser_type(),
                                         value,
-                                    );
+                                    ) {
+                                        return Some(msg);
+                                    }
                                 } else if field.default_value.is_none()
                                     && matches!(field.meta_type, GqlValueType::NonNullType(_))
                                 {
@@ -75,30 +86,20 @@ pub fn check_valid_input_value(
                             }
                             None
                         }
-                        _ => None,
+                        _ => Some(format!("{} is invalid value for {}", value, type_name)),
                     },
                     TypeDefinition::Enum(enum_value) => match value {
-                        Value::String(name) => {
+                        Value::String(name) | Value::Enum(name) => {
                             if enum_value.contains(name) {
-                                Some(format!(
-                                    "Enum type {} does not contain the value {}",
-                                    enum_value.name, name
-                                ))
-                            } else {
                                 None
-                            }
-                        }
-                        Value::Enum(name) => {
-                            if enum_value.contains(name) {
+                            } else {
                                 Some(format!(
                                     "Enum type {} does not contain the value {}",
                                     enum_value.name, name
                                 ))
-                            } else {
-                                None
                             }
                         }
-                        _ => None,
+                        _ => Some(format!("{} is invalid value for {}", value, type_name)),
                     },
                     _ => None,
                 },
@@ -128,6 +129,67 @@ pub fn check_valid_input_value(
     }
 }
 
+pub fn check_one_of_input_value(
+    schema: &Schema,
+    ty: &GqlValueType,
+    value: &GqlValue,
+    variable_definitions: &[&VariableDefinition<'_, String>],
+) -> Option<String> {
+    match (ty, value) {
+        (GqlValueType::NonNullType(non_null_type), _) => {
+            check_one_of_input_value(schema, non_null_type, value, variable_definitions)
+        }
+        (GqlValueType::ListType(list_type), GqlValue::List(values)) => values
+            .iter()
+            .find_map(|v| check_one_of_input_value(schema, list_type, v, variable_definitions)),
+        (GqlValueType::ListType(list_type), _) => {
+            check_one_of_input_value(schema, list_type, value, variable_definitions)
+        }
+        (GqlValueType::NamedType(type_name), GqlValue::Object(object_value)) => {
+            let input_object = match schema.type_definitions.get(type_name) {
+                Some(TypeDefinition::InputObject(input_object)) => input_object,
+                _ => return None,
+            };
+            if input_object.is_one_of() {
+                if object_value.len() != 1 {
+                    return Some(format!(
+                        "OneOf input object {} must specify exactly one field",
+                        type_name
+                    ));
+                }
+                for (field_name, field_value) in object_value {
+                    match field_value {
+                        GqlValue::Null => {
+                            return Some(format!(
+                                "Field {}.{} of OneOf input object must be non-null",
+                                type_name, field_name
+                            ))
+                        }
+                        GqlValue::Variable(var_name)
+                            if variable_definitions.iter().any(|def| {
+                                &def.name == var_name
+                                    && !matches!(def.var_type, Type::NonNullType(_))
+                            }) =>
+                        {
+                            return Some(format!(
+                                "Variable {} for field {}.{} of OneOf input object must be non-null type",
+                                var_name, type_name, field_name
+                            ))
+                        }
+                        _ => {}
+                    }
+                }
+            }
+            input_object.fields.iter().find_map(|field| {
+                object_value.get(&field.name).and_then(|v| {
+                    check_one_of_input_value(schema, &field.meta_type, v, variable_definitions)
+                })
+            })
+        }
+        _ => None,
+    }
+}
+
 pub fn get_type_name(ty: &Type<'_, String>) -> String {
     match ty {
         Type::NamedType(named_type) => named_type.to_string(),
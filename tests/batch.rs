@@ -0,0 +1,169 @@
+use rusty_gql::*;
+
+struct Query;
+
+#[GqlType]
+impl Query {
+    async fn value(&self) -> i32 {
+        10
+    }
+}
+
+fn build_container(
+    batch_limit: Option<usize>,
+) -> Container<Query, EmptyMutation, EmptySubscription> {
+    let contents = schema_content("./tests/schemas/test_schema.graphql");
+    let schema_doc = [contents.as_str()];
+    let mut builder = Container::builder(&schema_doc, Query, EmptyMutation, EmptySubscription)
+        .batch_concurrency(2);
+    if let Some(batch_limit) = batch_limit {
+        builder = builder.limit_batch(batch_limit);
+    }
+    builder.build().unwrap()
+}
+
+async fn check_batch_response(
+    body: &str,
+    expected_response: &str,
+    container: &Container<Query, EmptyMutation, EmptySubscription>,
+) {
+    let mut headers = http::HeaderMap::new();
+    headers.insert(
+        http::header::CONTENT_TYPE,
+        "application/json".parse().unwrap(),
+    );
+    let request = receive_batch_http_request(
+        &http::Method::POST,
+        &headers,
+        None,
+        futures_util::io::Cursor::new(body.as_bytes().to_vec()),
+        Default::default(),
+    )
+    .await
+    .unwrap();
+    let res = execute_batch(container, request).await;
+    assert_eq!(serde_json::to_string(&res).unwrap(), expected_response);
+}
+
+#[tokio::test]
+pub async fn test_single_request() {
+    let container = build_container(None);
+    check_batch_response(
+        r#"{"query": "{ value }"}"#,
+        r#"{"data":{"value":10}}"#,
+        &container,
+    )
+    .await;
+}
+
+#[tokio::test]
+pub async fn test_batch_request() {
+    let container = build_container(None);
+    check_batch_response(
+        r#"[{"query": "{ value }"}, {"query": "{ __typename }"}, {"query": "{ invalid }"}]"#,
+        r#"[{"data":{"value":10}},{"data":{"__typename":"Query"}},{"data":null,"errors":[{"message":"Unknown field \"invalid\" on type \"Query\"","locations":[{"line":1,"column":3}],"path":[],"extensions":null}]}]"#,
+        &container,
+    )
+    .await;
+}
+
+#[tokio::test]
+pub async fn test_batch_limit() {
+    let container = build_container(Some(2));
+    check_batch_response(
+        r#"[{"query": "{ value }"}, {"query": "{ value }"}, {"query": "{ value }"}]"#,
+        r#"{"data":null,"errors":[{"message":"Batch size 3 exceeds the maximum batch size 2.","locations":[],"path":[],"extensions":null}]}"#,
+        &container,
+    )
+    .await;
+}
+
+#[tokio::test]
+pub async fn test_default_batch_limit() {
+    let container = build_container(None);
+    let body = format!("[{}]", [r#"{"query": "{ value }"}"#; 11].join(", "));
+    check_batch_response(
+        &body,
+        r#"{"data":null,"errors":[{"message":"Batch size 11 exceeds the maximum batch size 10.","locations":[],"path":[],"extensions":null}]}"#,
+        &container,
+    )
+    .await;
+}
+
+#[tokio::test]
+pub async fn test_invalid_batch_request() {
+    let mut headers = http::HeaderMap::new();
+    headers.insert(
+        http::header::CONTENT_TYPE,
+        "application/json".parse().unwrap(),
+    );
+    for (body, message) in [
+        ("[]", "batch request must not be empty"),
+        (
+            r#"[{"query": 1}]"#,
+            "invalid type: integer `1`, expected a string at line 1 column 12",
+        ),
+    ] {
+        let result = receive_batch_http_request(
+            &http::Method::POST,
+            &headers,
+            None,
+            futures_util::io::Cursor::new(body.as_bytes().to_vec()),
+            Default::default(),
+        )
+        .await;
+        match result {
+            Err(HttpRequestError::InvalidRequest(err)) => assert_eq!(err.to_string(), message),
+            _ => panic!("{} is invalid", body),
+        }
+    }
+}
+
+struct Mutation {
+    values: std::sync::Mutex<Vec<i32>>,
+}
+
+#[GqlType]
+impl Mutation {
+    async fn push(&self, value: i32, yields: i32) -> Vec<i32> {
+        for _ in 0..yields {
+            tokio::task::yield_now().await;
+        }
+        let mut values = self.values.lock().unwrap();
+        values.push(value);
+        values.clone()
+    }
+}
+
+#[tokio::test]
+pub async fn test_batch_mutations_in_order() {
+    let schema_doc = r#"
+    type Query {
+      value: Int
+    }
+
+    type Mutation {
+      push(value: Int!, yields: Int!): [Int!]!
+    }
+    "#;
+    let mutation = Mutation {
+        values: Default::default(),
+    };
+    let container = Container::builder(&[schema_doc], Query, mutation, EmptySubscription)
+        .build()
+        .unwrap();
+
+    let request = serde_json::from_str::<BatchRequest>(
+        r#"[
+            {"query": "mutation { push(value: 1, yields: 10) }"},
+            {"query": "{ value }"},
+            {"query": "mutation { push(value: 2, yields: 0) }"}
+        ]"#,
+    )
+    .unwrap();
+    let res = execute_batch(&container, request).await;
+    assert_eq!(
+        serde_json::to_string(&res).unwrap(),
+        r#"[{"data":{"push":[1]}},{"data":{"value":10}},{"data":{"push":[1,2]}}]"#
+    );
+}
@@ -0,0 +1,161 @@
+use rusty_gql::*;
+
+#[derive(Clone)]
+struct Person {
+    name: String,
+}
+
+#[GqlType]
+impl Person {
+    async fn name(&self) -> String {
+        self.name.clone()
+    }
+}
+
+fn all_persons() -> Vec<Person> {
+    ["Tom", "Mary", "John", "Emma"]
+        .iter()
+        .map(|name| Person {
+            name: name.to_string(),
+        })
+        .collect()
+}
+
+struct Query;
+
+#[GqlType]
+impl Query {
+    async fn persons(
+        &self,
+        first: Option<i32>,
+        after: Option<String>,
+        last: Option<i32>,
+        before: Option<String>,
+    ) -> Result<Connection<Person>, Error> {
+        let connection = connection_from_slice(
+            &all_persons(),
+            first,
+            after.as_deref(),
+            last,
+            before.as_deref(),
+        )?;
+        Ok(connection)
+    }
+
+    async fn friends(
+        &self,
+        first: Option<i32>,
+        after: Option<String>,
+    ) -> Result<Connection<Person>, Error> {
+        let persons = all_persons();
+        let connection =
+            connection_from_fetch(
+                persons.len(),
+                first,
+                after.as_deref(),
+                None,
+                None,
+                |offset, limit| async move {
+                    Ok(persons.into_iter().skip(offset).take(limit).collect())
+                },
+            )
+            .await?;
+        Ok(connection.with_name("Friends"))
+    }
+}
+
+#[tokio::test]
+pub async fn test_connection_from_slice() {
+    let contents = schema_content("./tests/schemas/connection.graphql");
+    let container = Container::builder(
+        &[contents.as_str()],
+        Query,
+        EmptyMutation,
+        EmptySubscription,
+    )
+    .build()
+    .unwrap();
+
+    let query_doc = r#"{
+        persons(first: 2) {
+            __typename
+            totalCount
+            edges { __typename node { name } }
+            pageInfo { hasPreviousPage hasNextPage endCursor }
+        }
+    }"#;
+    let req = build_test_request(query_doc, None, Default::default());
+    let expected_response = serde_json::json!({
+        "data": {
+            "persons": {
+                "__typename": "PersonConnection",
+                "edges": [
+                    { "__typename": "PersonEdge", "node": { "name": "Tom" } },
+                    { "__typename": "PersonEdge", "node": { "name": "Mary" } },
+                ],
+                "pageInfo": {
+                    "endCursor": encode_cursor(1),
+                    "hasNextPage": true,
+                    "hasPreviousPage": false,
+                },
+                "totalCount": 4,
+            }
+        }
+    });
+    check_gql_response(req, &expected_response.to_string(), &container).await;
+}
+
+#[tokio::test]
+pub async fn test_connection_from_fetch() {
+    let contents = schema_content("./tests/schemas/connection.graphql");
+    let container = Container::builder(
+        &[contents.as_str()],
+        Query,
+        EmptyMutation,
+        EmptySubscription,
+    )
+    .build()
+    .unwrap();
+
+    let query_doc = format!(
+        r#"{{
+            friends(first: 5, after: "{}") {{
+                __typename
+                edges {{ __typename cursor node {{ name }} }}
+                pageInfo {{ hasPreviousPage hasNextPage }}
+            }}
+        }}"#,
+        encode_cursor(1)
+    );
+    let req = build_test_request(&query_doc, None, Default::default());
+    let expected_response = serde_json::json!({
+        "data": {
+            "friends": {
+                "__typename": "FriendsConnection",
+                "edges": [
+                    { "__typename": "FriendsEdge", "cursor": encode_cursor(2), "node": { "name": "John" } },
+                    { "__typename": "FriendsEdge", "cursor": encode_cursor(3), "node": { "name": "Emma" } },
+                ],
+                "pageInfo": {
+                    "hasNextPage": false,
+                    "hasPreviousPage": true,
+                },
+            }
+        }
+    });
+    check_gql_response(req, &expected_response.to_string(), &container).await;
+}
+
+#[test]
+pub fn test_connection_check_resolvers() {
+    let contents = schema_content("./tests/schemas/connection.graphql");
+    let container = Container::builder(
+        &[contents.as_str()],
+        Query,
+        EmptyMutation,
+        EmptySubscription,
+    )
+    .check_resolvers()
+    .build();
+    assert!(container.is_ok());
+}
@@ -0,0 +1,291 @@
+use std::collections::BTreeMap;
+
+use rusty_gql::*;
+
+struct Person {
+    name: String,
+    age: i32,
+}
+
+#[GqlType]
+impl Person {
+    async fn name(&self) -> String {
+        self.name.clone()
+    }
+    async fn description(&self) -> Option<String> {
+        None
+    }
+    async fn age(&self) -> i32 {
+        self.age
+    }
+}
+
+struct Query;
+
+#[GqlType]
+impl Query {
+    async fn value(&self, ctx: &Context<'_>) -> i32 {
+        *ctx.get_data::<i32>().unwrap()
+    }
+
+    #[allow(unused)]
+    async fn person(&self, id: ID) -> Person {
+        Person {
+            name: "Tom".to_string(),
+            age: 20,
+        }
+    }
+}
+
+#[tokio::test]
+pub async fn test_data() {
+    let contents = schema_content("./tests/schemas/test_schema.graphql");
+
+    let container = Container::builder(
+        &[contents.as_str()],
+        Query,
+        EmptyMutation,
+        EmptySubscription,
+    )
+    .data(100_i32)
+    .build()
+    .unwrap();
+
+    let query_doc = r#"{ value }"#;
+    let req = build_test_request(query_doc, None, Default::default());
+    let expected_response = r#"{"data":{"value":100}}"#;
+    check_gql_response(req, expected_response, &container).await;
+}
+
+#[tokio::test]
+pub async fn test_limit_depth() {
+    let contents = schema_content("./tests/schemas/test_schema.graphql");
+
+    let container = Container::builder(
+        &[contents.as_str()],
+        Query,
+        EmptyMutation,
+        EmptySubscription,
+    )
+    .limit_depth(1)
+    .build()
+    .unwrap();
+
+    let query_doc = r#"{ person(id: 1) { name } }"#;
+    let req = build_test_request(query_doc, None, Default::default());
+    let expected_response = r#"{"data":null,"errors":[{"message":"Query depth 2 exceeds the maximum depth limit 1.","locations":[],"path":[],"extensions":null}]}"#;
+    check_gql_response(req, expected_response, &container).await;
+}
+
+#[tokio::test]
+pub async fn test_extension() {
+    struct FixedQuery;
+
+    #[async_trait::async_trait]
+    impl Extension for FixedQuery {
+        async fn prepare_request(&self, mut request: Request) -> ResolverResult<Request> {
+            if request.query.is_empty() {
+                request.query = "{ person(id: 1) { name } }".to_string();
+            }
+            Ok(request)
+        }
+    }
+
+    let contents = schema_content("./tests/schemas/test_schema.graphql");
+
+    let container = Container::builder(
+        &[contents.as_str()],
+        Query,
+        EmptyMutation,
+        EmptySubscription,
+    )
+    .extension(FixedQuery)
+    .build()
+    .unwrap();
+
+    let req = build_test_request("", None, Default::default());
+    let expected_response = r#"{"data":{"person":{"name":"Tom"}}}"#;
+    check_gql_response(req, expected_response, &container).await;
+}
+
+#[tokio::test]
+pub async fn test_directive() {
+    struct Auth;
+
+    #[async_trait::async_trait]
+    impl CustomDirective for Auth {
+        async fn resolve_field(
+            &self,
+            _ctx: &Context<'_>,
+            directive_args: &BTreeMap<String, GqlValue>,
+            resolve_fut: ResolveFut<'_>,
+        ) -> ResolverResult<Option<GqlValue>> {
+            let requires = directive_args.get("requires");
+            resolve_fut.await.map(|v| match requires {
+                Some(GqlValue::Enum(role)) if role == "ADMIN" => None,
+                _ => v,
+            })
+        }
+    }
+
+    let contents = schema_content("./tests/schemas/custom_directive.graphql");
+
+    let container = Container::builder(
+        &[contents.as_str()],
+        Query,
+        EmptyMutation,
+        EmptySubscription,
+    )
+    .directive("auth", Auth)
+    .build()
+    .unwrap();
+
+    let query_doc = r#"{ person(id: 1) { name age } }"#;
+    let req = build_test_request(query_doc, None, Default::default());
+    let expected_response = r#"{"data":{"person":{"age":20,"name":null}}}"#;
+    check_gql_response(req, expected_response, &container).await;
+}
+
+#[tokio::test]
+pub async fn test_undefined_directive() {
+    struct Noop;
+
+    #[async_trait::async_trait]
+    impl CustomDirective for Noop {
+        async fn resolve_field(
+            &self,
+            _ctx: &Context<'_>,
+            _directive_args: &BTreeMap<String, GqlValue>,
+            resolve_fut: ResolveFut<'_>,
+        ) -> ResolverResult<Option<GqlValue>> {
+            resolve_fut.await
+        }
+    }
+
+    let contents = schema_content("./tests/schemas/test_schema.graphql");
+
+    let result = Container::builder(
+        &[contents.as_str()],
+        Query,
+        EmptyMutation,
+        EmptySubscription,
+    )
+    .directive("undefined", Noop)
+    .build();
+
+    match result {
+        Ok(_) => panic!("undefined directive should be rejected"),
+        Err(errors) => assert_eq!(
+            errors[0].message,
+            "Directive @undefined is not defined in the schema."
+        ),
+    }
+}
+
+#[test]
+pub fn test_check_resolvers() {
+    struct User;
+
+    #[GqlType]
+    impl User {
+        async fn name(&self) -> String {
+            "rusty".to_string()
+        }
+
+        #[field(name = "mail")]
+        async fn email(&self) -> String {
+            "rusty@example.com".to_string()
+        }
+    }
+
+    struct Query;
+
+    #[GqlType]
+    impl Query {
+        async fn user(&self) -> Option<User> {
+            Some(User)
+        }
+
+        async fn users(&self) -> Vec<User> {
+            vec![User]
+        }
+
+        async fn count(&self) -> i32 {
+            1
+        }
+    }
+
+    let schema = r#"
+    type Query {
+      user: User
+      users: [User!]!
+      count: Int!
+    }
+    type User {
+      name: String!
+      mail: String!
+    }
+    "#;
+    let container = Container::builder(&[schema], Query, EmptyMutation, EmptySubscription)
+        .check_resolvers()
+        .build();
+    assert!(container.is_ok());
+
+    let schema = r#"
+    type Query {
+      user: User
+      count: Int!
+      total: Int!
+      post: Post
+    }
+    type User {
+      name: String!
+      email: String!
+    }
+    type Post {
+      title: String!
+    }
+    "#;
+    let errors = match Container::builder(&[schema], Query, EmptyMutation, EmptySubscription)
+        .check_resolvers()
+        .build()
+    {
+        Ok(_) => panic!("resolvers should not conform to the schema"),
+        Err(errors) => errors,
+    };
+    let messages: Vec<String> = errors.into_iter().map(|err| err.message).collect();
+    assert_eq!(
+        messages,
+        vec![
+            "Post has no resolver.",
+            "Query.total has no resolver.",
+            "Query.post has no resolver.",
+            "Query.users is not defined in the schema.",
+            "User.email has no resolver.",
+            "User.mail is not defined in the schema.",
+        ]
+    );
+}
+
+#[test]
+pub fn test_read_schemas_in_subdirectories() {
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::create_dir_all(dir.path().join("types")).unwrap();
+    std::fs::write(
+        dir.path().join("query.graphql"),
+        "type Query { user: User }",
+    )
+    .unwrap();
+    std::fs::write(
+        dir.path().join("types").join("user.graphql"),
+        "type User { name: String! }",
+    )
+    .unwrap();
+
+    let mut schemas = read_schemas(dir.path()).unwrap();
+    schemas.sort();
+    assert_eq!(
+        schemas,
+        vec!["type Query { user: User }", "type User { name: String! }"]
+    );
+}
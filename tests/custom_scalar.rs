@@ -60,3 +60,135 @@ pub async fn test_custom_scalar() {
     let expected_response = r#"{"data":{"test_custom_scalar":{"test":"Custom-Sample"}}}"#;
     check_gql_response(req, expected_response, &container).await;
 }
+
+#[tokio::test]
+pub async fn test_custom_scalar_coercion() {
+    struct Query;
+
+    // Accepts "YYYY-MM-DD" and stores the date as "YYYYMMDD" internally.
+    struct DateScalar;
+
+    impl CustomScalar for DateScalar {
+        fn parse_value(&self, value: GqlValue) -> Result<GqlValue, String> {
+            match value {
+                GqlValue::String(v) if v.len() == 10 && v.split('-').count() == 3 => {
+                    Ok(GqlValue::String(v.replace('-', "")))
+                }
+                v => Err(format!("{} is invalid value for Date", v)),
+            }
+        }
+
+        fn serialize(&self, value: GqlValue) -> Result<GqlValue, String> {
+            match value {
+                GqlValue::String(v) if v.len() == 8 => Ok(GqlValue::String(format!(
+                    "{}-{}-{}",
+                    &v[0..4],
+                    &v[4..6],
+                    &v[6..8]
+                ))),
+                v => Err(format!("{} is invalid value for Date", v)),
+            }
+        }
+    }
+
+    #[derive(Clone, GqlScalar)]
+    struct Date(String);
+
+    impl GqlInputType for Date {
+        fn from_gql_value(value: Option<GqlValue>) -> Result<Self, String> {
+            match value {
+                Some(GqlValue::String(v)) => Ok(Date(v)),
+                _ => Err("invalid Date".to_string()),
+            }
+        }
+
+        fn to_gql_value(&self) -> GqlValue {
+            GqlValue::String(self.0.clone())
+        }
+    }
+
+    struct Event {
+        date: Date,
+    }
+
+    #[GqlType]
+    impl Event {
+        async fn date(&self) -> Date {
+            self.date.clone()
+        }
+    }
+
+    #[GqlType]
+    impl Query {
+        #[allow(unused)]
+        async fn test_custom_scalar(&self) -> Option<Event> {
+            None
+        }
+
+        async fn event(&self, date: Date) -> Event {
+            assert_eq!(date.0.len(), 8);
+            Event { date }
+        }
+    }
+    let contents = schema_content("./tests/schemas/custom_scalar.graphql");
+    let schema_doc = [contents.as_str()];
+
+    let container = Container::builder(&schema_doc, Query, EmptyMutation, EmptySubscription)
+        .scalar("Date", DateScalar)
+        .build()
+        .unwrap();
+
+    let query_doc = r#"{ event(date: "2022-01-02") { date } }"#;
+    let req = build_test_request(query_doc, None, Default::default());
+    let expected_response = r#"{"data":{"event":{"date":"2022-01-02"}}}"#;
+    check_gql_response(req, expected_response, &container).await;
+
+    let query_doc = r#"{ event(date: "20220102") { date } }"#;
+    let req = build_test_request(query_doc, None, Default::default());
+    let expected_response = r#"{"data":null,"errors":[{"message":"Invalid value for argument date: 20220102 is invalid value for Date","locations":[],"path":[],"extensions":null}]}"#;
+    check_gql_response(req, expected_response, &container).await;
+
+    let query_doc = r#"query ($date: Date!) { event(date: $date) { date } }"#;
+    let variables = serde_json::from_str::<Variables>(r#"{"date": "2022-01-02"}"#).unwrap();
+    let req = build_test_request(query_doc, None, variables);
+    let expected_response = r#"{"data":{"event":{"date":"2022-01-02"}}}"#;
+    check_gql_response(req, expected_response, &container).await;
+
+    let query_doc = r#"query ($date: Date!) { event(date: $date) { date } }"#;
+    let variables = serde_json::from_str::<Variables>(r#"{"date": "tomorrow"}"#).unwrap();
+    let req = build_test_request(query_doc, None, variables);
+    let expected_response = r#"{"data":null,"errors":[{"message":"Invalid value for variable date: tomorrow is invalid value for Date","locations":[{"line":1,"column":8}],"path":[],"extensions":null}]}"#;
+    check_gql_response(req, expected_response, &container).await;
+
+    let query_doc = r#"{ __type(name: "Date") { specifiedByURL } }"#;
+    let req = build_test_request(query_doc, None, Default::default());
+    let expected_response =
+        r#"{"data":{"__type":{"specifiedByURL":"https://tools.ietf.org/html/rfc3339"}}}"#;
+    check_gql_response(req, expected_response, &container).await;
+}
+
+#[test]
+fn test_unknown_custom_scalar() {
+    struct Query;
+
+    #[GqlType]
+    impl Query {
+        async fn value(&self) -> i32 {
+            1
+        }
+    }
+
+    struct AnyScalar;
+
+    impl CustomScalar for AnyScalar {
+        fn parse_value(&self, value: GqlValue) -> Result<GqlValue, String> {
+            Ok(value)
+        }
+    }
+
+    let schema_doc = ["type Query { value: Int! }"];
+    let result = Container::builder(&schema_doc, Query, EmptyMutation, EmptySubscription)
+        .scalar("Unknown", AnyScalar)
+        .build();
+    assert!(result.is_err());
+}
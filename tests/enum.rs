@@ -33,3 +33,53 @@ pub async fn test_enum() {
     let expected_response = r#"{"data":{"enum_value":"Value0"}}"#;
     check_gql_response(req, expected_response, &container).await;
 }
+
+#[tokio::test]
+pub async fn test_enum_rename() {
+    struct Query;
+
+    #[derive(GqlEnum, Debug)]
+    #[gql(rename_all = "SCREAMING_SNAKE_CASE")]
+    enum Episode {
+        NewHope,
+        Empire,
+        #[gql(name = "JEDI")]
+        ReturnOfTheJedi,
+    }
+
+    #[GqlType]
+    impl Query {
+        async fn episode(&self, episode: Episode) -> Episode {
+            episode
+        }
+
+        async fn episode_name(&self, episode: Episode) -> String {
+            format!("{:?}", episode)
+        }
+    }
+
+    let schema = r#"
+    type Query {
+      episode(episode: Episode!): Episode!
+      episode_name(episode: Episode!): String!
+    }
+    enum Episode {
+      NEW_HOPE
+      EMPIRE
+      JEDI
+    }
+    "#;
+    let container = Container::new(
+        &[schema],
+        Query,
+        EmptyMutation,
+        EmptySubscription,
+        Default::default(),
+    )
+    .unwrap();
+
+    let query_doc = r#"{ episode(episode: NEW_HOPE) episode_name(episode: JEDI) }"#;
+    let req = build_test_request(query_doc, None, Default::default());
+    let expected_response = r#"{"data":{"episode":"NEW_HOPE","episode_name":"ReturnOfTheJedi"}}"#;
+    check_gql_response(req, expected_response, &container).await;
+}
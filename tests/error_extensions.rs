@@ -0,0 +1,98 @@
+use std::collections::BTreeMap;
+
+use rusty_gql::*;
+
+enum UserError {
+    NotFound(String),
+    RateLimited { retry_after: i32 },
+}
+
+impl ErrorExtensions for UserError {
+    fn message(&self) -> String {
+        match self {
+            UserError::NotFound(id) => format!("User {} is not found", id),
+            UserError::RateLimited { .. } => "Too many requests".to_string(),
+        }
+    }
+
+    fn extensions(&self) -> BTreeMap<String, GqlValue> {
+        let mut extensions = BTreeMap::new();
+        match self {
+            UserError::NotFound(_) => {
+                extensions.insert("code".to_string(), GqlValue::from("NOT_FOUND".to_string()));
+            }
+            UserError::RateLimited { retry_after } => {
+                extensions.insert(
+                    "code".to_string(),
+                    GqlValue::from("RATE_LIMITED".to_string()),
+                );
+                extensions.insert("retryAfter".to_string(), GqlValue::from(*retry_after));
+            }
+        }
+        extensions
+    }
+}
+
+struct Query;
+
+#[GqlType]
+impl Query {
+    async fn user(&self, id: String) -> Result<String, UserError> {
+        Err(UserError::NotFound(id))
+    }
+
+    async fn search(&self) -> Result<Vec<String>, UserError> {
+        Err(UserError::RateLimited { retry_after: 30 })
+    }
+
+    async fn name(&self) -> Result<String, Error> {
+        Err(Error::new("Name is too long").set_extension("maxLength", 10))
+    }
+
+    async fn count(&self) -> Result<i32, String> {
+        Err("count is unavailable".to_string())
+    }
+}
+
+const SCHEMA: &str =
+    "type Query { user(id: String!): String search: [String!] name: String count: Int }";
+
+#[tokio::test]
+async fn test_error_extensions() {
+    let schema_doc = [SCHEMA];
+    let container = Container::builder(&schema_doc, Query, EmptyMutation, EmptySubscription)
+        .build()
+        .unwrap();
+
+    let req = build_test_request(r#"{ user(id: "1") }"#, None, Default::default());
+    let expected_response = r#"{"data":null,"errors":[{"message":"User 1 is not found","locations":[{"line":1,"column":3}],"path":[],"extensions":{"code":"NOT_FOUND"}}]}"#;
+    check_gql_response(req, expected_response, &container).await;
+
+    let req = build_test_request("{ search }", None, Default::default());
+    let expected_response = r#"{"data":null,"errors":[{"message":"Too many requests","locations":[{"line":1,"column":3}],"path":[],"extensions":{"code":"RATE_LIMITED","retryAfter":30}}]}"#;
+    check_gql_response(req, expected_response, &container).await;
+
+    let req = build_test_request("{ name }", None, Default::default());
+    let expected_response = r#"{"data":null,"errors":[{"message":"Name is too long","locations":[{"line":1,"column":3}],"path":[],"extensions":{"maxLength":10}}]}"#;
+    check_gql_response(req, expected_response, &container).await;
+
+    let req = build_test_request("{ count }", None, Default::default());
+    let expected_response = r#"{"data":null,"errors":[{"message":"count is unavailable","locations":[{"line":1,"column":3}],"path":[],"extensions":null}]}"#;
+    check_gql_response(req, expected_response, &container).await;
+}
+
+#[test]
+fn test_typed_error_extensions() {
+    let error = GqlError::new("Forbidden", None).set_extentions(GqlTypedError {
+        error_type: GqlErrorType::PermissionDenied,
+        error_detail: Some("Admin only".to_string()),
+        origin: None,
+        debug_info: None,
+        debug_uri: None,
+    });
+    assert_eq!(error.error_type(), Some(GqlErrorType::PermissionDenied));
+    assert_eq!(
+        serde_json::to_string(&error.extensions).unwrap(),
+        r#"{"debugInfo":null,"debugUri":null,"errorDetail":"Admin only","errorType":"PermissionDenied","origin":null}"#
+    );
+}
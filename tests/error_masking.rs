@@ -0,0 +1,165 @@
+use rusty_gql::*;
+
+struct Query;
+
+#[GqlType]
+impl Query {
+    async fn internal(&self) -> Result<i32, Error> {
+        let typed_error = GqlTypedError {
+            error_type: GqlErrorType::Internal,
+            error_detail: None,
+            origin: None,
+            debug_info: None,
+            debug_uri: None,
+        };
+        Err(Error {
+            message: "relation \"users\" does not exist".to_string(),
+            extensions: Some(typed_error.to_extensions()),
+        })
+    }
+
+    async fn untyped(&self) -> Result<i32, Error> {
+        Err(Error::new("/var/lib/app/data.db is locked"))
+    }
+}
+
+const SCHEMA: &str = "type Query { internal: Int untyped: Int }";
+
+fn correlation_id(error: &GqlError) -> Option<&String> {
+    match error.extensions.as_ref()?.get("correlationId")? {
+        GqlValue::String(id) => Some(id),
+        _ => None,
+    }
+}
+
+#[tokio::test]
+async fn test_internal_errors_are_masked_by_default() {
+    let schema_doc = [SCHEMA];
+    let container = Container::builder(&schema_doc, Query, EmptyMutation, EmptySubscription)
+        .build()
+        .unwrap();
+
+    let req = build_test_request("{ internal }", None, Default::default());
+    let res = execute(&container, req).await;
+    assert_eq!(res.errors[0].message, "Internal server error");
+    assert_eq!(res.errors[0].error_type(), Some(GqlErrorType::Internal));
+    let id = correlation_id(&res.errors[0]).unwrap();
+    assert_eq!(id.len(), 36);
+
+    let req = build_test_request("{ internal }", None, Default::default());
+    let res = execute(&container, req).await;
+    assert_ne!(correlation_id(&res.errors[0]), Some(id));
+
+    let req = build_test_request("{ untyped }", None, Default::default());
+    let res = execute(&container, req).await;
+    assert_eq!(res.errors[0].message, "/var/lib/app/data.db is locked");
+    assert!(correlation_id(&res.errors[0]).is_none());
+
+    let container = Container::builder(&schema_doc, Query, EmptyMutation, EmptySubscription)
+        .error_formatter(ErrorMasking::new().mask_untyped(true))
+        .build()
+        .unwrap();
+
+    let req = build_test_request("{ untyped }", None, Default::default());
+    let res = execute(&container, req).await;
+    assert_eq!(res.errors[0].message, "Internal server error");
+    assert_eq!(res.errors[0].error_type(), Some(GqlErrorType::Internal));
+    assert!(correlation_id(&res.errors[0]).is_some());
+}
+
+struct FailingExtension;
+
+#[async_trait::async_trait]
+impl Extension for FailingExtension {
+    async fn prepare_request(&self, _request: Request) -> ResolverResult<Request> {
+        Err(GqlError::new("persisted query store is unreachable", None)
+            .with_error_type(GqlErrorType::Internal))
+    }
+}
+
+struct AppendingExtension;
+
+#[async_trait::async_trait]
+impl Extension for AppendingExtension {
+    async fn response(&self, mut response: Response) -> Response {
+        response.errors.push(
+            GqlError::new("metrics backend is unreachable", None)
+                .with_error_type(GqlErrorType::Internal),
+        );
+        response
+    }
+}
+
+#[tokio::test]
+async fn test_extension_errors_are_masked() {
+    let schema_doc = [SCHEMA];
+    let container = Container::builder(&schema_doc, Query, EmptyMutation, EmptySubscription)
+        .extension(FailingExtension)
+        .build()
+        .unwrap();
+
+    let req = build_test_request("{ untyped }", None, Default::default());
+    let res = execute(&container, req).await;
+    assert_eq!(res.errors[0].message, "Internal server error");
+    assert!(correlation_id(&res.errors[0]).is_some());
+
+    let container = Container::builder(&schema_doc, Query, EmptyMutation, EmptySubscription)
+        .extension(AppendingExtension)
+        .build()
+        .unwrap();
+
+    let req = build_test_request("{ unknown }", None, Default::default());
+    let res = execute(&container, req).await;
+    assert_eq!(res.errors.len(), 2);
+    assert_eq!(
+        res.errors[0].message,
+        "Unknown field \"unknown\" on type \"Query\""
+    );
+    assert_eq!(res.errors[1].message, "Internal server error");
+}
+
+#[tokio::test]
+async fn test_error_masking() {
+    let schema_doc = [SCHEMA];
+    let container = Container::builder(&schema_doc, Query, EmptyMutation, EmptySubscription)
+        .error_formatter(ErrorMasking::mask_all().message("Something went wrong"))
+        .build()
+        .unwrap();
+
+    let req = build_test_request("{ untyped }", None, Default::default());
+    let res = execute(&container, req).await;
+    assert_eq!(res.errors[0].message, "Something went wrong");
+    assert!(correlation_id(&res.errors[0]).is_some());
+
+    let container = Container::builder(&schema_doc, Query, EmptyMutation, EmptySubscription)
+        .error_formatter(ErrorMasking::mask_all().allow(GqlErrorType::Internal))
+        .build()
+        .unwrap();
+
+    let req = build_test_request("{ internal }", None, Default::default());
+    let res = execute(&container, req).await;
+    assert_eq!(res.errors[0].message, "relation \"users\" does not exist");
+}
+
+#[tokio::test]
+async fn test_custom_error_formatter() {
+    let schema_doc = [SCHEMA];
+    let container = Container::builder(&schema_doc, Query, EmptyMutation, EmptySubscription)
+        .error_formatter(|mut error: GqlError| {
+            error.message = format!("[app] {}", error.message);
+            error
+        })
+        .build()
+        .unwrap();
+
+    let req = build_test_request("{ untyped }", None, Default::default());
+    let res = execute(&container, req).await;
+    assert_eq!(
+        res.errors[0].message,
+        "[app] /var/lib/app/data.db is locked"
+    );
+
+    let req = build_test_request("{ unknown }", None, Default::default());
+    let res = execute(&container, req).await;
+    assert!(!res.errors[0].message.starts_with("[app]"));
+}
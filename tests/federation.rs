@@ -0,0 +1,217 @@
+use std::collections::BTreeMap;
+
+use rusty_gql::*;
+
+struct User {
+    id: ID,
+    username: String,
+}
+
+#[GqlType]
+impl User {
+    async fn id(&self) -> ID {
+        self.id.clone()
+    }
+    async fn username(&self) -> String {
+        self.username.clone()
+    }
+}
+
+struct Query;
+
+#[GqlType]
+impl Query {
+    async fn me(&self) -> User {
+        User {
+            id: ID::from("1"),
+            username: "Tom".to_string(),
+        }
+    }
+}
+
+struct UserResolver;
+
+#[async_trait::async_trait]
+impl EntityResolver for UserResolver {
+    async fn resolve_entity(
+        &self,
+        ctx: &SelectionSetContext<'_>,
+        representation: &BTreeMap<String, GqlValue>,
+    ) -> ResolverResult<GqlValue> {
+        match representation.get("id") {
+            Some(GqlValue::String(id)) => {
+                let user = User {
+                    id: ID::from(id.as_str()),
+                    username: format!("user{}", id),
+                };
+                user.resolve_selection_set(ctx).await
+            }
+            _ => Ok(GqlValue::Null),
+        }
+    }
+}
+
+#[tokio::test]
+pub async fn test_service_sdl() {
+    let contents = schema_content("./tests/schemas/federation.graphql");
+
+    let container = Container::builder(
+        &[contents.as_str()],
+        Query,
+        EmptyMutation,
+        EmptySubscription,
+    )
+    .enable_federation()
+    .build()
+    .unwrap();
+
+    let query_doc = r#"{ _service { sdl } }"#;
+    let req = build_test_request(query_doc, None, Default::default());
+    let expected_response = serde_json::json!({ "data": { "_service": { "sdl": contents } } });
+    check_gql_response(req, &expected_response.to_string(), &container).await;
+}
+
+#[tokio::test]
+pub async fn test_entities() {
+    let contents = schema_content("./tests/schemas/federation.graphql");
+
+    let container = Container::builder(
+        &[contents.as_str()],
+        Query,
+        EmptyMutation,
+        EmptySubscription,
+    )
+    .entity_resolver("User", UserResolver)
+    .build()
+    .unwrap();
+
+    let query_doc = r#"query ($representations: [_Any!]!) {
+        _entities(representations: $representations) {
+            __typename
+            ... on User { id username }
+        }
+    }"#;
+    let variables = serde_json::from_str::<Variables>(
+        r#"{"representations": [{"__typename": "User", "id": "1"}, {"__typename": "User", "id": "2"}]}"#,
+    )
+    .unwrap();
+    let req = build_test_request(query_doc, None, variables);
+    let expected_response = r#"{"data":{"_entities":[{"__typename":"User","id":"1","username":"user1"},{"__typename":"User","id":"2","username":"user2"}]}}"#;
+    check_gql_response(req, expected_response, &container).await;
+}
+
+#[tokio::test]
+pub async fn test_unknown_entity_resolver() {
+    let contents = schema_content("./tests/schemas/federation.graphql");
+
+    let result = Container::builder(
+        &[contents.as_str()],
+        Query,
+        EmptyMutation,
+        EmptySubscription,
+    )
+    .entity_resolver("Review", UserResolver)
+    .build();
+
+    match result {
+        Ok(_) => panic!("Review is not an entity"),
+        Err(errors) => assert_eq!(errors[0].message, "Review is not an entity type with @key."),
+    }
+}
+
+#[tokio::test]
+pub async fn test_entities_with_invalid_representation() {
+    let contents = schema_content("./tests/schemas/federation.graphql");
+
+    let container = Container::builder(
+        &[contents.as_str()],
+        Query,
+        EmptyMutation,
+        EmptySubscription,
+    )
+    .entity_resolver("User", UserResolver)
+    .build()
+    .unwrap();
+
+    let query_doc = r#"query ($representations: [_Any!]!) {
+        _entities(representations: $representations) {
+            ... on User { id }
+        }
+    }"#;
+    let variables = serde_json::from_str::<Variables>(
+        r#"{"representations": [{"__typename": "User", "id": "1"}, {"id": "2"}, {"__typename": "Review", "id": "3"}]}"#,
+    )
+    .unwrap();
+    let req = build_test_request(query_doc, None, variables);
+    let expected_response = r#"{"data":{"_entities":[{"id":"1"},null,null]},"errors":[{"message":"Entity representation must have __typename","locations":[{"line":2,"column":9}],"path":[],"extensions":{"errorType":"BadRequest"}},{"message":"Entity resolver for Review is not registered","locations":[{"line":2,"column":9}],"path":[],"extensions":{"errorType":"BadRequest"}}]}"#;
+    check_gql_response(req, expected_response, &container).await;
+}
+
+#[tokio::test]
+pub async fn test_repeatable_key() {
+    let schema_doc = r#"
+    type Query {
+      me: User
+    }
+
+    type User @key(fields: "id") @key(fields: "username") {
+      id: ID!
+      username: String!
+    }
+    "#;
+
+    let container = Container::builder(&[schema_doc], Query, EmptyMutation, EmptySubscription)
+        .entity_resolver("User", UserResolver)
+        .build()
+        .unwrap();
+
+    let query_doc = r#"{ __schema { directives { name isRepeatable } } }"#;
+    let req = build_test_request(query_doc, None, Default::default());
+    let res = execute(&container, req).await;
+    let directives = serde_json::to_value(&res.data).unwrap()["__schema"]["directives"].clone();
+    assert!(directives
+        .as_array()
+        .unwrap()
+        .contains(&serde_json::json!({ "name": "key", "isRepeatable": true })));
+}
+
+struct ServiceQuery;
+
+#[GqlType]
+impl ServiceQuery {
+    async fn _service(&self) -> Service {
+        Service
+    }
+}
+
+struct Service;
+
+#[GqlType]
+impl Service {
+    async fn sdl(&self) -> String {
+        "type Query { _service: _Service! }".to_string()
+    }
+}
+
+#[tokio::test]
+pub async fn test_service_defined_in_schema() {
+    let schema_doc = r#"
+    type Query {
+      _service: _Service!
+    }
+    "#;
+
+    let container = Container::builder(
+        &[schema_doc],
+        ServiceQuery,
+        EmptyMutation,
+        EmptySubscription,
+    )
+    .enable_federation()
+    .build()
+    .unwrap();
+
+    let req = build_test_request("{ _service { sdl } }", None, Default::default());
+    let expected_response = r#"{"data":{"_service":{"sdl":"type Query { _service: _Service! }"}}}"#;
+    check_gql_response(req, expected_response, &container).await;
+}
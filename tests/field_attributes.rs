@@ -0,0 +1,137 @@
+use rusty_gql::*;
+
+struct Person {
+    first_name: String,
+    last_name: String,
+}
+
+#[GqlType]
+impl Person {
+    #[field(name = "fullName")]
+    async fn full_name(&self) -> String {
+        self.join_name(" ")
+    }
+
+    #[field(deprecation = "Use fullName")]
+    async fn name(&self) -> String {
+        self.first_name.clone()
+    }
+
+    #[field(skip)]
+    fn join_name(&self, sep: &str) -> String {
+        format!("{}{}{}", self.first_name, sep, self.last_name)
+    }
+}
+
+struct AdminGuard;
+
+#[async_trait::async_trait]
+impl Guard for AdminGuard {
+    async fn check(&self, ctx: &Context<'_>) -> Result<(), Error> {
+        match ctx.get_data::<String>() {
+            Ok(role) if role == "admin" => Ok(()),
+            _ => Err(Error::new("Forbidden")),
+        }
+    }
+}
+
+struct Query;
+
+#[GqlType]
+impl Query {
+    #[field(complexity = 8)]
+    async fn persons(&self, #[arg(default = 2)] first: i32) -> Vec<Person> {
+        (0..first)
+            .map(|i| Person {
+                first_name: format!("First{}", i),
+                last_name: format!("Last{}", i),
+            })
+            .collect()
+    }
+
+    #[field(guard = "AdminGuard")]
+    async fn secret(&self) -> String {
+        "secret".to_string()
+    }
+
+    async fn greet(&self, #[arg(name = "userName")] name: String) -> String {
+        format!("Hello, {}", name)
+    }
+}
+
+const SCHEMA: &str = r#"
+type Query {
+  persons(first: Int): [Person!]!
+  secret: String
+  greet(userName: String!): String!
+}
+
+type Person {
+  fullName: String!
+  name: String!
+}
+"#;
+
+fn build_container(role: &str) -> Container<Query, EmptyMutation, EmptySubscription> {
+    let schema_doc = [SCHEMA];
+    Container::builder(&schema_doc, Query, EmptyMutation, EmptySubscription)
+        .data(role.to_string())
+        .limit_complexity(10)
+        .build()
+        .unwrap()
+}
+
+#[tokio::test]
+async fn test_field_name_and_skip() {
+    let container = build_container("user");
+    let req = build_test_request("{ persons { fullName } }", None, Default::default());
+    let expected_response =
+        r#"{"data":{"persons":[{"fullName":"First0 Last0"},{"fullName":"First1 Last1"}]}}"#;
+    check_gql_response(req, expected_response, &container).await;
+}
+
+#[tokio::test]
+async fn test_arg_attributes() {
+    let container = build_container("user");
+    let req = build_test_request(r#"{ greet(userName: "rusty") }"#, None, Default::default());
+    let expected_response = r#"{"data":{"greet":"Hello, rusty"}}"#;
+    check_gql_response(req, expected_response, &container).await;
+
+    let req = build_test_request("{ persons(first: 1) { name } }", None, Default::default());
+    let expected_response = r#"{"data":{"persons":[{"name":"First0"}]}}"#;
+    check_gql_response(req, expected_response, &container).await;
+}
+
+#[tokio::test]
+async fn test_field_guard() {
+    let container = build_container("admin");
+    let req = build_test_request("{ secret }", None, Default::default());
+    let expected_response = r#"{"data":{"secret":"secret"}}"#;
+    check_gql_response(req, expected_response, &container).await;
+
+    let container = build_container("user");
+    let req = build_test_request("{ secret }", None, Default::default());
+    let expected_response = r#"{"data":null,"errors":[{"message":"Forbidden","locations":[{"line":1,"column":3}],"path":[],"extensions":{"errorType":"PermissionDenied"}}]}"#;
+    check_gql_response(req, expected_response, &container).await;
+}
+
+#[tokio::test]
+async fn test_field_deprecation() {
+    let container = build_container("user");
+    let query = r#"{ __type(name: "Person") { fields(includeDeprecated: true) { name isDeprecated deprecationReason } } }"#;
+    let req = build_test_request(query, None, Default::default());
+    let expected_response = r#"{"data":{"__type":{"fields":[{"deprecationReason":null,"isDeprecated":false,"name":"fullName"},{"deprecationReason":"Use fullName","isDeprecated":true,"name":"name"}]}}}"#;
+    check_gql_response(req, expected_response, &container).await;
+}
+
+#[tokio::test]
+async fn test_complexity_limit() {
+    let container = build_container("user");
+    let req = build_test_request(
+        "{ persons { fullName name } secret }",
+        None,
+        Default::default(),
+    );
+    let expected_response = r#"{"data":null,"errors":[{"message":"Query complexity 11 exceeds the maximum complexity limit 10.","locations":[],"path":[],"extensions":null}]}"#;
+    check_gql_response(req, expected_response, &container).await;
+}
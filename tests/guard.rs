@@ -0,0 +1,137 @@
+use std::collections::BTreeMap;
+
+use rusty_gql::*;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Role {
+    Admin,
+    Editor,
+    Viewer,
+}
+
+impl Role {
+    fn from_name(name: &str) -> Option<Role> {
+        match name {
+            "ADMIN" => Some(Role::Admin),
+            "EDITOR" => Some(Role::Editor),
+            "VIEWER" => Some(Role::Viewer),
+            _ => None,
+        }
+    }
+}
+
+struct RoleGuard {
+    role: Option<Role>,
+}
+
+impl RoleGuard {
+    fn new(role: Role) -> Self {
+        RoleGuard { role: Some(role) }
+    }
+
+    fn from_args(args: &BTreeMap<String, GqlValue>) -> Self {
+        let role = match args.get("requires") {
+            Some(GqlValue::Enum(name)) => Role::from_name(name),
+            _ => None,
+        };
+        RoleGuard { role }
+    }
+}
+
+#[async_trait::async_trait]
+impl Guard for RoleGuard {
+    async fn check(&self, ctx: &Context<'_>) -> Result<(), Error> {
+        let current_role = ctx.get_data::<Role>().ok();
+        if self.role.is_some() && current_role == self.role.as_ref() {
+            Ok(())
+        } else {
+            Err(Error::new(format!(
+                "{:?} role is required",
+                self.role.unwrap_or(Role::Admin)
+            )))
+        }
+    }
+}
+
+struct Query;
+
+#[GqlType]
+impl Query {
+    #[field(guard = "RoleGuard::new(Role::Admin).or(RoleGuard::new(Role::Editor))")]
+    async fn draft(&self) -> String {
+        "draft".to_string()
+    }
+
+    #[field(guard = "RoleGuard::new(Role::Viewer).and(RoleGuard::new(Role::Admin))")]
+    async fn never(&self) -> String {
+        "never".to_string()
+    }
+
+    async fn users(&self) -> Vec<String> {
+        vec!["rusty".to_string()]
+    }
+
+    async fn value(&self) -> i32 {
+        10
+    }
+}
+
+const SCHEMA: &str = r#"
+type Query {
+  draft: String!
+  never: String!
+  users: [String!]! @auth(requires: ADMIN)
+  value: Int!
+}
+
+enum Role {
+  ADMIN
+  EDITOR
+  VIEWER
+}
+
+directive @auth(requires: Role!) on FIELD_DEFINITION | OBJECT
+"#;
+
+fn build_container(role: Role) -> Container<Query, EmptyMutation, EmptySubscription> {
+    let schema_doc = [SCHEMA];
+    Container::builder(&schema_doc, Query, EmptyMutation, EmptySubscription)
+        .data(role)
+        .guard_directive("auth", RoleGuard::from_args)
+        .build()
+        .unwrap()
+}
+
+#[tokio::test]
+async fn test_guard_composition() {
+    let container = build_container(Role::Editor);
+    let req = build_test_request("{ draft }", None, Default::default());
+    let expected_response = r#"{"data":{"draft":"draft"}}"#;
+    check_gql_response(req, expected_response, &container).await;
+
+    let container = build_container(Role::Viewer);
+    let req = build_test_request("{ draft }", None, Default::default());
+    let expected_response = r#"{"data":null,"errors":[{"message":"Editor role is required","locations":[{"line":1,"column":3}],"path":[],"extensions":{"errorType":"PermissionDenied"}}]}"#;
+    check_gql_response(req, expected_response, &container).await;
+
+    let req = build_test_request("{ never }", None, Default::default());
+    let expected_response = r#"{"data":null,"errors":[{"message":"Admin role is required","locations":[{"line":1,"column":3}],"path":[],"extensions":{"errorType":"PermissionDenied"}}]}"#;
+    check_gql_response(req, expected_response, &container).await;
+}
+
+#[tokio::test]
+async fn test_guard_directive() {
+    let container = build_container(Role::Admin);
+    let req = build_test_request("{ users value }", None, Default::default());
+    let expected_response = r#"{"data":{"users":["rusty"],"value":10}}"#;
+    check_gql_response(req, expected_response, &container).await;
+
+    let container = build_container(Role::Viewer);
+    let req = build_test_request("{ value }", None, Default::default());
+    let expected_response = r#"{"data":{"value":10}}"#;
+    check_gql_response(req, expected_response, &container).await;
+
+    let req = build_test_request("{ users }", None, Default::default());
+    let expected_response = r#"{"data":null,"errors":[{"message":"Admin role is required","locations":[{"line":1,"column":3}],"path":[],"extensions":{"errorType":"PermissionDenied"}}]}"#;
+    check_gql_response(req, expected_response, &container).await;
+}
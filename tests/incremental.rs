@@ -0,0 +1,319 @@
+use std::sync::{
+    atomic::{AtomicBool, AtomicUsize, Ordering},
+    Arc,
+};
+
+use futures_util::StreamExt;
+use rusty_gql::*;
+use tokio::sync::Notify;
+
+#[derive(Clone)]
+struct Person {
+    name: String,
+    age: i32,
+}
+
+#[GqlType]
+impl Person {
+    async fn name(&self) -> String {
+        self.name.clone()
+    }
+    async fn age(&self) -> i32 {
+        self.age
+    }
+    #[field(name = "slowAge")]
+    async fn slow_age(&self, ctx: &Context<'_>) -> i32 {
+        if let Ok(notify) = ctx.get_data::<Arc<Notify>>() {
+            notify.notified().await;
+        }
+        self.age
+    }
+    async fn greet(&self, name: String) -> String {
+        format!("Hello, {}. I'm {}.", name, self.name)
+    }
+    async fn friends(&self) -> Vec<Person> {
+        vec![Person {
+            name: "Alice".to_string(),
+            age: 30,
+        }]
+    }
+}
+
+struct Query;
+
+#[GqlType]
+impl Query {
+    async fn person(&self, ctx: &Context<'_>) -> Person {
+        if let Ok(calls) = ctx.get_data::<Arc<AtomicUsize>>() {
+            calls.fetch_add(1, Ordering::SeqCst);
+        }
+        Person {
+            name: "Tom".to_string(),
+            age: 20,
+        }
+    }
+    async fn persons(&self) -> Vec<Person> {
+        vec![
+            Person {
+                name: "Tom".to_string(),
+                age: 20,
+            },
+            Person {
+                name: "Bob".to_string(),
+                age: 25,
+            },
+        ]
+    }
+    async fn values(&self) -> Vec<i32> {
+        vec![1, 2, 3, 4]
+    }
+}
+
+fn build_container<T: std::any::Any + Send + Sync>(
+    data: T,
+) -> Container<Query, EmptyMutation, EmptySubscription> {
+    let contents = schema_content("./tests/schemas/incremental.graphql");
+    let schema_doc = [contents.as_str()];
+    Container::builder(&schema_doc, Query, EmptyMutation, EmptySubscription)
+        .data(data)
+        .build()
+        .unwrap()
+}
+
+async fn check_incremental_response(query: &str, variables: &str, expected_responses: &[&str]) {
+    let container = build_container(());
+    let variables = serde_json::from_str::<Variables>(variables).unwrap();
+    let req = build_test_request(query, None, variables);
+    let responses: Vec<String> = execute_incremental(&container, req)
+        .map(|res| serde_json::to_string(&res).unwrap())
+        .collect()
+        .await;
+    assert_eq!(responses, expected_responses);
+}
+
+#[tokio::test]
+async fn test_without_incremental_directives() {
+    check_incremental_response(
+        r#"{ person { name } }"#,
+        "{}",
+        &[r#"{"data":{"person":{"name":"Tom"}},"hasNext":false}"#],
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn test_defer_inline_fragment() {
+    check_incremental_response(
+        r#"{ person { name ... @defer(label: "age") { age } } }"#,
+        "{}",
+        &[
+            r#"{"data":{"person":{"name":"Tom"}},"hasNext":true}"#,
+            r#"{"incremental":[{"data":{"age":20},"path":["person"],"label":"age"}],"hasNext":false}"#,
+        ],
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn test_defer_fragment_spread_in_list() {
+    check_incremental_response(
+        r#"{ persons { name ...PersonAge @defer } } fragment PersonAge on Person { age }"#,
+        "{}",
+        &[
+            r#"{"data":{"persons":[{"name":"Tom"},{"name":"Bob"}]},"hasNext":true}"#,
+            r#"{"incremental":[{"data":{"age":20},"path":["persons",0]},{"data":{"age":25},"path":["persons",1]}],"hasNext":false}"#,
+        ],
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn test_defer_disabled() {
+    check_incremental_response(
+        r#"query ($defer: Boolean!) { person { name ... @defer(if: $defer) { age } } }"#,
+        r#"{"defer": false}"#,
+        &[r#"{"data":{"person":{"age":20,"name":"Tom"}},"hasNext":false}"#],
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn test_defer_disabled_by_default_value() {
+    check_incremental_response(
+        r#"query ($defer: Boolean = false) { person { name ... @defer(if: $defer) { age } } }"#,
+        "{}",
+        &[r#"{"data":{"person":{"age":20,"name":"Tom"}},"hasNext":false}"#],
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn test_defer_with_variables() {
+    check_incremental_response(
+        r#"query ($name: String!) { person { name ... @defer { greet(name: $name) } } }"#,
+        r#"{"name": "Bob"}"#,
+        &[
+            r#"{"data":{"person":{"name":"Tom"}},"hasNext":true}"#,
+            r#"{"incremental":[{"data":{"greet":"Hello, Bob. I'm Tom."},"path":["person"]}],"hasNext":false}"#,
+        ],
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn test_nested_defer() {
+    check_incremental_response(
+        r#"{ person { name ... @defer(label: "outer") { age ... @defer(label: "inner") { friends { name } } } } }"#,
+        "{}",
+        &[
+            r#"{"data":{"person":{"name":"Tom"}},"hasNext":true}"#,
+            r#"{"incremental":[{"data":{"age":20},"path":["person"],"label":"outer"},{"data":{"friends":[{"name":"Alice"}]},"path":["person"],"label":"inner"}],"hasNext":false}"#,
+        ],
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn test_defer_resolves_parent_once() {
+    let calls = Arc::new(AtomicUsize::new(0));
+    let container = build_container(calls.clone());
+    let req = build_test_request(
+        r#"{ person { name ... @defer { age } ... @defer { friends { name } } } }"#,
+        None,
+        Default::default(),
+    );
+    let responses: Vec<IncrementalResponse> = execute_incremental(&container, req).collect().await;
+    assert_eq!(responses.len(), 2);
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test]
+async fn test_initial_payload_before_deferred_fields() {
+    let notify = Arc::new(Notify::new());
+    let released = Arc::new(AtomicBool::new(false));
+    // Releases the deferred field in case the initial payload waits for it.
+    std::thread::spawn({
+        let notify = notify.clone();
+        let released = released.clone();
+        move || {
+            std::thread::sleep(std::time::Duration::from_secs(2));
+            released.store(true, Ordering::SeqCst);
+            notify.notify_one();
+        }
+    });
+
+    let container = build_container(notify.clone());
+    let req = build_test_request(
+        r#"{ person { name ... @defer { slowAge } } }"#,
+        None,
+        Default::default(),
+    );
+    let mut stream = execute_incremental(&container, req);
+    let initial = stream.next().await.unwrap();
+    assert!(!released.load(Ordering::SeqCst));
+    assert_eq!(
+        serde_json::to_string(&initial).unwrap(),
+        r#"{"data":{"person":{"name":"Tom"}},"hasNext":true}"#
+    );
+
+    notify.notify_one();
+    let responses: Vec<String> = stream
+        .map(|res| serde_json::to_string(&res).unwrap())
+        .collect()
+        .await;
+    assert_eq!(
+        responses,
+        vec![r#"{"incremental":[{"data":{"slowAge":20},"path":["person"]}],"hasNext":false}"#]
+    );
+}
+
+#[tokio::test]
+async fn test_stream() {
+    check_incremental_response(
+        r#"{ values @stream(initialCount: 1, label: "values") }"#,
+        "{}",
+        &[
+            r#"{"data":{"values":[1]},"hasNext":true}"#,
+            r#"{"incremental":[{"items":[2],"path":["values",1],"label":"values"},{"items":[3],"path":["values",2],"label":"values"},{"items":[4],"path":["values",3],"label":"values"}],"hasNext":false}"#,
+        ],
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn test_stream_object_list() {
+    check_incremental_response(
+        r#"{ persons @stream { name } }"#,
+        "{}",
+        &[
+            r#"{"data":{"persons":[]},"hasNext":true}"#,
+            r#"{"incremental":[{"items":[{"name":"Tom"}],"path":["persons",0]},{"items":[{"name":"Bob"}],"path":["persons",1]}],"hasNext":false}"#,
+        ],
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn test_stream_with_defer() {
+    check_incremental_response(
+        r#"{ persons @stream(initialCount: 1) { name ... @defer { age } } }"#,
+        "{}",
+        &[
+            r#"{"data":{"persons":[{"name":"Tom"}]},"hasNext":true}"#,
+            r#"{"incremental":[{"data":{"age":20},"path":["persons",0]},{"items":[{"name":"Bob"}],"path":["persons",1]},{"data":{"age":25},"path":["persons",1]}],"hasNext":false}"#,
+        ],
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn test_stream_disabled() {
+    check_incremental_response(
+        r#"query ($stream: Boolean!) { values @stream(if: $stream, initialCount: 1) }"#,
+        r#"{"stream": false}"#,
+        &[r#"{"data":{"values":[1,2,3,4]},"hasNext":false}"#],
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn test_stream_initial_count_covers_list() {
+    check_incremental_response(
+        r#"{ values @stream(initialCount: 4) }"#,
+        "{}",
+        &[r#"{"data":{"values":[1,2,3,4]},"hasNext":false}"#],
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn test_stream_negative_initial_count() {
+    let container = build_container(());
+    let req = build_test_request(
+        r#"{ values @stream(initialCount: -1) }"#,
+        None,
+        Default::default(),
+    );
+    let responses: Vec<IncrementalResponse> = execute_incremental(&container, req).collect().await;
+    assert_eq!(responses.len(), 1);
+    assert!(!responses[0].has_next);
+    assert_eq!(
+        responses[0].errors[0].message,
+        "initialCount of @stream must be a non-negative integer"
+    );
+}
+
+#[tokio::test]
+async fn test_stream_in_execute() {
+    let container = build_container(());
+    let req = build_test_request(
+        r#"{ values @stream(initialCount: 1) }"#,
+        None,
+        Default::default(),
+    );
+    let res = execute(&container, req).await;
+    assert_eq!(
+        serde_json::to_string(&res).unwrap(),
+        r#"{"data":{"values":[1,2,3,4]}}"#
+    );
+}
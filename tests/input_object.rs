@@ -32,3 +32,184 @@ pub async fn test_input_obj() {
     let expected_response = r#"{"data":{"input_test":"test*2"}}"#;
     check_gql_response(req, expected_response, &container).await;
 }
+
+#[tokio::test]
+pub async fn test_one_of_input_obj() {
+    struct Query;
+
+    #[derive(GqlInputObject)]
+    pub struct UserBy {
+        id: Option<ID>,
+        email: Option<String>,
+    }
+
+    struct User {
+        name: String,
+    }
+
+    #[GqlType]
+    impl User {
+        async fn name(&self) -> String {
+            self.name.clone()
+        }
+    }
+
+    #[GqlType]
+    impl Query {
+        #[allow(unused)]
+        async fn input_test(&self, input: Option<String>) -> String {
+            String::new()
+        }
+
+        async fn user(&self, by: UserBy) -> Option<User> {
+            match (by.id, by.email) {
+                (Some(id), None) => Some(User {
+                    name: format!("user{}", id.0),
+                }),
+                (None, Some(email)) => Some(User { name: email }),
+                _ => None,
+            }
+        }
+    }
+    let contents = schema_content("./tests/schemas/input_object.graphql");
+
+    let container = Container::new(
+        &[contents.as_str()],
+        Query,
+        EmptyMutation,
+        EmptySubscription,
+        Default::default(),
+    )
+    .unwrap();
+
+    let query_doc = r#"{ user(by: { id: 1 }) { name } }"#;
+    let req = build_test_request(query_doc, None, Default::default());
+    let expected_response = r#"{"data":{"user":{"name":"user1"}}}"#;
+    check_gql_response(req, expected_response, &container).await;
+
+    let query_doc = r#"{ user(by: { id: 1, email: "a@example.com" }) { name } }"#;
+    let req = build_test_request(query_doc, None, Default::default());
+    let expected_response = r#"{"data":null,"errors":[{"message":"Invalid value for argument by: OneOf input object UserBy must specify exactly one field","locations":[],"path":[],"extensions":null}]}"#;
+    check_gql_response(req, expected_response, &container).await;
+
+    let query_doc = r#"query ($by: UserBy!) { user(by: $by) { name } }"#;
+    let variables = serde_json::from_str::<Variables>(r#"{"by": {"email": null}}"#).unwrap();
+    let req = build_test_request(query_doc, None, variables);
+    let expected_response = r#"{"data":null,"errors":[{"message":"Invalid value for variable by: Field UserBy.email of OneOf input object must be non-null","locations":[{"line":1,"column":8}],"path":[],"extensions":null}]}"#;
+    check_gql_response(req, expected_response, &container).await;
+
+    let query_doc = r#"query ($by: UserBy!) { user(by: $by) { name } }"#;
+    let variables =
+        serde_json::from_str::<Variables>(r#"{"by": {"email": "a@example.com"}}"#).unwrap();
+    let req = build_test_request(query_doc, None, variables);
+    let expected_response = r#"{"data":{"user":{"name":"a@example.com"}}}"#;
+    check_gql_response(req, expected_response, &container).await;
+
+    let query_doc = r#"{ __type(name: "UserBy") { isOneOf } }"#;
+    let req = build_test_request(query_doc, None, Default::default());
+    let expected_response = r#"{"data":{"__type":{"isOneOf":true}}}"#;
+    check_gql_response(req, expected_response, &container).await;
+}
+
+#[tokio::test]
+pub async fn test_input_obj_rename_and_default() {
+    struct Query;
+
+    #[derive(GqlInputObject)]
+    #[gql(rename_all = "camelCase")]
+    pub struct UpdateUserInput {
+        user_id: ID,
+        #[gql(name = "displayName")]
+        name: MaybeUndefined<String>,
+        #[gql(default = 20)]
+        page_size: i32,
+        #[gql(default)]
+        tags: Vec<String>,
+    }
+
+    #[GqlType]
+    impl Query {
+        async fn update_user(&self, input: UpdateUserInput) -> String {
+            let name = match input.name {
+                MaybeUndefined::Undefined => "unchanged".to_string(),
+                MaybeUndefined::Null => "cleared".to_string(),
+                MaybeUndefined::Value(name) => name,
+            };
+            format!(
+                "{}:{}:{}:{}",
+                input.user_id.0,
+                name,
+                input.page_size,
+                input.tags.len()
+            )
+        }
+
+        async fn nickname(&self, nickname: MaybeUndefined<String>) -> String {
+            match nickname {
+                MaybeUndefined::Undefined => "undefined".to_string(),
+                MaybeUndefined::Null => "null".to_string(),
+                MaybeUndefined::Value(nickname) => nickname,
+            }
+        }
+    }
+
+    let schema = r#"
+    type Query {
+      update_user(input: UpdateUserInput!): String!
+      nickname(nickname: String): String!
+    }
+    input UpdateUserInput {
+      userId: ID!
+      displayName: String
+      pageSize: Int
+      tags: [String!]
+    }
+    "#;
+    let container = Container::new(
+        &[schema],
+        Query,
+        EmptyMutation,
+        EmptySubscription,
+        Default::default(),
+    )
+    .unwrap();
+
+    let query_doc = r#"{ update_user(input: {userId: "1"}) }"#;
+    let req = build_test_request(query_doc, None, Default::default());
+    let expected_response = r#"{"data":{"update_user":"1:unchanged:20:0"}}"#;
+    check_gql_response(req, expected_response, &container).await;
+
+    let query_doc =
+        r#"{ update_user(input: {userId: "1", displayName: null, pageSize: 5, tags: ["a"]}) }"#;
+    let req = build_test_request(query_doc, None, Default::default());
+    let expected_response = r#"{"data":{"update_user":"1:cleared:5:1"}}"#;
+    check_gql_response(req, expected_response, &container).await;
+
+    let query_doc = r#"{ update_user(input: {userId: "1", displayName: "rusty"}) }"#;
+    let req = build_test_request(query_doc, None, Default::default());
+    let expected_response = r#"{"data":{"update_user":"1:rusty:20:0"}}"#;
+    check_gql_response(req, expected_response, &container).await;
+
+    let req = build_test_request("{ nickname }", None, Default::default());
+    let expected_response = r#"{"data":{"nickname":"undefined"}}"#;
+    check_gql_response(req, expected_response, &container).await;
+
+    let req = build_test_request("{ nickname(nickname: null) }", None, Default::default());
+    let expected_response = r#"{"data":{"nickname":"null"}}"#;
+    check_gql_response(req, expected_response, &container).await;
+
+    let query_doc = "query ($nickname: String) { nickname(nickname: $nickname) }";
+    let req = build_test_request(query_doc, None, Default::default());
+    let expected_response = r#"{"data":{"nickname":"undefined"}}"#;
+    check_gql_response(req, expected_response, &container).await;
+
+    let variables = serde_json::from_str::<Variables>(r#"{"nickname": null}"#).unwrap();
+    let req = build_test_request(query_doc, None, variables);
+    let expected_response = r#"{"data":{"nickname":"null"}}"#;
+    check_gql_response(req, expected_response, &container).await;
+
+    let query_doc = r#"query ($nickname: String = "rusty") { nickname(nickname: $nickname) }"#;
+    let req = build_test_request(query_doc, None, Default::default());
+    let expected_response = r#"{"data":{"nickname":"rusty"}}"#;
+    check_gql_response(req, expected_response, &container).await;
+}
@@ -162,3 +162,196 @@ async fn test_scalar_introspection() {
         r#"{"data":{"__type":{"description":null,"kind":"SCALAR","name":"DateTime"}}}"#;
     check_gql_response(req, expected_response, &container).await;
 }
+
+#[tokio::test]
+async fn test_deprecation_introspection() {
+    struct Query;
+
+    #[GqlType]
+    impl Query {
+        async fn value(&self) -> i32 {
+            10
+        }
+    }
+    let schema_doc = [r#"
+        directive @tag(name: String!) repeatable on FIELD_DEFINITION
+
+        type Query {
+          value: Int!
+          oldValue(limit: Int @deprecated(reason: "Use first"), first: Int): Int @deprecated(reason: "Use value")
+          tags: [String!]! @tag(name: "a") @tag(name: "b")
+        }
+
+        input Filter {
+          name: String
+          nickname: String @deprecated
+        }
+
+        enum Color {
+          RED
+          CRIMSON @deprecated(reason: "Use RED")
+        }
+    "#];
+
+    let container = Container::builder(&schema_doc, Query, EmptyMutation, EmptySubscription)
+        .build()
+        .unwrap();
+
+    let query = r#"{ __type(name: "Query") { fields { name } } }"#;
+    let req = build_test_request(query, None, Default::default());
+    let expected_response = r#"{"data":{"__type":{"fields":[{"name":"value"},{"name":"tags"}]}}}"#;
+    check_gql_response(req, expected_response, &container).await;
+
+    let query = r#"{ __type(name: "Query") { fields(includeDeprecated: true) { name isDeprecated deprecationReason args(includeDeprecated: true) { name isDeprecated deprecationReason } } } }"#;
+    let req = build_test_request(query, None, Default::default());
+    let expected_response = r#"{"data":{"__type":{"fields":[{"args":[],"deprecationReason":null,"isDeprecated":false,"name":"value"},{"args":[{"deprecationReason":"Use first","isDeprecated":true,"name":"limit"},{"deprecationReason":null,"isDeprecated":false,"name":"first"}],"deprecationReason":"Use value","isDeprecated":true,"name":"oldValue"},{"args":[],"deprecationReason":null,"isDeprecated":false,"name":"tags"}]}}}"#;
+    check_gql_response(req, expected_response, &container).await;
+
+    let query =
+        r#"{ __type(name: "Query") { fields(includeDeprecated: true) { name args { name } } } }"#;
+    let req = build_test_request(query, None, Default::default());
+    let expected_response = r#"{"data":{"__type":{"fields":[{"args":[],"name":"value"},{"args":[{"name":"first"}],"name":"oldValue"},{"args":[],"name":"tags"}]}}}"#;
+    check_gql_response(req, expected_response, &container).await;
+
+    let query = r#"{ __type(name: "Filter") { inputFields { name } } }"#;
+    let req = build_test_request(query, None, Default::default());
+    let expected_response = r#"{"data":{"__type":{"inputFields":[{"name":"name"}]}}}"#;
+    check_gql_response(req, expected_response, &container).await;
+
+    let query = r#"{ __type(name: "Filter") { inputFields(includeDeprecated: true) { name deprecationReason } } }"#;
+    let req = build_test_request(query, None, Default::default());
+    let expected_response = r#"{"data":{"__type":{"inputFields":[{"deprecationReason":null,"name":"name"},{"deprecationReason":"No longer supported","name":"nickname"}]}}}"#;
+    check_gql_response(req, expected_response, &container).await;
+
+    let query = r#"{ __type(name: "Color") { enumValues { name } } }"#;
+    let req = build_test_request(query, None, Default::default());
+    let expected_response = r#"{"data":{"__type":{"enumValues":[{"name":"RED"}]}}}"#;
+    check_gql_response(req, expected_response, &container).await;
+
+    let query = r#"{ __type(name: "Color") { enumValues(includeDeprecated: true) { name isDeprecated deprecationReason } } }"#;
+    let req = build_test_request(query, None, Default::default());
+    let expected_response = r#"{"data":{"__type":{"enumValues":[{"deprecationReason":null,"isDeprecated":false,"name":"RED"},{"deprecationReason":"Use RED","isDeprecated":true,"name":"CRIMSON"}]}}}"#;
+    check_gql_response(req, expected_response, &container).await;
+}
+
+#[tokio::test]
+async fn test_directive_and_wrapping_type_introspection() {
+    struct Query;
+
+    #[GqlType]
+    impl Query {
+        async fn value(&self) -> i32 {
+            10
+        }
+    }
+    let schema_doc = [r#"
+        directive @tag(name: String!) repeatable on FIELD_DEFINITION
+
+        type Query {
+          value: Int!
+          tags: [String!]! @tag(name: "a") @tag(name: "b")
+        }
+    "#];
+
+    let container = Container::builder(&schema_doc, Query, EmptyMutation, EmptySubscription)
+        .build()
+        .unwrap();
+
+    let query = r#"{ __schema { directives { name isRepeatable } } }"#;
+    let req = build_test_request(query, None, Default::default());
+    let res = execute(&container, req).await;
+    let directives = serde_json::to_value(&res.data).unwrap()["__schema"]["directives"].clone();
+    let directives = directives.as_array().unwrap();
+    assert!(directives.contains(&serde_json::json!({"name": "tag", "isRepeatable": true})));
+    assert!(directives.contains(&serde_json::json!({"name": "skip", "isRepeatable": false})));
+
+    let query = r#"{ __type(name: "Query") { fields { name type { kind name ofType { kind name ofType { kind name ofType { kind name } } } } } } }"#;
+    let req = build_test_request(query, None, Default::default());
+    let expected_response = r#"{"data":{"__type":{"fields":[{"name":"value","type":{"kind":"NON_NULL","name":null,"ofType":{"kind":"SCALAR","name":"Int","ofType":null}}},{"name":"tags","type":{"kind":"NON_NULL","name":null,"ofType":{"kind":"LIST","name":null,"ofType":{"kind":"NON_NULL","name":null,"ofType":{"kind":"SCALAR","name":"String"}}}}}]}}}"#;
+    check_gql_response(req, expected_response, &container).await;
+}
+
+#[test]
+fn test_required_argument_deprecation() {
+    struct Query;
+
+    #[GqlType]
+    impl Query {
+        async fn value(&self, _first: i32) -> i32 {
+            10
+        }
+    }
+    let schema_doc = [r#"
+        type Query {
+          value(first: Int! @deprecated): Int!
+        }
+    "#];
+
+    let result = Container::builder(&schema_doc, Query, EmptyMutation, EmptySubscription).build();
+    let errors = result.err().unwrap();
+    assert_eq!(
+        errors[0].message,
+        "Required argument Query.value(first:) cannot be deprecated."
+    );
+}
+
+#[tokio::test]
+async fn test_introspection_mode() {
+    struct Query;
+
+    #[GqlType]
+    impl Query {
+        async fn value(&self) -> i32 {
+            10
+        }
+    }
+    let schema_doc = ["type Query { value: Int! }"];
+
+    let container = Container::builder(&schema_doc, Query, EmptyMutation, EmptySubscription)
+        .introspection(IntrospectionMode::TypenameOnly)
+        .build()
+        .unwrap();
+
+    let req = build_test_request("{ __typename value }", None, Default::default());
+    let expected_response = r#"{"data":{"__typename":"Query","value":10}}"#;
+    check_gql_response(req, expected_response, &container).await;
+
+    let req = build_test_request(
+        r#"{ __type(name: "Query") { name } }"#,
+        None,
+        Default::default(),
+    );
+    let expected_response = r#"{"data":null,"errors":[{"message":"Introspection is disabled","locations":[{"line":1,"column":3}],"path":[],"extensions":{"errorType":"BadRequest"}}]}"#;
+    check_gql_response(req, expected_response, &container).await;
+
+    let container = Container::builder(&schema_doc, Query, EmptyMutation, EmptySubscription)
+        .disable_introspection()
+        .build()
+        .unwrap();
+
+    let req = build_test_request("{ __typename value }", None, Default::default());
+    let expected_response = r#"{"data":null,"errors":[{"message":"Introspection is disabled","locations":[{"line":1,"column":3}],"path":[],"extensions":{"errorType":"BadRequest"}}]}"#;
+    check_gql_response(req, expected_response, &container).await;
+
+    let container = Container::builder(&schema_doc, Query, EmptyMutation, EmptySubscription)
+        .introspection_callback(|request| {
+            if request.http_headers.contains_key("x-internal-user") {
+                IntrospectionMode::Enabled
+            } else {
+                IntrospectionMode::Disabled
+            }
+        })
+        .build()
+        .unwrap();
+
+    let query = "{ __schema { queryType { name } } }";
+    let req = build_test_request(query, None, Default::default());
+    let expected_response = r#"{"data":null,"errors":[{"message":"Introspection is disabled","locations":[{"line":1,"column":3}],"path":[],"extensions":{"errorType":"BadRequest"}}]}"#;
+    check_gql_response(req, expected_response, &container).await;
+
+    let mut req = build_test_request(query, None, Default::default());
+    req.http_headers
+        .insert("x-internal-user", "true".parse().unwrap());
+    let expected_response = r#"{"data":{"__schema":{"queryType":{"name":"Query"}}}}"#;
+    check_gql_response(req, expected_response, &container).await;
+}
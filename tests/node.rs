@@ -0,0 +1,209 @@
+use rusty_gql::*;
+
+struct User {
+    id: String,
+    name: String,
+}
+
+#[GqlType]
+impl User {
+    async fn id(&self) -> ID {
+        ID::global_id("User", &self.id)
+    }
+    async fn name(&self) -> String {
+        self.name.clone()
+    }
+}
+
+struct Post {
+    id: String,
+    title: String,
+}
+
+#[GqlType]
+impl Post {
+    async fn id(&self) -> ID {
+        ID::global_id("Post", &self.id)
+    }
+    async fn title(&self) -> String {
+        self.title.clone()
+    }
+}
+
+struct Query;
+
+#[GqlType]
+impl Query {
+    async fn me(&self) -> User {
+        User {
+            id: "1".to_string(),
+            name: "Tom".to_string(),
+        }
+    }
+}
+
+struct UserFetcher;
+
+#[async_trait::async_trait]
+impl NodeFetcher for UserFetcher {
+    async fn fetch_node(
+        &self,
+        ctx: &SelectionSetContext<'_>,
+        local_id: &str,
+    ) -> ResolverResult<GqlValue> {
+        let user = User {
+            id: local_id.to_string(),
+            name: format!("user{}", local_id),
+        };
+        user.resolve_selection_set(ctx).await
+    }
+}
+
+struct PostFetcher;
+
+#[async_trait::async_trait]
+impl NodeFetcher for PostFetcher {
+    async fn fetch_node(
+        &self,
+        ctx: &SelectionSetContext<'_>,
+        local_id: &str,
+    ) -> ResolverResult<GqlValue> {
+        if local_id != "1" {
+            return Ok(GqlValue::Null);
+        }
+        let post = Post {
+            id: local_id.to_string(),
+            title: "Hello".to_string(),
+        };
+        post.resolve_selection_set(ctx).await
+    }
+}
+
+fn build_container() -> Container<Query, EmptyMutation, EmptySubscription> {
+    let contents = schema_content("./tests/schemas/node.graphql");
+    Container::builder(
+        &[contents.as_str()],
+        Query,
+        EmptyMutation,
+        EmptySubscription,
+    )
+    .node_fetcher("User", UserFetcher)
+    .node_fetcher("Post", PostFetcher)
+    .build()
+    .unwrap()
+}
+
+#[tokio::test]
+pub async fn test_node() {
+    let container = build_container();
+
+    let query_doc = format!(
+        r#"{{ node(id: "{}") {{ __typename id ... on User {{ name }} }} }}"#,
+        ID::global_id("User", 2).0
+    );
+    let req = build_test_request(&query_doc, None, Default::default());
+    let expected_response = serde_json::json!({
+        "data": {
+            "node": { "__typename": "User", "id": ID::global_id("User", 2).0, "name": "user2" }
+        }
+    });
+    check_gql_response(req, &expected_response.to_string(), &container).await;
+}
+
+#[tokio::test]
+pub async fn test_nodes() {
+    let container = build_container();
+
+    let query_doc = format!(
+        r#"{{ nodes(ids: ["{}", "{}", "{}"]) {{ ... on User {{ name }} ... on Post {{ title }} }} }}"#,
+        ID::global_id("User", 1).0,
+        ID::global_id("Post", 1).0,
+        ID::global_id("Post", 2).0,
+    );
+    let req = build_test_request(&query_doc, None, Default::default());
+    let expected_response = r#"{"data":{"nodes":[{"name":"user1"},{"title":"Hello"},null]}}"#;
+    check_gql_response(req, expected_response, &container).await;
+}
+
+#[tokio::test]
+pub async fn test_unregistered_node_type() {
+    let contents = schema_content("./tests/schemas/node.graphql");
+    let result = Container::builder(
+        &[contents.as_str()],
+        Query,
+        EmptyMutation,
+        EmptySubscription,
+    )
+    .node_fetcher("Query", UserFetcher)
+    .build();
+
+    match result {
+        Ok(_) => panic!("Query does not implement Node"),
+        Err(errors) => assert_eq!(errors[0].message, "Query does not implement Node."),
+    }
+}
+
+#[tokio::test]
+pub async fn test_node_with_invalid_id() {
+    let container = build_container();
+
+    for id in ["invalid".to_string(), ID::global_id("Review", 1).0] {
+        let query_doc = format!(r#"{{ node(id: "{}") {{ id }} }}"#, id);
+        let req = build_test_request(&query_doc, None, Default::default());
+        check_gql_response(req, r#"{"data":{"node":null}}"#, &container).await;
+    }
+
+    let query_doc = format!(
+        r#"{{ nodes(ids: ["invalid", "{}", "{}"]) {{ id }} }}"#,
+        ID::global_id("Review", 1).0,
+        ID::global_id("User", 1).0,
+    );
+    let req = build_test_request(&query_doc, None, Default::default());
+    let expected_response = serde_json::json!({
+        "data": { "nodes": [null, null, { "id": ID::global_id("User", 1).0 }] }
+    });
+    check_gql_response(req, &expected_response.to_string(), &container).await;
+}
+
+struct NodeQuery;
+
+#[GqlType]
+impl NodeQuery {
+    async fn node(&self, id: ID) -> Option<User> {
+        Some(User {
+            id: id.0,
+            name: "defined in the schema".to_string(),
+        })
+    }
+}
+
+#[tokio::test]
+pub async fn test_node_defined_in_schema() {
+    let schema_doc = r#"
+    type Query {
+      node(id: ID!): User
+    }
+
+    interface Node {
+      id: ID!
+    }
+
+    type User implements Node {
+      id: ID!
+      name: String!
+    }
+    "#;
+    let container = Container::builder(&[schema_doc], NodeQuery, EmptyMutation, EmptySubscription)
+        .node_fetcher("User", UserFetcher)
+        .build()
+        .unwrap();
+
+    let query_doc = format!(
+        r#"{{ node(id: "2") {{ name }} nodes(ids: ["{}"]) {{ ... on User {{ name }} }} }}"#,
+        ID::global_id("User", 2).0,
+    );
+    let req = build_test_request(&query_doc, None, Default::default());
+    let expected_response =
+        r#"{"data":{"node":{"name":"defined in the schema"},"nodes":[{"name":"user2"}]}}"#;
+    check_gql_response(req, expected_response, &container).await;
+}
@@ -0,0 +1,272 @@
+#![allow(unused)]
+use rusty_gql::*;
+
+#[cfg(feature = "chrono")]
+#[tokio::test]
+pub async fn test_chrono_scalars() {
+    use chrono::{DateTime, NaiveDate, NaiveDateTime, NaiveTime, Utc};
+
+    struct Query;
+
+    #[GqlType]
+    impl Query {
+        async fn next_day(&self, date: NaiveDate) -> NaiveDate {
+            date.succ_opt().unwrap()
+        }
+
+        async fn created_at(&self, at: DateTime<Utc>) -> DateTime<Utc> {
+            at
+        }
+
+        async fn opens_at(&self, at: NaiveTime) -> NaiveTime {
+            at
+        }
+
+        async fn starts_at(&self, at: NaiveDateTime) -> NaiveDateTime {
+            at
+        }
+    }
+
+    let schema_doc = [r#"
+        scalar Date
+        scalar DateTime
+        scalar Time
+        scalar LocalDateTime
+        type Query {
+          next_day(date: Date!): Date!
+          created_at(at: DateTime!): DateTime!
+          opens_at(at: Time!): Time!
+          starts_at(at: LocalDateTime!): LocalDateTime!
+        }
+    "#];
+    let container = Container::builder(&schema_doc, Query, EmptyMutation, EmptySubscription)
+        .build()
+        .unwrap();
+
+    let query_doc = r#"{
+        next_day(date: "2022-01-31")
+        created_at(at: "2022-01-31T21:00:00+09:00")
+        opens_at(at: "09:30:00")
+        starts_at(at: "2022-01-31T09:30:00.5")
+    }"#;
+    let req = build_test_request(query_doc, None, Default::default());
+    let expected_response = r#"{"data":{"created_at":"2022-01-31T12:00:00Z","next_day":"2022-02-01","opens_at":"09:30:00","starts_at":"2022-01-31T09:30:00.500"}}"#;
+    check_gql_response(req, expected_response, &container).await;
+
+    let query_doc = r#"{ next_day(date: "01/31/2022") }"#;
+    let req = build_test_request(query_doc, None, Default::default());
+    let expected_response = r#"{"data":null,"errors":[{"message":"01/31/2022: invalid value for Date, input contains invalid characters","locations":[],"path":[],"extensions":{"errorType":"BadRequest"}}]}"#;
+    check_gql_response(req, expected_response, &container).await;
+}
+
+#[cfg(feature = "time")]
+#[tokio::test]
+pub async fn test_time_scalars() {
+    use time::{Date, OffsetDateTime, PrimitiveDateTime, Time};
+
+    struct Query;
+
+    #[GqlType]
+    impl Query {
+        async fn next_day(&self, date: Date) -> Date {
+            date.next_day().unwrap()
+        }
+
+        async fn created_at(&self, at: OffsetDateTime) -> OffsetDateTime {
+            at
+        }
+
+        async fn opens_at(&self, at: Time) -> Time {
+            at
+        }
+
+        async fn starts_at(&self, at: PrimitiveDateTime) -> PrimitiveDateTime {
+            at
+        }
+    }
+
+    let schema_doc = [r#"
+        scalar Date
+        scalar DateTime
+        scalar Time
+        scalar LocalDateTime
+        type Query {
+          next_day(date: Date!): Date!
+          created_at(at: DateTime!): DateTime!
+          opens_at(at: Time!): Time!
+          starts_at(at: LocalDateTime!): LocalDateTime!
+        }
+    "#];
+    let container = Container::builder(&schema_doc, Query, EmptyMutation, EmptySubscription)
+        .build()
+        .unwrap();
+
+    let query_doc = r#"{
+        next_day(date: "2022-01-31")
+        created_at(at: "2022-01-31T12:00:00Z")
+        opens_at(at: "09:30:00")
+        starts_at(at: "2022-01-31T09:30:00.5")
+    }"#;
+    let req = build_test_request(query_doc, None, Default::default());
+    let expected_response = r#"{"data":{"created_at":"2022-01-31T12:00:00Z","next_day":"2022-02-01","opens_at":"09:30:00.0","starts_at":"2022-01-31T09:30:00.5"}}"#;
+    check_gql_response(req, expected_response, &container).await;
+}
+
+#[cfg(all(feature = "uuid", feature = "url"))]
+#[tokio::test]
+pub async fn test_uuid_and_url_scalars() {
+    use url::Url;
+    use uuid::Uuid;
+
+    struct Query;
+
+    #[GqlType]
+    impl Query {
+        async fn id(&self, id: Uuid) -> Uuid {
+            id
+        }
+
+        async fn homepage(&self, url: Url) -> Url {
+            url.join("about").unwrap()
+        }
+    }
+
+    let schema_doc = [r#"
+        scalar UUID
+        scalar Url
+        type Query {
+          id(id: UUID!): UUID!
+          homepage(url: Url!): Url!
+        }
+    "#];
+    let container = Container::builder(&schema_doc, Query, EmptyMutation, EmptySubscription)
+        .build()
+        .unwrap();
+
+    let query_doc = r#"{
+        id(id: "67E55044-10B1-426F-9247-BB680E5FE0C8")
+        homepage(url: "https://example.com/")
+    }"#;
+    let req = build_test_request(query_doc, None, Default::default());
+    let expected_response = r#"{"data":{"homepage":"https://example.com/about","id":"67e55044-10b1-426f-9247-bb680e5fe0c8"}}"#;
+    check_gql_response(req, expected_response, &container).await;
+}
+
+#[cfg(all(feature = "rust_decimal", feature = "bigdecimal"))]
+#[tokio::test]
+pub async fn test_decimal_scalars() {
+    use bigdecimal::BigDecimal;
+    use rust_decimal::Decimal;
+
+    struct Query;
+
+    #[GqlType]
+    impl Query {
+        async fn price(&self, price: Decimal) -> Decimal {
+            price * Decimal::new(2, 0)
+        }
+
+        async fn total(&self, total: BigDecimal) -> BigDecimal {
+            total * BigDecimal::from(2)
+        }
+    }
+
+    let schema_doc = [r#"
+        scalar Decimal
+        scalar BigDecimal
+        type Query {
+          price(price: Decimal!): Decimal!
+          total(total: BigDecimal!): BigDecimal!
+        }
+    "#];
+    let container = Container::builder(&schema_doc, Query, EmptyMutation, EmptySubscription)
+        .build()
+        .unwrap();
+
+    let query_doc = r#"{ price(price: "10.25") total(total: 1.5) }"#;
+    let req = build_test_request(query_doc, None, Default::default());
+    let expected_response = r#"{"data":{"price":"20.50","total":"3.0"}}"#;
+    check_gql_response(req, expected_response, &container).await;
+}
+
+#[cfg(feature = "json")]
+#[tokio::test]
+pub async fn test_json_scalar() {
+    struct Query;
+
+    #[GqlType]
+    impl Query {
+        async fn echo(&self, value: serde_json::Value) -> serde_json::Value {
+            value
+        }
+    }
+
+    let schema_doc = [r#"
+        scalar JSON
+        type Query {
+          echo(value: JSON): JSON
+        }
+    "#];
+    let container = Container::builder(&schema_doc, Query, EmptyMutation, EmptySubscription)
+        .build()
+        .unwrap();
+
+    let query_doc = r#"query ($value: JSON) { echo(value: $value) }"#;
+    let variables =
+        serde_json::from_str::<Variables>(r#"{"value": {"a": [1, "b", null], "c": true}}"#)
+            .unwrap();
+    let req = build_test_request(query_doc, None, variables);
+    let expected_response = r#"{"data":{"echo":{"a":[1,"b",null],"c":true}}}"#;
+    check_gql_response(req, expected_response, &container).await;
+}
+
+#[cfg(feature = "json")]
+#[tokio::test]
+pub async fn test_json_wrapper() {
+    #[derive(serde::Serialize, serde::Deserialize)]
+    struct Settings {
+        theme: String,
+        tags: Vec<String>,
+        limit: Option<u32>,
+    }
+
+    struct Query;
+
+    #[GqlType]
+    impl Query {
+        async fn settings(&self, settings: Json<Settings>) -> Json<Settings> {
+            let mut settings = settings;
+            settings.tags.push("saved".to_string());
+            settings
+        }
+
+        async fn defaults(&self) -> Option<Json<Settings>> {
+            Some(Json(Settings {
+                theme: "dark".to_string(),
+                tags: Vec::new(),
+                limit: None,
+            }))
+        }
+    }
+
+    let schema_doc = [r#"
+        scalar JSON
+        type Query {
+          settings(settings: JSON!): JSON!
+          defaults: JSON
+        }
+    "#];
+    let container = Container::builder(&schema_doc, Query, EmptyMutation, EmptySubscription)
+        .build()
+        .unwrap();
+
+    let query_doc = r#"{ settings(settings: {theme: "light", tags: ["a"], limit: 10}) defaults }"#;
+    let req = build_test_request(query_doc, None, Default::default());
+    let expected_response = r#"{"data":{"defaults":{"limit":null,"tags":[],"theme":"dark"},"settings":{"limit":10,"tags":["a","saved"],"theme":"light"}}}"#;
+    check_gql_response(req, expected_response, &container).await;
+
+    let query_doc = r#"{ settings(settings: {theme: 1}) }"#;
+    let req = build_test_request(query_doc, None, Default::default());
+    let expected_response = r#"{"data":null,"errors":[{"message":"Invalid value for JSON, invalid type: integer `1`, expected a string","locations":[],"path":[],"extensions":{"errorType":"BadRequest"}}]}"#;
+    check_gql_response(req, expected_response, &container).await;
+}
@@ -0,0 +1,81 @@
+use http::{header, Method, StatusCode};
+use rusty_gql::*;
+use tower_service::Service;
+
+struct Query;
+
+#[GqlType]
+impl Query {
+    async fn value(&self) -> i32 {
+        10
+    }
+}
+
+fn build_service() -> GraphQLService<Query, EmptyMutation, EmptySubscription> {
+    let contents = schema_content("./tests/schemas/test_schema.graphql");
+    let container = Container::new(
+        &[contents.as_str()],
+        Query,
+        EmptyMutation,
+        EmptySubscription,
+        Default::default(),
+    )
+    .unwrap();
+    GraphQLService::new(container)
+}
+
+#[tokio::test]
+pub async fn test_post_request() {
+    let mut service = build_service();
+    let req = http::Request::builder()
+        .method(Method::POST)
+        .uri("/graphql")
+        .header(header::CONTENT_TYPE, "application/json")
+        .header(header::ACCEPT, GRAPHQL_RESPONSE_CONTENT_TYPE)
+        .body(r#"{"query": "{ value }"}"#.to_string())
+        .unwrap();
+    let res = service.call(req).await.unwrap();
+
+    assert_eq!(res.status(), StatusCode::OK);
+    assert_eq!(
+        res.headers().get(header::CONTENT_TYPE).unwrap(),
+        GRAPHQL_RESPONSE_CONTENT_TYPE
+    );
+    assert_eq!(res.body(), r#"{"data":{"value":10}}"#);
+}
+
+#[tokio::test]
+pub async fn test_get_request() {
+    let mut service = build_service();
+    let req = http::Request::builder()
+        .method(Method::GET)
+        .uri("/graphql?query=%7B%20value%20%7D")
+        .body(String::new())
+        .unwrap();
+    let res = service.call(req).await.unwrap();
+
+    assert_eq!(res.status(), StatusCode::OK);
+    assert_eq!(
+        res.headers().get(header::CONTENT_TYPE).unwrap(),
+        JSON_CONTENT_TYPE
+    );
+    assert_eq!(res.body(), r#"{"data":{"value":10}}"#);
+}
+
+#[tokio::test]
+pub async fn test_rejected_request() {
+    let mut service = build_service();
+    let req = http::Request::builder()
+        .method(Method::POST)
+        .uri("/graphql")
+        .header(header::CONTENT_TYPE, "text/plain")
+        .body("{ value }".to_string())
+        .unwrap();
+    let res = service.call(req).await.unwrap();
+
+    assert_eq!(res.status(), StatusCode::UNSUPPORTED_MEDIA_TYPE);
+    assert_eq!(
+        res.body(),
+        r#"{"data":null,"errors":[{"message":"unsupported media type","locations":[],"path":[],"extensions":null}]}"#
+    );
+}
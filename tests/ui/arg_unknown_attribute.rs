@@ -0,0 +1,12 @@
+use rusty_gql::*;
+
+struct Query;
+
+#[GqlType]
+impl Query {
+    async fn value(&self, #[arg(rename = "v")] value: i32) -> i32 {
+        value
+    }
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use rusty_gql::*;
+
+#[derive(GqlInputObject)]
+#[gql(name = "Input")]
+struct InputObj {
+    value: i32,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use rusty_gql::*;
+
+#[derive(GqlEnum)]
+#[gql(rename_all = "kebab-case")]
+enum Episode {
+    NewHope,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use rusty_gql::*;
+
+#[derive(GqlEnum)]
+enum Episode {
+    #[gql(default)]
+    NewHope,
+}
+
+fn main() {}
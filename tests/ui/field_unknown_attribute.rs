@@ -0,0 +1,13 @@
+use rusty_gql::*;
+
+struct Query;
+
+#[GqlType]
+impl Query {
+    #[field(rename = "value")]
+    async fn value(&self) -> i32 {
+        1
+    }
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use rusty_gql::*;
+
+#[derive(GqlInputObject)]
+struct InputObj {
+    #[gql(skip)]
+    value: i32,
+}
+
+fn main() {}
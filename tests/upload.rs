@@ -0,0 +1,155 @@
+#![allow(warnings, unused)]
+use futures_util::TryStreamExt;
+use rusty_gql::*;
+
+struct File {
+    filename: String,
+    content_type: Option<String>,
+    content: String,
+}
+
+#[GqlType]
+impl File {
+    async fn filename(&self) -> String {
+        self.filename.clone()
+    }
+    async fn contentType(&self) -> Option<String> {
+        self.content_type.clone()
+    }
+    async fn content(&self) -> String {
+        self.content.clone()
+    }
+}
+
+async fn read_file(ctx: &Context<'_>, file: Upload) -> File {
+    let upload = file.value(ctx).unwrap();
+    let filename = upload.filename.clone();
+    let content_type = upload.content_type.clone();
+    let chunks: Vec<Vec<u8>> = upload.into_stream().try_collect().await.unwrap();
+    File {
+        filename,
+        content_type,
+        content: String::from_utf8(chunks.concat()).unwrap(),
+    }
+}
+
+struct Query;
+
+#[GqlType]
+impl Query {
+    async fn value(&self) -> i32 {
+        10
+    }
+}
+
+struct Mutation;
+
+#[GqlType]
+impl Mutation {
+    async fn singleUpload(&self, ctx: &Context<'_>, file: Upload) -> File {
+        read_file(ctx, file).await
+    }
+
+    async fn multipleUpload(&self, ctx: &Context<'_>, files: Vec<Upload>) -> Vec<File> {
+        let mut result = Vec::new();
+        for file in files {
+            result.push(read_file(ctx, file).await);
+        }
+        result
+    }
+}
+
+fn multipart_headers() -> http::HeaderMap {
+    let mut headers = http::HeaderMap::new();
+    headers.insert(
+        http::header::CONTENT_TYPE,
+        "multipart/form-data; boundary=boundary".parse().unwrap(),
+    );
+    headers
+}
+
+fn multipart_body(operations: &str, map: &str, files: &[(&str, &str, &str)]) -> String {
+    let mut body = String::new();
+    body += "--boundary\r\nContent-Disposition: form-data; name=\"operations\"\r\n\r\n";
+    body += operations;
+    body += "\r\n--boundary\r\nContent-Disposition: form-data; name=\"map\"\r\n\r\n";
+    body += map;
+    for (name, filename, content) in files {
+        body += &format!(
+            "\r\n--boundary\r\nContent-Disposition: form-data; name=\"{}\"; filename=\"{}\"\r\nContent-Type: text/plain\r\n\r\n{}",
+            name, filename, content
+        );
+    }
+    body += "\r\n--boundary--\r\n";
+    body
+}
+
+fn build_container() -> Container<Query, Mutation, EmptySubscription> {
+    let contents = schema_content("./tests/schemas/upload.graphql");
+    Container::new(
+        &vec![contents.as_str()],
+        Query,
+        Mutation,
+        EmptySubscription,
+        Default::default(),
+    )
+    .unwrap()
+}
+
+#[tokio::test]
+pub async fn test_single_upload() {
+    let container = build_container();
+    let body = multipart_body(
+        r#"{"query": "mutation ($file: Upload!) { singleUpload(file: $file) { filename contentType content } }", "variables": {"file": null}}"#,
+        r#"{"0": ["variables.file"]}"#,
+        &[("0", "a.txt", "hello")],
+    );
+    let req = receive_http_request(
+        &http::Method::POST,
+        &multipart_headers(),
+        None,
+        futures_util::io::Cursor::new(body.into_bytes()),
+        MultipartOptions::default(),
+    )
+    .await
+    .unwrap();
+
+    let expected_response = r#"{"data":{"singleUpload":{"content":"hello","contentType":"text/plain","filename":"a.txt"}}}"#;
+    check_gql_response(req, expected_response, &container).await;
+}
+
+#[tokio::test]
+pub async fn test_multiple_upload() {
+    let container = build_container();
+    let body = multipart_body(
+        r#"{"query": "mutation ($files: [Upload!]!) { multipleUpload(files: $files) { filename content } }", "variables": {"files": [null, null]}}"#,
+        r#"{"0": ["variables.files.0"], "1": ["variables.files.1"]}"#,
+        &[("0", "a.txt", "hello"), ("1", "b.txt", "world")],
+    );
+    let req = receive_http_request(
+        &http::Method::POST,
+        &multipart_headers(),
+        None,
+        futures_util::io::Cursor::new(body.into_bytes()),
+        MultipartOptions::default(),
+    )
+    .await
+    .unwrap();
+
+    let expected_response = r#"{"data":{"multipleUpload":[{"content":"hello","filename":"a.txt"},{"content":"world","filename":"b.txt"}]}}"#;
+    check_gql_response(req, expected_response, &container).await;
+}
+
+#[tokio::test]
+pub async fn test_upload_from_json() {
+    let container = build_container();
+    let variables = serde_json::from_str::<Variables>(r#"{"file": 0}"#).unwrap();
+    let req = build_test_request(
+        "mutation ($file: Upload!) { singleUpload(file: $file) { filename } }",
+        None,
+        variables,
+    );
+    let res = execute(&container, req).await;
+    assert_eq!(res.errors.len(), 1);
+    assert_eq!(res.errors[0].message, "0: invalid gql value for upload");
+}
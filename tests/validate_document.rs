@@ -0,0 +1,68 @@
+use rusty_gql::*;
+
+fn starwars_schema() -> Schema {
+    let contents = std::fs::read_to_string("tests/schemas/starwars.graphql").unwrap();
+    build_schema(&[contents.as_str()], Default::default()).unwrap()
+}
+
+#[test]
+fn test_validate_document() {
+    let schema = starwars_schema();
+    let query_doc = r#"
+    query Hero($episode: Episode) {
+      hero(episode: $episode) {
+        ...CharacterFields
+      }
+    }
+    query Droid($id: ID!) {
+      droid(id: $id) {
+        ...CharacterFields
+        primaryFunction
+      }
+    }
+    mutation CreateReview($review: ReviewInput!) {
+      createReview(episode: JEDI, review: $review) {
+        stars
+      }
+    }
+    fragment CharacterFields on Character {
+      id
+      name
+    }
+    "#;
+    assert!(validate_document(&schema, query_doc).is_empty());
+}
+
+#[test]
+fn test_validate_document_errors() {
+    let schema = starwars_schema();
+    let query_doc = r#"
+    query Hero($episode: Episode) {
+      hero {
+        ...CharacterFields
+      }
+    }
+    query Droid {
+      droid(id: "1") {
+        ...CharacterFields
+      }
+    }
+    fragment CharacterFields on Character {
+      age
+    }
+    "#;
+    let messages: Vec<String> = validate_document(&schema, query_doc)
+        .into_iter()
+        .map(|err| err.message)
+        .collect();
+    assert_eq!(
+        messages,
+        vec![
+            "Unknown field \"age\" on type \"Character\"",
+            "Variable episode is not used by operation Hero",
+        ]
+    );
+
+    let errors = validate_document(&schema, "query { hero { name ");
+    assert_eq!(errors.len(), 1);
+}